@@ -1,4 +1,4 @@
-var Douyin=(()=>{var Ge=Object.create;var C0=Object.defineProperty;var Ze=Object.getOwnPropertyDescriptor;var Je=Object.getOwnPropertyNames;var Qe=Object.getPrototypeOf,Ye=Object.prototype.hasOwnProperty;var Ex=(r=>typeof require<"u"?require:typeof Proxy<"u"?new Proxy(r,{get:(x,n)=>(typeof require<"u"?require:x)[n]}):r)(function(r){if(typeof require<"u")return require.apply(this,arguments);throw Error('Dynamic require of "'+r+'" is not supported')});var T=(r,x)=>()=>(x||r((x={exports:{}}).exports,x),x.exports),$e=(r,x)=>{for(var n in x)C0(r,n,{get:x[n],enumerable:!0})},Fx=(r,x,n,t)=>{if(x&&typeof x=="object"||typeof x=="function")for(let B of Je(x))!Ye.call(r,B)&&B!==n&&C0(r,B,{get:()=>x[B],enumerable:!(t=Ze(x,B))||t.enumerable});return r};var Ve=(r,x,n)=>(n=r!=null?Ge(Qe(r)):{},Fx(x||!r||!r.__esModule?C0(n,"default",{value:r,enumerable:!0}):n,r)),Me=r=>Fx(C0({},"__esModule",{value:!0}),r);var Dx=T(()=>{});var O=T((A0,px)=>{(function(r,x){typeof A0=="object"?px.exports=A0=x():typeof define=="function"&&define.amd?define([],x):r.CryptoJS=x()})(A0,function(){var r=r||(function(x,n){var t;if(typeof window<"u"&&window.crypto&&(t=window.crypto),typeof self<"u"&&self.crypto&&(t=self.crypto),typeof globalThis<"u"&&globalThis.crypto&&(t=globalThis.crypto),!t&&typeof window<"u"&&window.msCrypto&&(t=window.msCrypto),!t&&typeof global<"u"&&global.crypto&&(t=global.crypto),!t&&typeof Ex=="function")try{t=Dx()}catch{}var B=function(){if(t){if(typeof t.getRandomValues=="function")try{return t.getRandomValues(new Uint32Array(1))[0]}catch{}if(typeof t.randomBytes=="function")try{return t.randomBytes(4).readInt32LE()}catch{}}throw new Error("Native crypto module could not be used to get secure random number.")},o=Object.create||(function(){function a(){}return function(s){var E;return a.prototype=s,E=new a,a.prototype=null,E}})(),F={},e=F.lib={},c=e.Base=(function(){return{extend:function(a){var s=o(this);return a&&s.mixIn(a),(!s.hasOwnProperty("init")||this.init===s.init)&&(s.init=function(){s.$super.init.apply(this,arguments)}),s.init.prototype=s,s.$super=this,s},create:function(){var a=this.extend();return a.init.apply(a,arguments),a},init:function(){},mixIn:function(a){for(var s in a)a.hasOwnProperty(s)&&(this[s]=a[s]);a.hasOwnProperty("toString")&&(this.toString=a.toString)},clone:function(){return this.init.prototype.extend(this)}}})(),l=e.WordArray=c.extend({init:function(a,s){a=this.words=a||[],s!=n?this.sigBytes=s:this.sigBytes=a.length*4},toString:function(a){return(a||d).stringify(this)},concat:function(a){var s=this.words,E=a.words,A=this.sigBytes,D=a.sigBytes;if(this.clamp(),A%4)for(var p=0;p<D;p++){var H=E[p>>>2]>>>24-p%4*8&255;s[A+p>>>2]|=H<<24-(A+p)%4*8}else for(var S=0;S<D;S+=4)s[A+S>>>2]=E[S>>>2];return this.sigBytes+=D,this},clamp:function(){var a=this.words,s=this.sigBytes;a[s>>>2]&=4294967295<<32-s%4*8,a.length=x.ceil(s/4)},clone:function(){var a=c.clone.call(this);return a.words=this.words.slice(0),a},random:function(a){for(var s=[],E=0;E<a;E+=4)s.push(B());return new l.init(s,a)}}),i=F.enc={},d=i.Hex={stringify:function(a){for(var s=a.words,E=a.sigBytes,A=[],D=0;D<E;D++){var p=s[D>>>2]>>>24-D%4*8&255;A.push((p>>>4).toString(16)),A.push((p&15).toString(16))}return A.join("")},parse:function(a){for(var s=a.length,E=[],A=0;A<s;A+=2)E[A>>>3]|=parseInt(a.substr(A,2),16)<<24-A%8*4;return new l.init(E,s/2)}},f=i.Latin1={stringify:function(a){for(var s=a.words,E=a.sigBytes,A=[],D=0;D<E;D++){var p=s[D>>>2]>>>24-D%4*8&255;A.push(String.fromCharCode(p))}return A.join("")},parse:function(a){for(var s=a.length,E=[],A=0;A<s;A++)E[A>>>2]|=(a.charCodeAt(A)&255)<<24-A%4*8;return new l.init(E,s)}},h=i.Utf8={stringify:function(a){try{return decodeURIComponent(escape(f.stringify(a)))}catch{throw new Error("Malformed UTF-8 data")}},parse:function(a){return f.parse(unescape(encodeURIComponent(a)))}},v=e.BufferedBlockAlgorithm=c.extend({reset:function(){this._data=new l.init,this._nDataBytes=0},_append:function(a){typeof a=="string"&&(a=h.parse(a)),this._data.concat(a),this._nDataBytes+=a.sigBytes},_process:function(a){var s,E=this._data,A=E.words,D=E.sigBytes,p=this.blockSize,H=p*4,S=D/H;a?S=x.ceil(S):S=x.max((S|0)-this._minBufferSize,0);var q=S*p,R=x.min(q*4,D);if(q){for(var b=0;b<q;b+=p)this._doProcessBlock(A,b);s=A.splice(0,q),E.sigBytes-=R}return new l.init(s,R)},clone:function(){var a=c.clone.call(this);return a._data=this._data.clone(),a},_minBufferSize:0}),u=e.Hasher=v.extend({cfg:c.extend(),init:function(a){this.cfg=this.cfg.extend(a),this.reset()},reset:function(){v.reset.call(this),this._doReset()},update:function(a){return this._append(a),this._process(),this},finalize:function(a){a&&this._append(a);var s=this._doFinalize();return s},blockSize:512/32,_createHelper:function(a){return function(s,E){return new a.init(E).finalize(s)}},_createHmacHelper:function(a){return function(s,E){return new C.HMAC.init(a,E).finalize(s)}}}),C=F.algo={};return F})(Math);return r})});var B0=T((E0,bx)=>{(function(r,x){typeof E0=="object"?bx.exports=E0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(E0,function(r){return(function(x){var n=r,t=n.lib,B=t.Base,o=t.WordArray,F=n.x64={},e=F.Word=B.extend({init:function(l,i){this.high=l,this.low=i}}),c=F.WordArray=B.extend({init:function(l,i){l=this.words=l||[],i!=x?this.sigBytes=i:this.sigBytes=l.length*8},toX32:function(){for(var l=this.words,i=l.length,d=[],f=0;f<i;f++){var h=l[f];d.push(h.high),d.push(h.low)}return o.create(d,this.sigBytes)},clone:function(){for(var l=B.clone.call(this),i=l.words=this.words.slice(0),d=i.length,f=0;f<d;f++)i[f]=i[f].clone();return l}})})(),r})});var yx=T((F0,_x)=>{(function(r,x){typeof F0=="object"?_x.exports=F0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(F0,function(r){return(function(){if(typeof ArrayBuffer=="function"){var x=r,n=x.lib,t=n.WordArray,B=t.init,o=t.init=function(F){if(F instanceof ArrayBuffer&&(F=new Uint8Array(F)),(F instanceof Int8Array||typeof Uint8ClampedArray<"u"&&F instanceof Uint8ClampedArray||F instanceof Int16Array||F instanceof Uint16Array||F instanceof Int32Array||F instanceof Uint32Array||F instanceof Float32Array||F instanceof Float64Array)&&(F=new Uint8Array(F.buffer,F.byteOffset,F.byteLength)),F instanceof Uint8Array){for(var e=F.byteLength,c=[],l=0;l<e;l++)c[l>>>2]|=F[l]<<24-l%4*8;B.call(this,c,e)}else B.apply(this,arguments)};o.prototype=t}})(),r.lib.WordArray})});var kx=T((D0,gx)=>{(function(r,x){typeof D0=="object"?gx.exports=D0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(D0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.enc,o=B.Utf16=B.Utf16BE={stringify:function(e){for(var c=e.words,l=e.sigBytes,i=[],d=0;d<l;d+=2){var f=c[d>>>2]>>>16-d%4*8&65535;i.push(String.fromCharCode(f))}return i.join("")},parse:function(e){for(var c=e.length,l=[],i=0;i<c;i++)l[i>>>1]|=e.charCodeAt(i)<<16-i%2*16;return t.create(l,c*2)}};B.Utf16LE={stringify:function(e){for(var c=e.words,l=e.sigBytes,i=[],d=0;d<l;d+=2){var f=F(c[d>>>2]>>>16-d%4*8&65535);i.push(String.fromCharCode(f))}return i.join("")},parse:function(e){for(var c=e.length,l=[],i=0;i<c;i++)l[i>>>1]|=F(e.charCodeAt(i)<<16-i%2*16);return t.create(l,c*2)}};function F(e){return e<<8&4278255360|e>>>8&16711935}})(),r.enc.Utf16})});var e0=T((p0,mx)=>{(function(r,x){typeof p0=="object"?mx.exports=p0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(p0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.enc,o=B.Base64={stringify:function(e){var c=e.words,l=e.sigBytes,i=this._map;e.clamp();for(var d=[],f=0;f<l;f+=3)for(var h=c[f>>>2]>>>24-f%4*8&255,v=c[f+1>>>2]>>>24-(f+1)%4*8&255,u=c[f+2>>>2]>>>24-(f+2)%4*8&255,C=h<<16|v<<8|u,a=0;a<4&&f+a*.75<l;a++)d.push(i.charAt(C>>>6*(3-a)&63));var s=i.charAt(64);if(s)for(;d.length%4;)d.push(s);return d.join("")},parse:function(e){var c=e.length,l=this._map,i=this._reverseMap;if(!i){i=this._reverseMap=[];for(var d=0;d<l.length;d++)i[l.charCodeAt(d)]=d}var f=l.charAt(64);if(f){var h=e.indexOf(f);h!==-1&&(c=h)}return F(e,c,i)},_map:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/="};function F(e,c,l){for(var i=[],d=0,f=0;f<c;f++)if(f%4){var h=l[e.charCodeAt(f-1)]<<f%4*2,v=l[e.charCodeAt(f)]>>>6-f%4*2,u=h|v;i[d>>>2]|=u<<24-d%4*8,d++}return t.create(i,d)}})(),r.enc.Base64})});var wx=T((b0,Sx)=>{(function(r,x){typeof b0=="object"?Sx.exports=b0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(b0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.enc,o=B.Base64url={stringify:function(e,c){c===void 0&&(c=!0);var l=e.words,i=e.sigBytes,d=c?this._safe_map:this._map;e.clamp();for(var f=[],h=0;h<i;h+=3)for(var v=l[h>>>2]>>>24-h%4*8&255,u=l[h+1>>>2]>>>24-(h+1)%4*8&255,C=l[h+2>>>2]>>>24-(h+2)%4*8&255,a=v<<16|u<<8|C,s=0;s<4&&h+s*.75<i;s++)f.push(d.charAt(a>>>6*(3-s)&63));var E=d.charAt(64);if(E)for(;f.length%4;)f.push(E);return f.join("")},parse:function(e,c){c===void 0&&(c=!0);var l=e.length,i=c?this._safe_map:this._map,d=this._reverseMap;if(!d){d=this._reverseMap=[];for(var f=0;f<i.length;f++)d[i.charCodeAt(f)]=f}var h=i.charAt(64);if(h){var v=e.indexOf(h);v!==-1&&(l=v)}return F(e,l,d)},_map:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=",_safe_map:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"};function F(e,c,l){for(var i=[],d=0,f=0;f<c;f++)if(f%4){var h=l[e.charCodeAt(f-1)]<<f%4*2,v=l[e.charCodeAt(f)]>>>6-f%4*2,u=h|v;i[d>>>2]|=u<<24-d%4*8,d++}return t.create(i,d)}})(),r.enc.Base64url})});var r0=T((_0,Hx)=>{(function(r,x){typeof _0=="object"?Hx.exports=_0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(_0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.algo,e=[];(function(){for(var h=0;h<64;h++)e[h]=x.abs(x.sin(h+1))*4294967296|0})();var c=F.MD5=o.extend({_doReset:function(){this._hash=new B.init([1732584193,4023233417,2562383102,271733878])},_doProcessBlock:function(h,v){for(var u=0;u<16;u++){var C=v+u,a=h[C];h[C]=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360}var s=this._hash.words,E=h[v+0],A=h[v+1],D=h[v+2],p=h[v+3],H=h[v+4],S=h[v+5],q=h[v+6],R=h[v+7],b=h[v+8],w=h[v+9],z=h[v+10],k=h[v+11],P=h[v+12],W=h[v+13],j=h[v+14],U=h[v+15],_=s[0],g=s[1],m=s[2],y=s[3];_=l(_,g,m,y,E,7,e[0]),y=l(y,_,g,m,A,12,e[1]),m=l(m,y,_,g,D,17,e[2]),g=l(g,m,y,_,p,22,e[3]),_=l(_,g,m,y,H,7,e[4]),y=l(y,_,g,m,S,12,e[5]),m=l(m,y,_,g,q,17,e[6]),g=l(g,m,y,_,R,22,e[7]),_=l(_,g,m,y,b,7,e[8]),y=l(y,_,g,m,w,12,e[9]),m=l(m,y,_,g,z,17,e[10]),g=l(g,m,y,_,k,22,e[11]),_=l(_,g,m,y,P,7,e[12]),y=l(y,_,g,m,W,12,e[13]),m=l(m,y,_,g,j,17,e[14]),g=l(g,m,y,_,U,22,e[15]),_=i(_,g,m,y,A,5,e[16]),y=i(y,_,g,m,q,9,e[17]),m=i(m,y,_,g,k,14,e[18]),g=i(g,m,y,_,E,20,e[19]),_=i(_,g,m,y,S,5,e[20]),y=i(y,_,g,m,z,9,e[21]),m=i(m,y,_,g,U,14,e[22]),g=i(g,m,y,_,H,20,e[23]),_=i(_,g,m,y,w,5,e[24]),y=i(y,_,g,m,j,9,e[25]),m=i(m,y,_,g,p,14,e[26]),g=i(g,m,y,_,b,20,e[27]),_=i(_,g,m,y,W,5,e[28]),y=i(y,_,g,m,D,9,e[29]),m=i(m,y,_,g,R,14,e[30]),g=i(g,m,y,_,P,20,e[31]),_=d(_,g,m,y,S,4,e[32]),y=d(y,_,g,m,b,11,e[33]),m=d(m,y,_,g,k,16,e[34]),g=d(g,m,y,_,j,23,e[35]),_=d(_,g,m,y,A,4,e[36]),y=d(y,_,g,m,H,11,e[37]),m=d(m,y,_,g,R,16,e[38]),g=d(g,m,y,_,z,23,e[39]),_=d(_,g,m,y,W,4,e[40]),y=d(y,_,g,m,E,11,e[41]),m=d(m,y,_,g,p,16,e[42]),g=d(g,m,y,_,q,23,e[43]),_=d(_,g,m,y,w,4,e[44]),y=d(y,_,g,m,P,11,e[45]),m=d(m,y,_,g,U,16,e[46]),g=d(g,m,y,_,D,23,e[47]),_=f(_,g,m,y,E,6,e[48]),y=f(y,_,g,m,R,10,e[49]),m=f(m,y,_,g,j,15,e[50]),g=f(g,m,y,_,S,21,e[51]),_=f(_,g,m,y,P,6,e[52]),y=f(y,_,g,m,p,10,e[53]),m=f(m,y,_,g,z,15,e[54]),g=f(g,m,y,_,A,21,e[55]),_=f(_,g,m,y,b,6,e[56]),y=f(y,_,g,m,U,10,e[57]),m=f(m,y,_,g,q,15,e[58]),g=f(g,m,y,_,W,21,e[59]),_=f(_,g,m,y,H,6,e[60]),y=f(y,_,g,m,k,10,e[61]),m=f(m,y,_,g,D,15,e[62]),g=f(g,m,y,_,w,21,e[63]),s[0]=s[0]+_|0,s[1]=s[1]+g|0,s[2]=s[2]+m|0,s[3]=s[3]+y|0},_doFinalize:function(){var h=this._data,v=h.words,u=this._nDataBytes*8,C=h.sigBytes*8;v[C>>>5]|=128<<24-C%32;var a=x.floor(u/4294967296),s=u;v[(C+64>>>9<<4)+15]=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360,v[(C+64>>>9<<4)+14]=(s<<8|s>>>24)&16711935|(s<<24|s>>>8)&4278255360,h.sigBytes=(v.length+1)*4,this._process();for(var E=this._hash,A=E.words,D=0;D<4;D++){var p=A[D];A[D]=(p<<8|p>>>24)&16711935|(p<<24|p>>>8)&4278255360}return E},clone:function(){var h=o.clone.call(this);return h._hash=this._hash.clone(),h}});function l(h,v,u,C,a,s,E){var A=h+(v&u|~v&C)+a+E;return(A<<s|A>>>32-s)+v}function i(h,v,u,C,a,s,E){var A=h+(v&C|u&~C)+a+E;return(A<<s|A>>>32-s)+v}function d(h,v,u,C,a,s,E){var A=h+(v^u^C)+a+E;return(A<<s|A>>>32-s)+v}function f(h,v,u,C,a,s,E){var A=h+(u^(v|~C))+a+E;return(A<<s|A>>>32-s)+v}n.MD5=o._createHelper(c),n.HmacMD5=o._createHmacHelper(c)})(Math),r.MD5})});var ix=T((y0,qx)=>{(function(r,x){typeof y0=="object"?qx.exports=y0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(y0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=n.Hasher,o=x.algo,F=[],e=o.SHA1=B.extend({_doReset:function(){this._hash=new t.init([1732584193,4023233417,2562383102,271733878,3285377520])},_doProcessBlock:function(c,l){for(var i=this._hash.words,d=i[0],f=i[1],h=i[2],v=i[3],u=i[4],C=0;C<80;C++){if(C<16)F[C]=c[l+C]|0;else{var a=F[C-3]^F[C-8]^F[C-14]^F[C-16];F[C]=a<<1|a>>>31}var s=(d<<5|d>>>27)+u+F[C];C<20?s+=(f&h|~f&v)+1518500249:C<40?s+=(f^h^v)+1859775393:C<60?s+=(f&h|f&v|h&v)-1894007588:s+=(f^h^v)-899497514,u=v,v=h,h=f<<30|f>>>2,f=d,d=s}i[0]=i[0]+d|0,i[1]=i[1]+f|0,i[2]=i[2]+h|0,i[3]=i[3]+v|0,i[4]=i[4]+u|0},_doFinalize:function(){var c=this._data,l=c.words,i=this._nDataBytes*8,d=c.sigBytes*8;return l[d>>>5]|=128<<24-d%32,l[(d+64>>>9<<4)+14]=Math.floor(i/4294967296),l[(d+64>>>9<<4)+15]=i,c.sigBytes=l.length*4,this._process(),this._hash},clone:function(){var c=B.clone.call(this);return c._hash=this._hash.clone(),c}});x.SHA1=B._createHelper(e),x.HmacSHA1=B._createHmacHelper(e)})(),r.SHA1})});var k0=T((g0,zx)=>{(function(r,x){typeof g0=="object"?zx.exports=g0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(g0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.algo,e=[],c=[];(function(){function d(u){for(var C=x.sqrt(u),a=2;a<=C;a++)if(!(u%a))return!1;return!0}function f(u){return(u-(u|0))*4294967296|0}for(var h=2,v=0;v<64;)d(h)&&(v<8&&(e[v]=f(x.pow(h,1/2))),c[v]=f(x.pow(h,1/3)),v++),h++})();var l=[],i=F.SHA256=o.extend({_doReset:function(){this._hash=new B.init(e.slice(0))},_doProcessBlock:function(d,f){for(var h=this._hash.words,v=h[0],u=h[1],C=h[2],a=h[3],s=h[4],E=h[5],A=h[6],D=h[7],p=0;p<64;p++){if(p<16)l[p]=d[f+p]|0;else{var H=l[p-15],S=(H<<25|H>>>7)^(H<<14|H>>>18)^H>>>3,q=l[p-2],R=(q<<15|q>>>17)^(q<<13|q>>>19)^q>>>10;l[p]=S+l[p-7]+R+l[p-16]}var b=s&E^~s&A,w=v&u^v&C^u&C,z=(v<<30|v>>>2)^(v<<19|v>>>13)^(v<<10|v>>>22),k=(s<<26|s>>>6)^(s<<21|s>>>11)^(s<<7|s>>>25),P=D+k+b+c[p]+l[p],W=z+w;D=A,A=E,E=s,s=a+P|0,a=C,C=u,u=v,v=P+W|0}h[0]=h[0]+v|0,h[1]=h[1]+u|0,h[2]=h[2]+C|0,h[3]=h[3]+a|0,h[4]=h[4]+s|0,h[5]=h[5]+E|0,h[6]=h[6]+A|0,h[7]=h[7]+D|0},_doFinalize:function(){var d=this._data,f=d.words,h=this._nDataBytes*8,v=d.sigBytes*8;return f[v>>>5]|=128<<24-v%32,f[(v+64>>>9<<4)+14]=x.floor(h/4294967296),f[(v+64>>>9<<4)+15]=h,d.sigBytes=f.length*4,this._process(),this._hash},clone:function(){var d=o.clone.call(this);return d._hash=this._hash.clone(),d}});n.SHA256=o._createHelper(i),n.HmacSHA256=o._createHmacHelper(i)})(Math),r.SHA256})});var Wx=T((m0,Rx)=>{(function(r,x,n){typeof m0=="object"?Rx.exports=m0=x(O(),k0()):typeof define=="function"&&define.amd?define(["./core","./sha256"],x):x(r.CryptoJS)})(m0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.algo,o=B.SHA256,F=B.SHA224=o.extend({_doReset:function(){this._hash=new t.init([3238371032,914150663,812702999,4144912697,4290775857,1750603025,1694076839,3204075428])},_doFinalize:function(){var e=o._doFinalize.call(this);return e.sigBytes-=4,e}});x.SHA224=o._createHelper(F),x.HmacSHA224=o._createHmacHelper(F)})(),r.SHA224})});var nx=T((S0,Px)=>{(function(r,x,n){typeof S0=="object"?Px.exports=S0=x(O(),B0()):typeof define=="function"&&define.amd?define(["./core","./x64-core"],x):x(r.CryptoJS)})(S0,function(r){return(function(){var x=r,n=x.lib,t=n.Hasher,B=x.x64,o=B.Word,F=B.WordArray,e=x.algo;function c(){return o.create.apply(o,arguments)}var l=[c(1116352408,3609767458),c(1899447441,602891725),c(3049323471,3964484399),c(3921009573,2173295548),c(961987163,4081628472),c(1508970993,3053834265),c(2453635748,2937671579),c(2870763221,3664609560),c(3624381080,2734883394),c(310598401,1164996542),c(607225278,1323610764),c(1426881987,3590304994),c(1925078388,4068182383),c(2162078206,991336113),c(2614888103,633803317),c(3248222580,3479774868),c(3835390401,2666613458),c(4022224774,944711139),c(264347078,2341262773),c(604807628,2007800933),c(770255983,1495990901),c(1249150122,1856431235),c(1555081692,3175218132),c(1996064986,2198950837),c(2554220882,3999719339),c(2821834349,766784016),c(2952996808,2566594879),c(3210313671,3203337956),c(3336571891,1034457026),c(3584528711,2466948901),c(113926993,3758326383),c(338241895,168717936),c(666307205,1188179964),c(773529912,1546045734),c(1294757372,1522805485),c(1396182291,2643833823),c(1695183700,2343527390),c(1986661051,1014477480),c(2177026350,1206759142),c(2456956037,344077627),c(2730485921,1290863460),c(2820302411,3158454273),c(3259730800,3505952657),c(3345764771,106217008),c(3516065817,3606008344),c(3600352804,1432725776),c(4094571909,1467031594),c(275423344,851169720),c(430227734,3100823752),c(506948616,1363258195),c(659060556,3750685593),c(883997877,3785050280),c(958139571,3318307427),c(1322822218,3812723403),c(1537002063,2003034995),c(1747873779,3602036899),c(1955562222,1575990012),c(2024104815,1125592928),c(2227730452,2716904306),c(2361852424,442776044),c(2428436474,593698344),c(2756734187,3733110249),c(3204031479,2999351573),c(3329325298,3815920427),c(3391569614,3928383900),c(3515267271,566280711),c(3940187606,3454069534),c(4118630271,4000239992),c(116418474,1914138554),c(174292421,2731055270),c(289380356,3203993006),c(460393269,320620315),c(685471733,587496836),c(852142971,1086792851),c(1017036298,365543100),c(1126000580,2618297676),c(1288033470,3409855158),c(1501505948,4234509866),c(1607167915,987167468),c(1816402316,1246189591)],i=[];(function(){for(var f=0;f<80;f++)i[f]=c()})();var d=e.SHA512=t.extend({_doReset:function(){this._hash=new F.init([new o.init(1779033703,4089235720),new o.init(3144134277,2227873595),new o.init(1013904242,4271175723),new o.init(2773480762,1595750129),new o.init(1359893119,2917565137),new o.init(2600822924,725511199),new o.init(528734635,4215389547),new o.init(1541459225,327033209)])},_doProcessBlock:function(f,h){for(var v=this._hash.words,u=v[0],C=v[1],a=v[2],s=v[3],E=v[4],A=v[5],D=v[6],p=v[7],H=u.high,S=u.low,q=C.high,R=C.low,b=a.high,w=a.low,z=s.high,k=s.low,P=E.high,W=E.low,j=A.high,U=A.low,_=D.high,g=D.low,m=p.high,y=p.low,N=H,X=S,K=q,L=R,a0=b,t0=w,rx=z,f0=k,Q=P,G=W,h0=j,o0=U,l0=_,c0=g,tx=m,s0=y,Y=0;Y<80;Y++){var J,M,u0=i[Y];if(Y<16)M=u0.high=f[h+Y*2]|0,J=u0.low=f[h+Y*2+1]|0;else{var fx=i[Y-15],i0=fx.high,v0=fx.low,ze=(i0>>>1|v0<<31)^(i0>>>8|v0<<24)^i0>>>7,ox=(v0>>>1|i0<<31)^(v0>>>8|i0<<24)^(v0>>>7|i0<<25),cx=i[Y-2],n0=cx.high,d0=cx.low,Re=(n0>>>19|d0<<13)^(n0<<3|d0>>>29)^n0>>>6,sx=(d0>>>19|n0<<13)^(d0<<3|n0>>>29)^(d0>>>6|n0<<26),vx=i[Y-7],We=vx.high,Pe=vx.low,dx=i[Y-16],je=dx.high,Bx=dx.low;J=ox+Pe,M=ze+We+(J>>>0<ox>>>0?1:0),J=J+sx,M=M+Re+(J>>>0<sx>>>0?1:0),J=J+Bx,M=M+je+(J>>>0<Bx>>>0?1:0),u0.high=M,u0.low=J}var Le=Q&h0^~Q&l0,hx=G&o0^~G&c0,Te=N&K^N&a0^K&a0,Oe=X&L^X&t0^L&t0,Ue=(N>>>28|X<<4)^(N<<30|X>>>2)^(N<<25|X>>>7),lx=(X>>>28|N<<4)^(X<<30|N>>>2)^(X<<25|N>>>7),Xe=(Q>>>14|G<<18)^(Q>>>18|G<<14)^(Q<<23|G>>>9),Ne=(G>>>14|Q<<18)^(G>>>18|Q<<14)^(G<<23|Q>>>9),ux=l[Y],Ie=ux.high,Cx=ux.low,Z=s0+Ne,x0=tx+Xe+(Z>>>0<s0>>>0?1:0),Z=Z+hx,x0=x0+Le+(Z>>>0<hx>>>0?1:0),Z=Z+Cx,x0=x0+Ie+(Z>>>0<Cx>>>0?1:0),Z=Z+J,x0=x0+M+(Z>>>0<J>>>0?1:0),Ax=lx+Oe,Ke=Ue+Te+(Ax>>>0<lx>>>0?1:0);tx=l0,s0=c0,l0=h0,c0=o0,h0=Q,o0=G,G=f0+Z|0,Q=rx+x0+(G>>>0<f0>>>0?1:0)|0,rx=a0,f0=t0,a0=K,t0=L,K=N,L=X,X=Z+Ax|0,N=x0+Ke+(X>>>0<Z>>>0?1:0)|0}S=u.low=S+X,u.high=H+N+(S>>>0<X>>>0?1:0),R=C.low=R+L,C.high=q+K+(R>>>0<L>>>0?1:0),w=a.low=w+t0,a.high=b+a0+(w>>>0<t0>>>0?1:0),k=s.low=k+f0,s.high=z+rx+(k>>>0<f0>>>0?1:0),W=E.low=W+G,E.high=P+Q+(W>>>0<G>>>0?1:0),U=A.low=U+o0,A.high=j+h0+(U>>>0<o0>>>0?1:0),g=D.low=g+c0,D.high=_+l0+(g>>>0<c0>>>0?1:0),y=p.low=y+s0,p.high=m+tx+(y>>>0<s0>>>0?1:0)},_doFinalize:function(){var f=this._data,h=f.words,v=this._nDataBytes*8,u=f.sigBytes*8;h[u>>>5]|=128<<24-u%32,h[(u+128>>>10<<5)+30]=Math.floor(v/4294967296),h[(u+128>>>10<<5)+31]=v,f.sigBytes=h.length*4,this._process();var C=this._hash.toX32();return C},clone:function(){var f=t.clone.call(this);return f._hash=this._hash.clone(),f},blockSize:1024/32});x.SHA512=t._createHelper(d),x.HmacSHA512=t._createHmacHelper(d)})(),r.SHA512})});var Lx=T((w0,jx)=>{(function(r,x,n){typeof w0=="object"?jx.exports=w0=x(O(),B0(),nx()):typeof define=="function"&&define.amd?define(["./core","./x64-core","./sha512"],x):x(r.CryptoJS)})(w0,function(r){return(function(){var x=r,n=x.x64,t=n.Word,B=n.WordArray,o=x.algo,F=o.SHA512,e=o.SHA384=F.extend({_doReset:function(){this._hash=new B.init([new t.init(3418070365,3238371032),new t.init(1654270250,914150663),new t.init(2438529370,812702999),new t.init(355462360,4144912697),new t.init(1731405415,4290775857),new t.init(2394180231,1750603025),new t.init(3675008525,1694076839),new t.init(1203062813,3204075428)])},_doFinalize:function(){var c=F._doFinalize.call(this);return c.sigBytes-=16,c}});x.SHA384=F._createHelper(e),x.HmacSHA384=F._createHmacHelper(e)})(),r.SHA384})});var Ox=T((H0,Tx)=>{(function(r,x,n){typeof H0=="object"?Tx.exports=H0=x(O(),B0()):typeof define=="function"&&define.amd?define(["./core","./x64-core"],x):x(r.CryptoJS)})(H0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.x64,e=F.Word,c=n.algo,l=[],i=[],d=[];(function(){for(var v=1,u=0,C=0;C<24;C++){l[v+5*u]=(C+1)*(C+2)/2%64;var a=u%5,s=(2*v+3*u)%5;v=a,u=s}for(var v=0;v<5;v++)for(var u=0;u<5;u++)i[v+5*u]=u+(2*v+3*u)%5*5;for(var E=1,A=0;A<24;A++){for(var D=0,p=0,H=0;H<7;H++){if(E&1){var S=(1<<H)-1;S<32?p^=1<<S:D^=1<<S-32}E&128?E=E<<1^113:E<<=1}d[A]=e.create(D,p)}})();var f=[];(function(){for(var v=0;v<25;v++)f[v]=e.create()})();var h=c.SHA3=o.extend({cfg:o.cfg.extend({outputLength:512}),_doReset:function(){for(var v=this._state=[],u=0;u<25;u++)v[u]=new e.init;this.blockSize=(1600-2*this.cfg.outputLength)/32},_doProcessBlock:function(v,u){for(var C=this._state,a=this.blockSize/2,s=0;s<a;s++){var E=v[u+2*s],A=v[u+2*s+1];E=(E<<8|E>>>24)&16711935|(E<<24|E>>>8)&4278255360,A=(A<<8|A>>>24)&16711935|(A<<24|A>>>8)&4278255360;var D=C[s];D.high^=A,D.low^=E}for(var p=0;p<24;p++){for(var H=0;H<5;H++){for(var S=0,q=0,R=0;R<5;R++){var D=C[H+5*R];S^=D.high,q^=D.low}var b=f[H];b.high=S,b.low=q}for(var H=0;H<5;H++)for(var w=f[(H+4)%5],z=f[(H+1)%5],k=z.high,P=z.low,S=w.high^(k<<1|P>>>31),q=w.low^(P<<1|k>>>31),R=0;R<5;R++){var D=C[H+5*R];D.high^=S,D.low^=q}for(var W=1;W<25;W++){var S,q,D=C[W],j=D.high,U=D.low,_=l[W];_<32?(S=j<<_|U>>>32-_,q=U<<_|j>>>32-_):(S=U<<_-32|j>>>64-_,q=j<<_-32|U>>>64-_);var g=f[i[W]];g.high=S,g.low=q}var m=f[0],y=C[0];m.high=y.high,m.low=y.low;for(var H=0;H<5;H++)for(var R=0;R<5;R++){var W=H+5*R,D=C[W],N=f[W],X=f[(H+1)%5+5*R],K=f[(H+2)%5+5*R];D.high=N.high^~X.high&K.high,D.low=N.low^~X.low&K.low}var D=C[0],L=d[p];D.high^=L.high,D.low^=L.low}},_doFinalize:function(){var v=this._data,u=v.words,C=this._nDataBytes*8,a=v.sigBytes*8,s=this.blockSize*32;u[a>>>5]|=1<<24-a%32,u[(x.ceil((a+1)/s)*s>>>5)-1]|=128,v.sigBytes=u.length*4,this._process();for(var E=this._state,A=this.cfg.outputLength/8,D=A/8,p=[],H=0;H<D;H++){var S=E[H],q=S.high,R=S.low;q=(q<<8|q>>>24)&16711935|(q<<24|q>>>8)&4278255360,R=(R<<8|R>>>24)&16711935|(R<<24|R>>>8)&4278255360,p.push(R),p.push(q)}return new B.init(p,A)},clone:function(){for(var v=o.clone.call(this),u=v._state=this._state.slice(0),C=0;C<25;C++)u[C]=u[C].clone();return v}});n.SHA3=o._createHelper(h),n.HmacSHA3=o._createHmacHelper(h)})(Math),r.SHA3})});var Xx=T((q0,Ux)=>{(function(r,x){typeof q0=="object"?Ux.exports=q0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(q0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.algo,e=B.create([0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,7,4,13,1,10,6,15,3,12,0,9,5,2,14,11,8,3,10,14,4,9,15,8,1,2,7,0,6,13,11,5,12,1,9,11,10,0,8,12,4,13,3,7,15,14,5,6,2,4,0,5,9,7,12,2,10,14,1,3,8,11,6,15,13]),c=B.create([5,14,7,0,9,2,11,4,13,6,15,8,1,10,3,12,6,11,3,7,0,13,5,10,14,15,8,12,4,9,1,2,15,5,1,3,7,14,6,9,11,8,12,2,10,0,4,13,8,6,4,1,3,11,15,0,5,12,2,13,9,7,10,14,12,15,10,4,1,5,8,7,6,2,13,14,0,3,9,11]),l=B.create([11,14,15,12,5,8,7,9,11,13,14,15,6,7,9,8,7,6,8,13,11,9,7,15,7,12,15,9,11,7,13,12,11,13,6,7,14,9,13,15,14,8,13,6,5,12,7,5,11,12,14,15,14,15,9,8,9,14,5,6,8,6,5,12,9,15,5,11,6,8,13,12,5,12,13,14,11,8,5,6]),i=B.create([8,9,9,11,13,15,15,5,7,7,8,11,14,14,12,6,9,13,15,7,12,8,9,11,7,7,12,7,6,15,13,11,9,7,15,11,8,6,6,14,12,13,5,14,13,13,7,5,15,5,8,11,14,14,6,14,6,9,12,9,12,5,15,8,8,5,12,9,12,5,14,6,8,13,6,5,15,13,11,11]),d=B.create([0,1518500249,1859775393,2400959708,2840853838]),f=B.create([1352829926,1548603684,1836072691,2053994217,0]),h=F.RIPEMD160=o.extend({_doReset:function(){this._hash=B.create([1732584193,4023233417,2562383102,271733878,3285377520])},_doProcessBlock:function(A,D){for(var p=0;p<16;p++){var H=D+p,S=A[H];A[H]=(S<<8|S>>>24)&16711935|(S<<24|S>>>8)&4278255360}var q=this._hash.words,R=d.words,b=f.words,w=e.words,z=c.words,k=l.words,P=i.words,W,j,U,_,g,m,y,N,X,K;m=W=q[0],y=j=q[1],N=U=q[2],X=_=q[3],K=g=q[4];for(var L,p=0;p<80;p+=1)L=W+A[D+w[p]]|0,p<16?L+=v(j,U,_)+R[0]:p<32?L+=u(j,U,_)+R[1]:p<48?L+=C(j,U,_)+R[2]:p<64?L+=a(j,U,_)+R[3]:L+=s(j,U,_)+R[4],L=L|0,L=E(L,k[p]),L=L+g|0,W=g,g=_,_=E(U,10),U=j,j=L,L=m+A[D+z[p]]|0,p<16?L+=s(y,N,X)+b[0]:p<32?L+=a(y,N,X)+b[1]:p<48?L+=C(y,N,X)+b[2]:p<64?L+=u(y,N,X)+b[3]:L+=v(y,N,X)+b[4],L=L|0,L=E(L,P[p]),L=L+K|0,m=K,K=X,X=E(N,10),N=y,y=L;L=q[1]+U+X|0,q[1]=q[2]+_+K|0,q[2]=q[3]+g+m|0,q[3]=q[4]+W+y|0,q[4]=q[0]+j+N|0,q[0]=L},_doFinalize:function(){var A=this._data,D=A.words,p=this._nDataBytes*8,H=A.sigBytes*8;D[H>>>5]|=128<<24-H%32,D[(H+64>>>9<<4)+14]=(p<<8|p>>>24)&16711935|(p<<24|p>>>8)&4278255360,A.sigBytes=(D.length+1)*4,this._process();for(var S=this._hash,q=S.words,R=0;R<5;R++){var b=q[R];q[R]=(b<<8|b>>>24)&16711935|(b<<24|b>>>8)&4278255360}return S},clone:function(){var A=o.clone.call(this);return A._hash=this._hash.clone(),A}});function v(A,D,p){return A^D^p}function u(A,D,p){return A&D|~A&p}function C(A,D,p){return(A|~D)^p}function a(A,D,p){return A&p|D&~p}function s(A,D,p){return A^(D|~p)}function E(A,D){return A<<D|A>>>32-D}n.RIPEMD160=o._createHelper(h),n.HmacRIPEMD160=o._createHmacHelper(h)})(Math),r.RIPEMD160})});var R0=T((z0,Nx)=>{(function(r,x){typeof z0=="object"?Nx.exports=z0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(z0,function(r){(function(){var x=r,n=x.lib,t=n.Base,B=x.enc,o=B.Utf8,F=x.algo,e=F.HMAC=t.extend({init:function(c,l){c=this._hasher=new c.init,typeof l=="string"&&(l=o.parse(l));var i=c.blockSize,d=i*4;l.sigBytes>d&&(l=c.finalize(l)),l.clamp();for(var f=this._oKey=l.clone(),h=this._iKey=l.clone(),v=f.words,u=h.words,C=0;C<i;C++)v[C]^=1549556828,u[C]^=909522486;f.sigBytes=h.sigBytes=d,this.reset()},reset:function(){var c=this._hasher;c.reset(),c.update(this._iKey)},update:function(c){return this._hasher.update(c),this},finalize:function(c){var l=this._hasher,i=l.finalize(c);l.reset();var d=l.finalize(this._oKey.clone().concat(i));return d}})})()})});var Kx=T((W0,Ix)=>{(function(r,x,n){typeof W0=="object"?Ix.exports=W0=x(O(),k0(),R0()):typeof define=="function"&&define.amd?define(["./core","./sha256","./hmac"],x):x(r.CryptoJS)})(W0,function(r){return(function(){var x=r,n=x.lib,t=n.Base,B=n.WordArray,o=x.algo,F=o.SHA256,e=o.HMAC,c=o.PBKDF2=t.extend({cfg:t.extend({keySize:128/32,hasher:F,iterations:25e4}),init:function(l){this.cfg=this.cfg.extend(l)},compute:function(l,i){for(var d=this.cfg,f=e.create(d.hasher,l),h=B.create(),v=B.create([1]),u=h.words,C=v.words,a=d.keySize,s=d.iterations;u.length<a;){var E=f.update(i).finalize(v);f.reset();for(var A=E.words,D=A.length,p=E,H=1;H<s;H++){p=f.finalize(p),f.reset();for(var S=p.words,q=0;q<D;q++)A[q]^=S[q]}h.concat(E),C[0]++}return h.sigBytes=a*4,h}});x.PBKDF2=function(l,i,d){return c.create(d).compute(l,i)}})(),r.PBKDF2})});var $=T((P0,Gx)=>{(function(r,x,n){typeof P0=="object"?Gx.exports=P0=x(O(),ix(),R0()):typeof define=="function"&&define.amd?define(["./core","./sha1","./hmac"],x):x(r.CryptoJS)})(P0,function(r){return(function(){var x=r,n=x.lib,t=n.Base,B=n.WordArray,o=x.algo,F=o.MD5,e=o.EvpKDF=t.extend({cfg:t.extend({keySize:128/32,hasher:F,iterations:1}),init:function(c){this.cfg=this.cfg.extend(c)},compute:function(c,l){for(var i,d=this.cfg,f=d.hasher.create(),h=B.create(),v=h.words,u=d.keySize,C=d.iterations;v.length<u;){i&&f.update(i),i=f.update(c).finalize(l),f.reset();for(var a=1;a<C;a++)i=f.finalize(i),f.reset();h.concat(i)}return h.sigBytes=u*4,h}});x.EvpKDF=function(c,l,i){return e.create(i).compute(c,l)}})(),r.EvpKDF})});var I=T((j0,Zx)=>{(function(r,x,n){typeof j0=="object"?Zx.exports=j0=x(O(),$()):typeof define=="function"&&define.amd?define(["./core","./evpkdf"],x):x(r.CryptoJS)})(j0,function(r){r.lib.Cipher||(function(x){var n=r,t=n.lib,B=t.Base,o=t.WordArray,F=t.BufferedBlockAlgorithm,e=n.enc,c=e.Utf8,l=e.Base64,i=n.algo,d=i.EvpKDF,f=t.Cipher=F.extend({cfg:B.extend(),createEncryptor:function(b,w){return this.create(this._ENC_XFORM_MODE,b,w)},createDecryptor:function(b,w){return this.create(this._DEC_XFORM_MODE,b,w)},init:function(b,w,z){this.cfg=this.cfg.extend(z),this._xformMode=b,this._key=w,this.reset()},reset:function(){F.reset.call(this),this._doReset()},process:function(b){return this._append(b),this._process()},finalize:function(b){b&&this._append(b);var w=this._doFinalize();return w},keySize:128/32,ivSize:128/32,_ENC_XFORM_MODE:1,_DEC_XFORM_MODE:2,_createHelper:(function(){function b(w){return typeof w=="string"?R:H}return function(w){return{encrypt:function(z,k,P){return b(k).encrypt(w,z,k,P)},decrypt:function(z,k,P){return b(k).decrypt(w,z,k,P)}}}})()}),h=t.StreamCipher=f.extend({_doFinalize:function(){var b=this._process(!0);return b},blockSize:1}),v=n.mode={},u=t.BlockCipherMode=B.extend({createEncryptor:function(b,w){return this.Encryptor.create(b,w)},createDecryptor:function(b,w){return this.Decryptor.create(b,w)},init:function(b,w){this._cipher=b,this._iv=w}}),C=v.CBC=(function(){var b=u.extend();b.Encryptor=b.extend({processBlock:function(z,k){var P=this._cipher,W=P.blockSize;w.call(this,z,k,W),P.encryptBlock(z,k),this._prevBlock=z.slice(k,k+W)}}),b.Decryptor=b.extend({processBlock:function(z,k){var P=this._cipher,W=P.blockSize,j=z.slice(k,k+W);P.decryptBlock(z,k),w.call(this,z,k,W),this._prevBlock=j}});function w(z,k,P){var W,j=this._iv;j?(W=j,this._iv=x):W=this._prevBlock;for(var U=0;U<P;U++)z[k+U]^=W[U]}return b})(),a=n.pad={},s=a.Pkcs7={pad:function(b,w){for(var z=w*4,k=z-b.sigBytes%z,P=k<<24|k<<16|k<<8|k,W=[],j=0;j<k;j+=4)W.push(P);var U=o.create(W,k);b.concat(U)},unpad:function(b){var w=b.words[b.sigBytes-1>>>2]&255;b.sigBytes-=w}},E=t.BlockCipher=f.extend({cfg:f.cfg.extend({mode:C,padding:s}),reset:function(){var b;f.reset.call(this);var w=this.cfg,z=w.iv,k=w.mode;this._xformMode==this._ENC_XFORM_MODE?b=k.createEncryptor:(b=k.createDecryptor,this._minBufferSize=1),this._mode&&this._mode.__creator==b?this._mode.init(this,z&&z.words):(this._mode=b.call(k,this,z&&z.words),this._mode.__creator=b)},_doProcessBlock:function(b,w){this._mode.processBlock(b,w)},_doFinalize:function(){var b,w=this.cfg.padding;return this._xformMode==this._ENC_XFORM_MODE?(w.pad(this._data,this.blockSize),b=this._process(!0)):(b=this._process(!0),w.unpad(b)),b},blockSize:128/32}),A=t.CipherParams=B.extend({init:function(b){this.mixIn(b)},toString:function(b){return(b||this.formatter).stringify(this)}}),D=n.format={},p=D.OpenSSL={stringify:function(b){var w,z=b.ciphertext,k=b.salt;return k?w=o.create([1398893684,1701076831]).concat(k).concat(z):w=z,w.toString(l)},parse:function(b){var w,z=l.parse(b),k=z.words;return k[0]==1398893684&&k[1]==1701076831&&(w=o.create(k.slice(2,4)),k.splice(0,4),z.sigBytes-=16),A.create({ciphertext:z,salt:w})}},H=t.SerializableCipher=B.extend({cfg:B.extend({format:p}),encrypt:function(b,w,z,k){k=this.cfg.extend(k);var P=b.createEncryptor(z,k),W=P.finalize(w),j=P.cfg;return A.create({ciphertext:W,key:z,iv:j.iv,algorithm:b,mode:j.mode,padding:j.padding,blockSize:b.blockSize,formatter:k.format})},decrypt:function(b,w,z,k){k=this.cfg.extend(k),w=this._parse(w,k.format);var P=b.createDecryptor(z,k).finalize(w.ciphertext);return P},_parse:function(b,w){return typeof b=="string"?w.parse(b,this):b}}),S=n.kdf={},q=S.OpenSSL={execute:function(b,w,z,k,P){if(k||(k=o.random(64/8)),P)var W=d.create({keySize:w+z,hasher:P}).compute(b,k);else var W=d.create({keySize:w+z}).compute(b,k);var j=o.create(W.words.slice(w),z*4);return W.sigBytes=w*4,A.create({key:W,iv:j,salt:k})}},R=t.PasswordBasedCipher=H.extend({cfg:H.cfg.extend({kdf:q}),encrypt:function(b,w,z,k){k=this.cfg.extend(k);var P=k.kdf.execute(z,b.keySize,b.ivSize,k.salt,k.hasher);k.iv=P.iv;var W=H.encrypt.call(this,b,w,P.key,k);return W.mixIn(P),W},decrypt:function(b,w,z,k){k=this.cfg.extend(k),w=this._parse(w,k.format);var P=k.kdf.execute(z,b.keySize,b.ivSize,w.salt,k.hasher);k.iv=P.iv;var W=H.decrypt.call(this,b,w,P.key,k);return W}})})()})});var Qx=T((L0,Jx)=>{(function(r,x,n){typeof L0=="object"?Jx.exports=L0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(L0,function(r){return r.mode.CFB=(function(){var x=r.lib.BlockCipherMode.extend();x.Encryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize;n.call(this,t,B,F,o),this._prevBlock=t.slice(B,B+F)}}),x.Decryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize,e=t.slice(B,B+F);n.call(this,t,B,F,o),this._prevBlock=e}});function n(t,B,o,F){var e,c=this._iv;c?(e=c.slice(0),this._iv=void 0):e=this._prevBlock,F.encryptBlock(e,0);for(var l=0;l<o;l++)t[B+l]^=e[l]}return x})(),r.mode.CFB})});var $x=T((T0,Yx)=>{(function(r,x,n){typeof T0=="object"?Yx.exports=T0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(T0,function(r){return r.mode.CTR=(function(){var x=r.lib.BlockCipherMode.extend(),n=x.Encryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize,e=this._iv,c=this._counter;e&&(c=this._counter=e.slice(0),this._iv=void 0);var l=c.slice(0);o.encryptBlock(l,0),c[F-1]=c[F-1]+1|0;for(var i=0;i<F;i++)t[B+i]^=l[i]}});return x.Decryptor=n,x})(),r.mode.CTR})});var Mx=T((O0,Vx)=>{(function(r,x,n){typeof O0=="object"?Vx.exports=O0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(O0,function(r){return r.mode.CTRGladman=(function(){var x=r.lib.BlockCipherMode.extend();function n(o){if((o>>24&255)===255){var F=o>>16&255,e=o>>8&255,c=o&255;F===255?(F=0,e===255?(e=0,c===255?c=0:++c):++e):++F,o=0,o+=F<<16,o+=e<<8,o+=c}else o+=1<<24;return o}function t(o){return(o[0]=n(o[0]))===0&&(o[1]=n(o[1])),o}var B=x.Encryptor=x.extend({processBlock:function(o,F){var e=this._cipher,c=e.blockSize,l=this._iv,i=this._counter;l&&(i=this._counter=l.slice(0),this._iv=void 0),t(i);var d=i.slice(0);e.encryptBlock(d,0);for(var f=0;f<c;f++)o[F+f]^=d[f]}});return x.Decryptor=B,x})(),r.mode.CTRGladman})});var ee=T((U0,xe)=>{(function(r,x,n){typeof U0=="object"?xe.exports=U0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(U0,function(r){return r.mode.OFB=(function(){var x=r.lib.BlockCipherMode.extend(),n=x.Encryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize,e=this._iv,c=this._keystream;e&&(c=this._keystream=e.slice(0),this._iv=void 0),o.encryptBlock(c,0);for(var l=0;l<F;l++)t[B+l]^=c[l]}});return x.Decryptor=n,x})(),r.mode.OFB})});var te=T((X0,re)=>{(function(r,x,n){typeof X0=="object"?re.exports=X0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(X0,function(r){return r.mode.ECB=(function(){var x=r.lib.BlockCipherMode.extend();return x.Encryptor=x.extend({processBlock:function(n,t){this._cipher.encryptBlock(n,t)}}),x.Decryptor=x.extend({processBlock:function(n,t){this._cipher.decryptBlock(n,t)}}),x})(),r.mode.ECB})});var ne=T((N0,ie)=>{(function(r,x,n){typeof N0=="object"?ie.exports=N0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(N0,function(r){return r.pad.AnsiX923={pad:function(x,n){var t=x.sigBytes,B=n*4,o=B-t%B,F=t+o-1;x.clamp(),x.words[F>>>2]|=o<<24-F%4*8,x.sigBytes+=o},unpad:function(x){var n=x.words[x.sigBytes-1>>>2]&255;x.sigBytes-=n}},r.pad.Ansix923})});var fe=T((I0,ae)=>{(function(r,x,n){typeof I0=="object"?ae.exports=I0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(I0,function(r){return r.pad.Iso10126={pad:function(x,n){var t=n*4,B=t-x.sigBytes%t;x.concat(r.lib.WordArray.random(B-1)).concat(r.lib.WordArray.create([B<<24],1))},unpad:function(x){var n=x.words[x.sigBytes-1>>>2]&255;x.sigBytes-=n}},r.pad.Iso10126})});var ce=T((K0,oe)=>{(function(r,x,n){typeof K0=="object"?oe.exports=K0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(K0,function(r){return r.pad.Iso97971={pad:function(x,n){x.concat(r.lib.WordArray.create([2147483648],1)),r.pad.ZeroPadding.pad(x,n)},unpad:function(x){r.pad.ZeroPadding.unpad(x),x.sigBytes--}},r.pad.Iso97971})});var ve=T((G0,se)=>{(function(r,x,n){typeof G0=="object"?se.exports=G0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(G0,function(r){return r.pad.ZeroPadding={pad:function(x,n){var t=n*4;x.clamp(),x.sigBytes+=t-(x.sigBytes%t||t)},unpad:function(x){for(var n=x.words,t=x.sigBytes-1,t=x.sigBytes-1;t>=0;t--)if(n[t>>>2]>>>24-t%4*8&255){x.sigBytes=t+1;break}}},r.pad.ZeroPadding})});var Be=T((Z0,de)=>{(function(r,x,n){typeof Z0=="object"?de.exports=Z0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(Z0,function(r){return r.pad.NoPadding={pad:function(){},unpad:function(){}},r.pad.NoPadding})});var le=T((J0,he)=>{(function(r,x,n){typeof J0=="object"?he.exports=J0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(J0,function(r){return(function(x){var n=r,t=n.lib,B=t.CipherParams,o=n.enc,F=o.Hex,e=n.format,c=e.Hex={stringify:function(l){return l.ciphertext.toString(F)},parse:function(l){var i=F.parse(l);return B.create({ciphertext:i})}}})(),r.format.Hex})});var Ce=T((Q0,ue)=>{(function(r,x,n){typeof Q0=="object"?ue.exports=Q0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(Q0,function(r){return(function(){var x=r,n=x.lib,t=n.BlockCipher,B=x.algo,o=[],F=[],e=[],c=[],l=[],i=[],d=[],f=[],h=[],v=[];(function(){for(var a=[],s=0;s<256;s++)s<128?a[s]=s<<1:a[s]=s<<1^283;for(var E=0,A=0,s=0;s<256;s++){var D=A^A<<1^A<<2^A<<3^A<<4;D=D>>>8^D&255^99,o[E]=D,F[D]=E;var p=a[E],H=a[p],S=a[H],q=a[D]*257^D*16843008;e[E]=q<<24|q>>>8,c[E]=q<<16|q>>>16,l[E]=q<<8|q>>>24,i[E]=q;var q=S*16843009^H*65537^p*257^E*16843008;d[D]=q<<24|q>>>8,f[D]=q<<16|q>>>16,h[D]=q<<8|q>>>24,v[D]=q,E?(E=p^a[a[a[S^p]]],A^=a[a[A]]):E=A=1}})();var u=[0,1,2,4,8,16,32,64,128,27,54],C=B.AES=t.extend({_doReset:function(){var a;if(!(this._nRounds&&this._keyPriorReset===this._key)){for(var s=this._keyPriorReset=this._key,E=s.words,A=s.sigBytes/4,D=this._nRounds=A+6,p=(D+1)*4,H=this._keySchedule=[],S=0;S<p;S++)S<A?H[S]=E[S]:(a=H[S-1],S%A?A>6&&S%A==4&&(a=o[a>>>24]<<24|o[a>>>16&255]<<16|o[a>>>8&255]<<8|o[a&255]):(a=a<<8|a>>>24,a=o[a>>>24]<<24|o[a>>>16&255]<<16|o[a>>>8&255]<<8|o[a&255],a^=u[S/A|0]<<24),H[S]=H[S-A]^a);for(var q=this._invKeySchedule=[],R=0;R<p;R++){var S=p-R;if(R%4)var a=H[S];else var a=H[S-4];R<4||S<=4?q[R]=a:q[R]=d[o[a>>>24]]^f[o[a>>>16&255]]^h[o[a>>>8&255]]^v[o[a&255]]}}},encryptBlock:function(a,s){this._doCryptBlock(a,s,this._keySchedule,e,c,l,i,o)},decryptBlock:function(a,s){var E=a[s+1];a[s+1]=a[s+3],a[s+3]=E,this._doCryptBlock(a,s,this._invKeySchedule,d,f,h,v,F);var E=a[s+1];a[s+1]=a[s+3],a[s+3]=E},_doCryptBlock:function(a,s,E,A,D,p,H,S){for(var q=this._nRounds,R=a[s]^E[0],b=a[s+1]^E[1],w=a[s+2]^E[2],z=a[s+3]^E[3],k=4,P=1;P<q;P++){var W=A[R>>>24]^D[b>>>16&255]^p[w>>>8&255]^H[z&255]^E[k++],j=A[b>>>24]^D[w>>>16&255]^p[z>>>8&255]^H[R&255]^E[k++],U=A[w>>>24]^D[z>>>16&255]^p[R>>>8&255]^H[b&255]^E[k++],_=A[z>>>24]^D[R>>>16&255]^p[b>>>8&255]^H[w&255]^E[k++];R=W,b=j,w=U,z=_}var W=(S[R>>>24]<<24|S[b>>>16&255]<<16|S[w>>>8&255]<<8|S[z&255])^E[k++],j=(S[b>>>24]<<24|S[w>>>16&255]<<16|S[z>>>8&255]<<8|S[R&255])^E[k++],U=(S[w>>>24]<<24|S[z>>>16&255]<<16|S[R>>>8&255]<<8|S[b&255])^E[k++],_=(S[z>>>24]<<24|S[R>>>16&255]<<16|S[b>>>8&255]<<8|S[w&255])^E[k++];a[s]=W,a[s+1]=j,a[s+2]=U,a[s+3]=_},keySize:256/32});x.AES=t._createHelper(C)})(),r.AES})});var Ee=T((Y0,Ae)=>{(function(r,x,n){typeof Y0=="object"?Ae.exports=Y0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(Y0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=n.BlockCipher,o=x.algo,F=[57,49,41,33,25,17,9,1,58,50,42,34,26,18,10,2,59,51,43,35,27,19,11,3,60,52,44,36,63,55,47,39,31,23,15,7,62,54,46,38,30,22,14,6,61,53,45,37,29,21,13,5,28,20,12,4],e=[14,17,11,24,1,5,3,28,15,6,21,10,23,19,12,4,26,8,16,7,27,20,13,2,41,52,31,37,47,55,30,40,51,45,33,48,44,49,39,56,34,53,46,42,50,36,29,32],c=[1,2,4,6,8,10,12,14,15,17,19,21,23,25,27,28],l=[{0:8421888,268435456:32768,536870912:8421378,805306368:2,1073741824:512,1342177280:8421890,1610612736:8389122,1879048192:8388608,2147483648:514,2415919104:8389120,2684354560:33280,2952790016:8421376,3221225472:32770,3489660928:8388610,3758096384:0,4026531840:33282,134217728:0,402653184:8421890,671088640:33282,939524096:32768,1207959552:8421888,1476395008:512,1744830464:8421378,2013265920:2,2281701376:8389120,2550136832:33280,2818572288:8421376,3087007744:8389122,3355443200:8388610,3623878656:32770,3892314112:514,4160749568:8388608,1:32768,268435457:2,536870913:8421888,805306369:8388608,1073741825:8421378,1342177281:33280,1610612737:512,1879048193:8389122,2147483649:8421890,2415919105:8421376,2684354561:8388610,2952790017:33282,3221225473:514,3489660929:8389120,3758096385:32770,4026531841:0,134217729:8421890,402653185:8421376,671088641:8388608,939524097:512,1207959553:32768,1476395009:8388610,1744830465:2,2013265921:33282,2281701377:32770,2550136833:8389122,2818572289:514,3087007745:8421888,3355443201:8389120,3623878657:0,3892314113:33280,4160749569:8421378},{0:1074282512,16777216:16384,33554432:524288,50331648:1074266128,67108864:1073741840,83886080:1074282496,100663296:1073758208,117440512:16,134217728:540672,150994944:1073758224,167772160:1073741824,184549376:540688,201326592:524304,218103808:0,234881024:16400,251658240:1074266112,8388608:1073758208,25165824:540688,41943040:16,58720256:1073758224,75497472:1074282512,92274688:1073741824,109051904:524288,125829120:1074266128,142606336:524304,159383552:0,176160768:16384,192937984:1074266112,209715200:1073741840,226492416:540672,243269632:1074282496,260046848:16400,268435456:0,285212672:1074266128,301989888:1073758224,318767104:1074282496,335544320:1074266112,352321536:16,369098752:540688,385875968:16384,402653184:16400,419430400:524288,436207616:524304,452984832:1073741840,469762048:540672,486539264:1073758208,503316480:1073741824,520093696:1074282512,276824064:540688,293601280:524288,310378496:1074266112,327155712:16384,343932928:1073758208,360710144:1074282512,377487360:16,394264576:1073741824,411041792:1074282496,427819008:1073741840,444596224:1073758224,461373440:524304,478150656:0,494927872:16400,511705088:1074266128,528482304:540672},{0:260,1048576:0,2097152:67109120,3145728:65796,4194304:65540,5242880:67108868,6291456:67174660,7340032:67174400,8388608:67108864,9437184:67174656,10485760:65792,11534336:67174404,12582912:67109124,13631488:65536,14680064:4,15728640:256,524288:67174656,1572864:67174404,2621440:0,3670016:67109120,4718592:67108868,5767168:65536,6815744:65540,7864320:260,8912896:4,9961472:256,11010048:67174400,12058624:65796,13107200:65792,14155776:67109124,15204352:67174660,16252928:67108864,16777216:67174656,17825792:65540,18874368:65536,19922944:67109120,20971520:256,22020096:67174660,23068672:67108868,24117248:0,25165824:67109124,26214400:67108864,27262976:4,28311552:65792,29360128:67174400,30408704:260,31457280:65796,32505856:67174404,17301504:67108864,18350080:260,19398656:67174656,20447232:0,21495808:65540,22544384:67109120,23592960:256,24641536:67174404,25690112:65536,26738688:67174660,27787264:65796,28835840:67108868,29884416:67109124,30932992:67174400,31981568:4,33030144:65792},{0:2151682048,65536:2147487808,131072:4198464,196608:2151677952,262144:0,327680:4198400,393216:2147483712,458752:4194368,524288:2147483648,589824:4194304,655360:64,720896:2147487744,786432:2151678016,851968:4160,917504:4096,983040:2151682112,32768:2147487808,98304:64,163840:2151678016,229376:2147487744,294912:4198400,360448:2151682112,425984:0,491520:2151677952,557056:4096,622592:2151682048,688128:4194304,753664:4160,819200:2147483648,884736:4194368,950272:4198464,1015808:2147483712,1048576:4194368,1114112:4198400,1179648:2147483712,1245184:0,1310720:4160,1376256:2151678016,1441792:2151682048,1507328:2147487808,1572864:2151682112,1638400:2147483648,1703936:2151677952,1769472:4198464,1835008:2147487744,1900544:4194304,1966080:64,2031616:4096,1081344:2151677952,1146880:2151682112,1212416:0,1277952:4198400,1343488:4194368,1409024:2147483648,1474560:2147487808,1540096:64,1605632:2147483712,1671168:4096,1736704:2147487744,1802240:2151678016,1867776:4160,1933312:2151682048,1998848:4194304,2064384:4198464},{0:128,4096:17039360,8192:262144,12288:536870912,16384:537133184,20480:16777344,24576:553648256,28672:262272,32768:16777216,36864:537133056,40960:536871040,45056:553910400,49152:553910272,53248:0,57344:17039488,61440:553648128,2048:17039488,6144:553648256,10240:128,14336:17039360,18432:262144,22528:537133184,26624:553910272,30720:536870912,34816:537133056,38912:0,43008:553910400,47104:16777344,51200:536871040,55296:553648128,59392:16777216,63488:262272,65536:262144,69632:128,73728:536870912,77824:553648256,81920:16777344,86016:553910272,90112:537133184,94208:16777216,98304:553910400,102400:553648128,106496:17039360,110592:537133056,114688:262272,118784:536871040,122880:0,126976:17039488,67584:553648256,71680:16777216,75776:17039360,79872:537133184,83968:536870912,88064:17039488,92160:128,96256:553910272,100352:262272,104448:553910400,108544:0,112640:553648128,116736:16777344,120832:262144,124928:537133056,129024:536871040},{0:268435464,256:8192,512:270532608,768:270540808,1024:268443648,1280:2097152,1536:2097160,1792:268435456,2048:0,2304:268443656,2560:2105344,2816:8,3072:270532616,3328:2105352,3584:8200,3840:270540800,128:270532608,384:270540808,640:8,896:2097152,1152:2105352,1408:268435464,1664:268443648,1920:8200,2176:2097160,2432:8192,2688:268443656,2944:270532616,3200:0,3456:270540800,3712:2105344,3968:268435456,4096:268443648,4352:270532616,4608:270540808,4864:8200,5120:2097152,5376:268435456,5632:268435464,5888:2105344,6144:2105352,6400:0,6656:8,6912:270532608,7168:8192,7424:268443656,7680:270540800,7936:2097160,4224:8,4480:2105344,4736:2097152,4992:268435464,5248:268443648,5504:8200,5760:270540808,6016:270532608,6272:270540800,6528:270532616,6784:8192,7040:2105352,7296:2097160,7552:0,7808:268435456,8064:268443656},{0:1048576,16:33555457,32:1024,48:1049601,64:34604033,80:0,96:1,112:34603009,128:33555456,144:1048577,160:33554433,176:34604032,192:34603008,208:1025,224:1049600,240:33554432,8:34603009,24:0,40:33555457,56:34604032,72:1048576,88:33554433,104:33554432,120:1025,136:1049601,152:33555456,168:34603008,184:1048577,200:1024,216:34604033,232:1,248:1049600,256:33554432,272:1048576,288:33555457,304:34603009,320:1048577,336:33555456,352:34604032,368:1049601,384:1025,400:34604033,416:1049600,432:1,448:0,464:34603008,480:33554433,496:1024,264:1049600,280:33555457,296:34603009,312:1,328:33554432,344:1048576,360:1025,376:34604032,392:33554433,408:34603008,424:0,440:34604033,456:1049601,472:1024,488:33555456,504:1048577},{0:134219808,1:131072,2:134217728,3:32,4:131104,5:134350880,6:134350848,7:2048,8:134348800,9:134219776,10:133120,11:134348832,12:2080,13:0,14:134217760,15:133152,2147483648:2048,2147483649:134350880,2147483650:134219808,2147483651:134217728,2147483652:134348800,2147483653:133120,2147483654:133152,2147483655:32,2147483656:134217760,2147483657:2080,2147483658:131104,2147483659:134350848,2147483660:0,2147483661:134348832,2147483662:134219776,2147483663:131072,16:133152,17:134350848,18:32,19:2048,20:134219776,21:134217760,22:134348832,23:131072,24:0,25:131104,26:134348800,27:134219808,28:134350880,29:133120,30:2080,31:134217728,2147483664:131072,2147483665:2048,2147483666:134348832,2147483667:133152,2147483668:32,2147483669:134348800,2147483670:134217728,2147483671:134219808,2147483672:134350880,2147483673:134217760,2147483674:134219776,2147483675:0,2147483676:133120,2147483677:2080,2147483678:131104,2147483679:134350848}],i=[4160749569,528482304,33030144,2064384,129024,8064,504,2147483679],d=o.DES=B.extend({_doReset:function(){for(var u=this._key,C=u.words,a=[],s=0;s<56;s++){var E=F[s]-1;a[s]=C[E>>>5]>>>31-E%32&1}for(var A=this._subKeys=[],D=0;D<16;D++){for(var p=A[D]=[],H=c[D],s=0;s<24;s++)p[s/6|0]|=a[(e[s]-1+H)%28]<<31-s%6,p[4+(s/6|0)]|=a[28+(e[s+24]-1+H)%28]<<31-s%6;p[0]=p[0]<<1|p[0]>>>31;for(var s=1;s<7;s++)p[s]=p[s]>>>(s-1)*4+3;p[7]=p[7]<<5|p[7]>>>27}for(var S=this._invSubKeys=[],s=0;s<16;s++)S[s]=A[15-s]},encryptBlock:function(u,C){this._doCryptBlock(u,C,this._subKeys)},decryptBlock:function(u,C){this._doCryptBlock(u,C,this._invSubKeys)},_doCryptBlock:function(u,C,a){this._lBlock=u[C],this._rBlock=u[C+1],f.call(this,4,252645135),f.call(this,16,65535),h.call(this,2,858993459),h.call(this,8,16711935),f.call(this,1,1431655765);for(var s=0;s<16;s++){for(var E=a[s],A=this._lBlock,D=this._rBlock,p=0,H=0;H<8;H++)p|=l[H][((D^E[H])&i[H])>>>0];this._lBlock=D,this._rBlock=A^p}var S=this._lBlock;this._lBlock=this._rBlock,this._rBlock=S,f.call(this,1,1431655765),h.call(this,8,16711935),h.call(this,2,858993459),f.call(this,16,65535),f.call(this,4,252645135),u[C]=this._lBlock,u[C+1]=this._rBlock},keySize:64/32,ivSize:64/32,blockSize:64/32});function f(u,C){var a=(this._lBlock>>>u^this._rBlock)&C;this._rBlock^=a,this._lBlock^=a<<u}function h(u,C){var a=(this._rBlock>>>u^this._lBlock)&C;this._lBlock^=a,this._rBlock^=a<<u}x.DES=B._createHelper(d);var v=o.TripleDES=B.extend({_doReset:function(){var u=this._key,C=u.words;if(C.length!==2&&C.length!==4&&C.length<6)throw new Error("Invalid key length - 3DES requires the key length to be 64, 128, 192 or >192.");var a=C.slice(0,2),s=C.length<4?C.slice(0,2):C.slice(2,4),E=C.length<6?C.slice(0,2):C.slice(4,6);this._des1=d.createEncryptor(t.create(a)),this._des2=d.createEncryptor(t.create(s)),this._des3=d.createEncryptor(t.create(E))},encryptBlock:function(u,C){this._des1.encryptBlock(u,C),this._des2.decryptBlock(u,C),this._des3.encryptBlock(u,C)},decryptBlock:function(u,C){this._des3.decryptBlock(u,C),this._des2.encryptBlock(u,C),this._des1.decryptBlock(u,C)},keySize:192/32,ivSize:64/32,blockSize:64/32});x.TripleDES=B._createHelper(v)})(),r.TripleDES})});var De=T(($0,Fe)=>{(function(r,x,n){typeof $0=="object"?Fe.exports=$0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})($0,function(r){return(function(){var x=r,n=x.lib,t=n.StreamCipher,B=x.algo,o=B.RC4=t.extend({_doReset:function(){for(var c=this._key,l=c.words,i=c.sigBytes,d=this._S=[],f=0;f<256;f++)d[f]=f;for(var f=0,h=0;f<256;f++){var v=f%i,u=l[v>>>2]>>>24-v%4*8&255;h=(h+d[f]+u)%256;var C=d[f];d[f]=d[h],d[h]=C}this._i=this._j=0},_doProcessBlock:function(c,l){c[l]^=F.call(this)},keySize:256/32,ivSize:0});function F(){for(var c=this._S,l=this._i,i=this._j,d=0,f=0;f<4;f++){l=(l+1)%256,i=(i+c[l])%256;var h=c[l];c[l]=c[i],c[i]=h,d|=c[(c[l]+c[i])%256]<<24-f*8}return this._i=l,this._j=i,d}x.RC4=t._createHelper(o);var e=B.RC4Drop=o.extend({cfg:o.cfg.extend({drop:192}),_doReset:function(){o._doReset.call(this);for(var c=this.cfg.drop;c>0;c--)F.call(this)}});x.RC4Drop=t._createHelper(e)})(),r.RC4})});var be=T((V0,pe)=>{(function(r,x,n){typeof V0=="object"?pe.exports=V0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(V0,function(r){return(function(){var x=r,n=x.lib,t=n.StreamCipher,B=x.algo,o=[],F=[],e=[],c=B.Rabbit=t.extend({_doReset:function(){for(var i=this._key.words,d=this.cfg.iv,f=0;f<4;f++)i[f]=(i[f]<<8|i[f]>>>24)&16711935|(i[f]<<24|i[f]>>>8)&4278255360;var h=this._X=[i[0],i[3]<<16|i[2]>>>16,i[1],i[0]<<16|i[3]>>>16,i[2],i[1]<<16|i[0]>>>16,i[3],i[2]<<16|i[1]>>>16],v=this._C=[i[2]<<16|i[2]>>>16,i[0]&4294901760|i[1]&65535,i[3]<<16|i[3]>>>16,i[1]&4294901760|i[2]&65535,i[0]<<16|i[0]>>>16,i[2]&4294901760|i[3]&65535,i[1]<<16|i[1]>>>16,i[3]&4294901760|i[0]&65535];this._b=0;for(var f=0;f<4;f++)l.call(this);for(var f=0;f<8;f++)v[f]^=h[f+4&7];if(d){var u=d.words,C=u[0],a=u[1],s=(C<<8|C>>>24)&16711935|(C<<24|C>>>8)&4278255360,E=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360,A=s>>>16|E&4294901760,D=E<<16|s&65535;v[0]^=s,v[1]^=A,v[2]^=E,v[3]^=D,v[4]^=s,v[5]^=A,v[6]^=E,v[7]^=D;for(var f=0;f<4;f++)l.call(this)}},_doProcessBlock:function(i,d){var f=this._X;l.call(this),o[0]=f[0]^f[5]>>>16^f[3]<<16,o[1]=f[2]^f[7]>>>16^f[5]<<16,o[2]=f[4]^f[1]>>>16^f[7]<<16,o[3]=f[6]^f[3]>>>16^f[1]<<16;for(var h=0;h<4;h++)o[h]=(o[h]<<8|o[h]>>>24)&16711935|(o[h]<<24|o[h]>>>8)&4278255360,i[d+h]^=o[h]},blockSize:128/32,ivSize:64/32});function l(){for(var i=this._X,d=this._C,f=0;f<8;f++)F[f]=d[f];d[0]=d[0]+1295307597+this._b|0,d[1]=d[1]+3545052371+(d[0]>>>0<F[0]>>>0?1:0)|0,d[2]=d[2]+886263092+(d[1]>>>0<F[1]>>>0?1:0)|0,d[3]=d[3]+1295307597+(d[2]>>>0<F[2]>>>0?1:0)|0,d[4]=d[4]+3545052371+(d[3]>>>0<F[3]>>>0?1:0)|0,d[5]=d[5]+886263092+(d[4]>>>0<F[4]>>>0?1:0)|0,d[6]=d[6]+1295307597+(d[5]>>>0<F[5]>>>0?1:0)|0,d[7]=d[7]+3545052371+(d[6]>>>0<F[6]>>>0?1:0)|0,this._b=d[7]>>>0<F[7]>>>0?1:0;for(var f=0;f<8;f++){var h=i[f]+d[f],v=h&65535,u=h>>>16,C=((v*v>>>17)+v*u>>>15)+u*u,a=((h&4294901760)*h|0)+((h&65535)*h|0);e[f]=C^a}i[0]=e[0]+(e[7]<<16|e[7]>>>16)+(e[6]<<16|e[6]>>>16)|0,i[1]=e[1]+(e[0]<<8|e[0]>>>24)+e[7]|0,i[2]=e[2]+(e[1]<<16|e[1]>>>16)+(e[0]<<16|e[0]>>>16)|0,i[3]=e[3]+(e[2]<<8|e[2]>>>24)+e[1]|0,i[4]=e[4]+(e[3]<<16|e[3]>>>16)+(e[2]<<16|e[2]>>>16)|0,i[5]=e[5]+(e[4]<<8|e[4]>>>24)+e[3]|0,i[6]=e[6]+(e[5]<<16|e[5]>>>16)+(e[4]<<16|e[4]>>>16)|0,i[7]=e[7]+(e[6]<<8|e[6]>>>24)+e[5]|0}x.Rabbit=t._createHelper(c)})(),r.Rabbit})});var ye=T((M0,_e)=>{(function(r,x,n){typeof M0=="object"?_e.exports=M0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(M0,function(r){return(function(){var x=r,n=x.lib,t=n.StreamCipher,B=x.algo,o=[],F=[],e=[],c=B.RabbitLegacy=t.extend({_doReset:function(){var i=this._key.words,d=this.cfg.iv,f=this._X=[i[0],i[3]<<16|i[2]>>>16,i[1],i[0]<<16|i[3]>>>16,i[2],i[1]<<16|i[0]>>>16,i[3],i[2]<<16|i[1]>>>16],h=this._C=[i[2]<<16|i[2]>>>16,i[0]&4294901760|i[1]&65535,i[3]<<16|i[3]>>>16,i[1]&4294901760|i[2]&65535,i[0]<<16|i[0]>>>16,i[2]&4294901760|i[3]&65535,i[1]<<16|i[1]>>>16,i[3]&4294901760|i[0]&65535];this._b=0;for(var v=0;v<4;v++)l.call(this);for(var v=0;v<8;v++)h[v]^=f[v+4&7];if(d){var u=d.words,C=u[0],a=u[1],s=(C<<8|C>>>24)&16711935|(C<<24|C>>>8)&4278255360,E=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360,A=s>>>16|E&4294901760,D=E<<16|s&65535;h[0]^=s,h[1]^=A,h[2]^=E,h[3]^=D,h[4]^=s,h[5]^=A,h[6]^=E,h[7]^=D;for(var v=0;v<4;v++)l.call(this)}},_doProcessBlock:function(i,d){var f=this._X;l.call(this),o[0]=f[0]^f[5]>>>16^f[3]<<16,o[1]=f[2]^f[7]>>>16^f[5]<<16,o[2]=f[4]^f[1]>>>16^f[7]<<16,o[3]=f[6]^f[3]>>>16^f[1]<<16;for(var h=0;h<4;h++)o[h]=(o[h]<<8|o[h]>>>24)&16711935|(o[h]<<24|o[h]>>>8)&4278255360,i[d+h]^=o[h]},blockSize:128/32,ivSize:64/32});function l(){for(var i=this._X,d=this._C,f=0;f<8;f++)F[f]=d[f];d[0]=d[0]+1295307597+this._b|0,d[1]=d[1]+3545052371+(d[0]>>>0<F[0]>>>0?1:0)|0,d[2]=d[2]+886263092+(d[1]>>>0<F[1]>>>0?1:0)|0,d[3]=d[3]+1295307597+(d[2]>>>0<F[2]>>>0?1:0)|0,d[4]=d[4]+3545052371+(d[3]>>>0<F[3]>>>0?1:0)|0,d[5]=d[5]+886263092+(d[4]>>>0<F[4]>>>0?1:0)|0,d[6]=d[6]+1295307597+(d[5]>>>0<F[5]>>>0?1:0)|0,d[7]=d[7]+3545052371+(d[6]>>>0<F[6]>>>0?1:0)|0,this._b=d[7]>>>0<F[7]>>>0?1:0;for(var f=0;f<8;f++){var h=i[f]+d[f],v=h&65535,u=h>>>16,C=((v*v>>>17)+v*u>>>15)+u*u,a=((h&4294901760)*h|0)+((h&65535)*h|0);e[f]=C^a}i[0]=e[0]+(e[7]<<16|e[7]>>>16)+(e[6]<<16|e[6]>>>16)|0,i[1]=e[1]+(e[0]<<8|e[0]>>>24)+e[7]|0,i[2]=e[2]+(e[1]<<16|e[1]>>>16)+(e[0]<<16|e[0]>>>16)|0,i[3]=e[3]+(e[2]<<8|e[2]>>>24)+e[1]|0,i[4]=e[4]+(e[3]<<16|e[3]>>>16)+(e[2]<<16|e[2]>>>16)|0,i[5]=e[5]+(e[4]<<8|e[4]>>>24)+e[3]|0,i[6]=e[6]+(e[5]<<16|e[5]>>>16)+(e[4]<<16|e[4]>>>16)|0,i[7]=e[7]+(e[6]<<8|e[6]>>>24)+e[5]|0}x.RabbitLegacy=t._createHelper(c)})(),r.RabbitLegacy})});var ke=T((xx,ge)=>{(function(r,x,n){typeof xx=="object"?ge.exports=xx=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(xx,function(r){return(function(){var x=r,n=x.lib,t=n.BlockCipher,B=x.algo;let o=16,F=[608135816,2242054355,320440878,57701188,2752067618,698298832,137296536,3964562569,1160258022,953160567,3193202383,887688300,3232508343,3380367581,1065670069,3041331479,2450970073,2306472731],e=[[3509652390,2564797868,805139163,3491422135,3101798381,1780907670,3128725573,4046225305,614570311,3012652279,134345442,2240740374,1667834072,1901547113,2757295779,4103290238,227898511,1921955416,1904987480,2182433518,2069144605,3260701109,2620446009,720527379,3318853667,677414384,3393288472,3101374703,2390351024,1614419982,1822297739,2954791486,3608508353,3174124327,2024746970,1432378464,3864339955,2857741204,1464375394,1676153920,1439316330,715854006,3033291828,289532110,2706671279,2087905683,3018724369,1668267050,732546397,1947742710,3462151702,2609353502,2950085171,1814351708,2050118529,680887927,999245976,1800124847,3300911131,1713906067,1641548236,4213287313,1216130144,1575780402,4018429277,3917837745,3693486850,3949271944,596196993,3549867205,258830323,2213823033,772490370,2760122372,1774776394,2652871518,566650946,4142492826,1728879713,2882767088,1783734482,3629395816,2517608232,2874225571,1861159788,326777828,3124490320,2130389656,2716951837,967770486,1724537150,2185432712,2364442137,1164943284,2105845187,998989502,3765401048,2244026483,1075463327,1455516326,1322494562,910128902,469688178,1117454909,936433444,3490320968,3675253459,1240580251,122909385,2157517691,634681816,4142456567,3825094682,3061402683,2540495037,79693498,3249098678,1084186820,1583128258,426386531,1761308591,1047286709,322548459,995290223,1845252383,2603652396,3431023940,2942221577,3202600964,3727903485,1712269319,422464435,3234572375,1170764815,3523960633,3117677531,1434042557,442511882,3600875718,1076654713,1738483198,4213154764,2393238008,3677496056,1014306527,4251020053,793779912,2902807211,842905082,4246964064,1395751752,1040244610,2656851899,3396308128,445077038,3742853595,3577915638,679411651,2892444358,2354009459,1767581616,3150600392,3791627101,3102740896,284835224,4246832056,1258075500,768725851,2589189241,3069724005,3532540348,1274779536,3789419226,2764799539,1660621633,3471099624,4011903706,913787905,3497959166,737222580,2514213453,2928710040,3937242737,1804850592,3499020752,2949064160,2386320175,2390070455,2415321851,4061277028,2290661394,2416832540,1336762016,1754252060,3520065937,3014181293,791618072,3188594551,3933548030,2332172193,3852520463,3043980520,413987798,3465142937,3030929376,4245938359,2093235073,3534596313,375366246,2157278981,2479649556,555357303,3870105701,2008414854,3344188149,4221384143,3956125452,2067696032,3594591187,2921233993,2428461,544322398,577241275,1471733935,610547355,4027169054,1432588573,1507829418,2025931657,3646575487,545086370,48609733,2200306550,1653985193,298326376,1316178497,3007786442,2064951626,458293330,2589141269,3591329599,3164325604,727753846,2179363840,146436021,1461446943,4069977195,705550613,3059967265,3887724982,4281599278,3313849956,1404054877,2845806497,146425753,1854211946],[1266315497,3048417604,3681880366,3289982499,290971e4,1235738493,2632868024,2414719590,3970600049,1771706367,1449415276,3266420449,422970021,1963543593,2690192192,3826793022,1062508698,1531092325,1804592342,2583117782,2714934279,4024971509,1294809318,4028980673,1289560198,2221992742,1669523910,35572830,157838143,1052438473,1016535060,1802137761,1753167236,1386275462,3080475397,2857371447,1040679964,2145300060,2390574316,1461121720,2956646967,4031777805,4028374788,33600511,2920084762,1018524850,629373528,3691585981,3515945977,2091462646,2486323059,586499841,988145025,935516892,3367335476,2599673255,2839830854,265290510,3972581182,2759138881,3795373465,1005194799,847297441,406762289,1314163512,1332590856,1866599683,4127851711,750260880,613907577,1450815602,3165620655,3734664991,3650291728,3012275730,3704569646,1427272223,778793252,1343938022,2676280711,2052605720,1946737175,3164576444,3914038668,3967478842,3682934266,1661551462,3294938066,4011595847,840292616,3712170807,616741398,312560963,711312465,1351876610,322626781,1910503582,271666773,2175563734,1594956187,70604529,3617834859,1007753275,1495573769,4069517037,2549218298,2663038764,504708206,2263041392,3941167025,2249088522,1514023603,1998579484,1312622330,694541497,2582060303,2151582166,1382467621,776784248,2618340202,3323268794,2497899128,2784771155,503983604,4076293799,907881277,423175695,432175456,1378068232,4145222326,3954048622,3938656102,3820766613,2793130115,2977904593,26017576,3274890735,3194772133,1700274565,1756076034,4006520079,3677328699,720338349,1533947780,354530856,688349552,3973924725,1637815568,332179504,3949051286,53804574,2852348879,3044236432,1282449977,3583942155,3416972820,4006381244,1617046695,2628476075,3002303598,1686838959,431878346,2686675385,1700445008,1080580658,1009431731,832498133,3223435511,2605976345,2271191193,2516031870,1648197032,4164389018,2548247927,300782431,375919233,238389289,3353747414,2531188641,2019080857,1475708069,455242339,2609103871,448939670,3451063019,1395535956,2413381860,1841049896,1491858159,885456874,4264095073,4001119347,1565136089,3898914787,1108368660,540939232,1173283510,2745871338,3681308437,4207628240,3343053890,4016749493,1699691293,1103962373,3625875870,2256883143,3830138730,1031889488,3479347698,1535977030,4236805024,3251091107,2132092099,1774941330,1199868427,1452454533,157007616,2904115357,342012276,595725824,1480756522,206960106,497939518,591360097,863170706,2375253569,3596610801,1814182875,2094937945,3421402208,1082520231,3463918190,2785509508,435703966,3908032597,1641649973,2842273706,3305899714,1510255612,2148256476,2655287854,3276092548,4258621189,236887753,3681803219,274041037,1734335097,3815195456,3317970021,1899903192,1026095262,4050517792,356393447,2410691914,3873677099,3682840055],[3913112168,2491498743,4132185628,2489919796,1091903735,1979897079,3170134830,3567386728,3557303409,857797738,1136121015,1342202287,507115054,2535736646,337727348,3213592640,1301675037,2528481711,1895095763,1721773893,3216771564,62756741,2142006736,835421444,2531993523,1442658625,3659876326,2882144922,676362277,1392781812,170690266,3921047035,1759253602,3611846912,1745797284,664899054,1329594018,3901205900,3045908486,2062866102,2865634940,3543621612,3464012697,1080764994,553557557,3656615353,3996768171,991055499,499776247,1265440854,648242737,3940784050,980351604,3713745714,1749149687,3396870395,4211799374,3640570775,1161844396,3125318951,1431517754,545492359,4268468663,3499529547,1437099964,2702547544,3433638243,2581715763,2787789398,1060185593,1593081372,2418618748,4260947970,69676912,2159744348,86519011,2512459080,3838209314,1220612927,3339683548,133810670,1090789135,1078426020,1569222167,845107691,3583754449,4072456591,1091646820,628848692,1613405280,3757631651,526609435,236106946,48312990,2942717905,3402727701,1797494240,859738849,992217954,4005476642,2243076622,3870952857,3732016268,765654824,3490871365,2511836413,1685915746,3888969200,1414112111,2273134842,3281911079,4080962846,172450625,2569994100,980381355,4109958455,2819808352,2716589560,2568741196,3681446669,3329971472,1835478071,660984891,3704678404,4045999559,3422617507,3040415634,1762651403,1719377915,3470491036,2693910283,3642056355,3138596744,1364962596,2073328063,1983633131,926494387,3423689081,2150032023,4096667949,1749200295,3328846651,309677260,2016342300,1779581495,3079819751,111262694,1274766160,443224088,298511866,1025883608,3806446537,1145181785,168956806,3641502830,3584813610,1689216846,3666258015,3200248200,1692713982,2646376535,4042768518,1618508792,1610833997,3523052358,4130873264,2001055236,3610705100,2202168115,4028541809,2961195399,1006657119,2006996926,3186142756,1430667929,3210227297,1314452623,4074634658,4101304120,2273951170,1399257539,3367210612,3027628629,1190975929,2062231137,2333990788,2221543033,2438960610,1181637006,548689776,2362791313,3372408396,3104550113,3145860560,296247880,1970579870,3078560182,3769228297,1714227617,3291629107,3898220290,166772364,1251581989,493813264,448347421,195405023,2709975567,677966185,3703036547,1463355134,2715995803,1338867538,1343315457,2802222074,2684532164,233230375,2599980071,2000651841,3277868038,1638401717,4028070440,3237316320,6314154,819756386,300326615,590932579,1405279636,3267499572,3150704214,2428286686,3959192993,3461946742,1862657033,1266418056,963775037,2089974820,2263052895,1917689273,448879540,3550394620,3981727096,150775221,3627908307,1303187396,508620638,2975983352,2726630617,1817252668,1876281319,1457606340,908771278,3720792119,3617206836,2455994898,1729034894,1080033504],[976866871,3556439503,2881648439,1522871579,1555064734,1336096578,3548522304,2579274686,3574697629,3205460757,3593280638,3338716283,3079412587,564236357,2993598910,1781952180,1464380207,3163844217,3332601554,1699332808,1393555694,1183702653,3581086237,1288719814,691649499,2847557200,2895455976,3193889540,2717570544,1781354906,1676643554,2592534050,3230253752,1126444790,2770207658,2633158820,2210423226,2615765581,2414155088,3127139286,673620729,2805611233,1269405062,4015350505,3341807571,4149409754,1057255273,2012875353,2162469141,2276492801,2601117357,993977747,3918593370,2654263191,753973209,36408145,2530585658,25011837,3520020182,2088578344,530523599,2918365339,1524020338,1518925132,3760827505,3759777254,1202760957,3985898139,3906192525,674977740,4174734889,2031300136,2019492241,3983892565,4153806404,3822280332,352677332,2297720250,60907813,90501309,3286998549,1016092578,2535922412,2839152426,457141659,509813237,4120667899,652014361,1966332200,2975202805,55981186,2327461051,676427537,3255491064,2882294119,3433927263,1307055953,942726286,933058658,2468411793,3933900994,4215176142,1361170020,2001714738,2830558078,3274259782,1222529897,1679025792,2729314320,3714953764,1770335741,151462246,3013232138,1682292957,1483529935,471910574,1539241949,458788160,3436315007,1807016891,3718408830,978976581,1043663428,3165965781,1927990952,4200891579,2372276910,3208408903,3533431907,1412390302,2931980059,4132332400,1947078029,3881505623,4168226417,2941484381,1077988104,1320477388,886195818,18198404,3786409e3,2509781533,112762804,3463356488,1866414978,891333506,18488651,661792760,1628790961,3885187036,3141171499,876946877,2693282273,1372485963,791857591,2686433993,3759982718,3167212022,3472953795,2716379847,445679433,3561995674,3504004811,3574258232,54117162,3331405415,2381918588,3769707343,4154350007,1140177722,4074052095,668550556,3214352940,367459370,261225585,2610173221,4209349473,3468074219,3265815641,314222801,3066103646,3808782860,282218597,3406013506,3773591054,379116347,1285071038,846784868,2669647154,3771962079,3550491691,2305946142,453669953,1268987020,3317592352,3279303384,3744833421,2610507566,3859509063,266596637,3847019092,517658769,3462560207,3443424879,370717030,4247526661,2224018117,4143653529,4112773975,2788324899,2477274417,1456262402,2901442914,1517677493,1846949527,2295493580,3734397586,2176403920,1280348187,1908823572,3871786941,846861322,1172426758,3287448474,3383383037,1655181056,3139813346,901632758,1897031941,2986607138,3066810236,3447102507,1393639104,373351379,950779232,625454576,3124240540,4148612726,2007998917,544563296,2244738638,2330496472,2058025392,1291430526,424198748,50039436,29584100,3605783033,2429876329,2791104160,1057563949,3255363231,3075367218,3463963227,1469046755,985887462]];var c={pbox:[],sbox:[]};function l(v,u){let C=u>>24&255,a=u>>16&255,s=u>>8&255,E=u&255,A=v.sbox[0][C]+v.sbox[1][a];return A=A^v.sbox[2][s],A=A+v.sbox[3][E],A}function i(v,u,C){let a=u,s=C,E;for(let A=0;A<o;++A)a=a^v.pbox[A],s=l(v,a)^s,E=a,a=s,s=E;return E=a,a=s,s=E,s=s^v.pbox[o],a=a^v.pbox[o+1],{left:a,right:s}}function d(v,u,C){let a=u,s=C,E;for(let A=o+1;A>1;--A)a=a^v.pbox[A],s=l(v,a)^s,E=a,a=s,s=E;return E=a,a=s,s=E,s=s^v.pbox[1],a=a^v.pbox[0],{left:a,right:s}}function f(v,u,C){for(let D=0;D<4;D++){v.sbox[D]=[];for(let p=0;p<256;p++)v.sbox[D][p]=e[D][p]}let a=0;for(let D=0;D<o+2;D++)v.pbox[D]=F[D]^u[a],a++,a>=C&&(a=0);let s=0,E=0,A=0;for(let D=0;D<o+2;D+=2)A=i(v,s,E),s=A.left,E=A.right,v.pbox[D]=s,v.pbox[D+1]=E;for(let D=0;D<4;D++)for(let p=0;p<256;p+=2)A=i(v,s,E),s=A.left,E=A.right,v.sbox[D][p]=s,v.sbox[D][p+1]=E;return!0}var h=B.Blowfish=t.extend({_doReset:function(){if(this._keyPriorReset!==this._key){var v=this._keyPriorReset=this._key,u=v.words,C=v.sigBytes/4;f(c,u,C)}},encryptBlock:function(v,u){var C=i(c,v[u],v[u+1]);v[u]=C.left,v[u+1]=C.right},decryptBlock:function(v,u){var C=d(c,v[u],v[u+1]);v[u]=C.left,v[u+1]=C.right},blockSize:64/32,keySize:128/32,ivSize:64/32});x.Blowfish=t._createHelper(h)})(),r.Blowfish})});var Se=T((ex,me)=>{(function(r,x,n){typeof ex=="object"?me.exports=ex=x(O(),B0(),yx(),kx(),e0(),wx(),r0(),ix(),k0(),Wx(),nx(),Lx(),Ox(),Xx(),R0(),Kx(),$(),I(),Qx(),$x(),Mx(),ee(),te(),ne(),fe(),ce(),ve(),Be(),le(),Ce(),Ee(),De(),be(),ye(),ke()):typeof define=="function"&&define.amd?define(["./core","./x64-core","./lib-typedarrays","./enc-utf16","./enc-base64","./enc-base64url","./md5","./sha1","./sha256","./sha224","./sha512","./sha384","./sha3","./ripemd160","./hmac","./pbkdf2","./evpkdf","./cipher-core","./mode-cfb","./mode-ctr","./mode-ctr-gladman","./mode-ofb","./mode-ecb","./pad-ansix923","./pad-iso10126","./pad-iso97971","./pad-zeropadding","./pad-nopadding","./format-hex","./aes","./tripledes","./rc4","./rabbit","./rabbit-legacy","./blowfish"],x):r.CryptoJS=x(r.CryptoJS)})(ex,function(r){return r})});var sr={};$e(sr,{genOuput:()=>cr,genReq:()=>or});var V=Ve(Se(),1);function ax(r,x){let[n,t,B,o]=r;function F(i,d,f,h,v,u,C){return i=i+(d&f|~d&h)+v+C|0,(i<<u|i>>>32-u)+d|0}function e(i,d,f,h,v,u,C){return i=i+(d&h|f&~h)+v+C|0,(i<<u|i>>>32-u)+d|0}function c(i,d,f,h,v,u,C){return i=i+(d^f^h)+v+C|0,(i<<u|i>>>32-u)+d|0}function l(i,d,f,h,v,u,C){return i=i+(f^(d|~h))+v+C|0,(i<<u|i>>>32-u)+d|0}n=F(n,t,B,o,x[0],7,-680876936),o=F(o,n,t,B,x[1],12,-389564586),B=F(B,o,n,t,x[2],17,606105819),t=F(t,B,o,n,x[3],22,-1044525330),n=F(n,t,B,o,x[4],7,-176418897),o=F(o,n,t,B,x[5],12,1200080426),B=F(B,o,n,t,x[6],17,-1473231341),t=F(t,B,o,n,x[7],22,-45705983),n=F(n,t,B,o,x[8],7,1770035416),o=F(o,n,t,B,x[9],12,-1958414417),B=F(B,o,n,t,x[10],17,-42063),t=F(t,B,o,n,x[11],22,-1990404162),n=F(n,t,B,o,x[12],7,1804603682),o=F(o,n,t,B,x[13],12,-40341101),B=F(B,o,n,t,x[14],17,-1502002290),t=F(t,B,o,n,x[15],22,1236535329),n=e(n,t,B,o,x[1],5,-165796510),o=e(o,n,t,B,x[6],9,-1069501632),B=e(B,o,n,t,x[11],14,643717713),t=e(t,B,o,n,x[0],20,-373897302),n=e(n,t,B,o,x[5],5,-701558691),o=e(o,n,t,B,x[10],9,38016083),B=e(B,o,n,t,x[15],14,-660478335),t=e(t,B,o,n,x[4],20,-405537848),n=e(n,t,B,o,x[9],5,568446438),o=e(o,n,t,B,x[14],9,-1019803690),B=e(B,o,n,t,x[3],14,-187363961),t=e(t,B,o,n,x[8],20,1163531501),n=e(n,t,B,o,x[13],5,-1444681467),o=e(o,n,t,B,x[2],9,-51403784),B=e(B,o,n,t,x[7],14,1735328473),t=e(t,B,o,n,x[12],20,-1926607734),n=c(n,t,B,o,x[5],4,-378558),o=c(o,n,t,B,x[8],11,-2022574463),B=c(B,o,n,t,x[11],16,1839030562),t=c(t,B,o,n,x[14],23,-35309556),n=c(n,t,B,o,x[1],4,-1530992060),o=c(o,n,t,B,x[4],11,1272893353),B=c(B,o,n,t,x[7],16,-155497632),t=c(t,B,o,n,x[10],23,-1094730640),n=c(n,t,B,o,x[13],4,681279174),o=c(o,n,t,B,x[0],11,-358537222),B=c(B,o,n,t,x[3],16,-722521979),t=c(t,B,o,n,x[6],23,76029189),n=c(n,t,B,o,x[9],4,-640364487),o=c(o,n,t,B,x[12],11,-421815835),B=c(B,o,n,t,x[15],16,530742520),t=c(t,B,o,n,x[2],23,-995338651),n=l(n,t,B,o,x[0],6,-198630844),o=l(o,n,t,B,x[7],10,1126891415),B=l(B,o,n,t,x[14],15,-1416354905),t=l(t,B,o,n,x[5],21,-57434055),n=l(n,t,B,o,x[12],6,1700485571),o=l(o,n,t,B,x[3],10,-1894986606),B=l(B,o,n,t,x[10],15,-1051523),t=l(t,B,o,n,x[1],21,-2054922799),n=l(n,t,B,o,x[8],6,1873313359),o=l(o,n,t,B,x[15],10,-30611744),B=l(B,o,n,t,x[6],15,-1560198380),t=l(t,B,o,n,x[13],21,1309151649),n=l(n,t,B,o,x[4],6,-145523070),o=l(o,n,t,B,x[11],10,-1120210379),B=l(B,o,n,t,x[2],15,718787259),t=l(t,B,o,n,x[9],21,-343485551),r[0]=r[0]+n|0,r[1]=r[1]+t|0,r[2]=r[2]+B|0,r[3]=r[3]+o|0}function xr(r){let x=[];for(let n=0;n<64;n+=4)x[n>>2]=r.charCodeAt(n)+(r.charCodeAt(n+1)<<8)+(r.charCodeAt(n+2)<<16)+(r.charCodeAt(n+3)<<24);return x}function er(r){let x=r.length,n=[1732584193,-271733879,-1732584194,271733878],t,B;for(t=64;t<=x;t+=64)ax(n,xr(r.substring(t-64,t)));for(r=r.substring(t-64),B=new Array(16).fill(0),t=0;t<r.length;t++)B[t>>2]|=r.charCodeAt(t)<<(t%4<<3);B[t>>2]|=128<<(t%4<<3),t>55&&(ax(n,B),B=new Array(16).fill(0));let o=x*8;return B[14]=o&4294967295,B[15]=o/4294967296|0,ax(n,B),n}function rr(r){let x="";for(let n=0;n<4;n++)x+="0123456789abcdef"[r>>n*8+4&15]+"0123456789abcdef"[r>>n*8&15];return x}function tr(r){return er(r).map(rr).join("")}function ir(r,x,n,t){let F=Object.keys(r).sort().map(c=>c+"="+r[c]).join("&")+"&salt="+x+"&ts="+n+"&secret="+t;return tr(F).replace(/b/g,"#").replace(/d/g,"F").replace(/#/g,"C")}function nr(r,x){let n=Math.floor(Date.now()/1e3),t=Math.random().toString(36).substring(2,10),B=ir(r,t,n,x);return{...r,time:n,key:t,sign:B}}function we(r){return r.split("").map(x=>{let n="ZYXABCDEFGHIJKLMNOPQRSTUVWzyxabcdefghijklmnopqrstuvw9876543210-_".indexOf(x);return n===-1?x:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"[n]}).join("")}function He(r,x=8){let n="";for(let t=0;t<r.length;t+=x){let B=r.slice(t,t+x);n+=B.split("").reverse().join("")}return n}function qe(r,x=90){let n=[];for(let t=0;t<r.length;t++)n.push(String.fromCharCode(r.charCodeAt(t)^x));return n.join("")}function ar(r,x,n){if(typeof V.default>"u")throw new Error("CryptoJS library is required for aesDecrypt. Please include crypto-js in your project.");let t=V.default.enc.Utf8.parse(n),B=V.default.enc.Base64.parse(x),o=V.default.enc.Base64.parse(r),e=V.default.AES.decrypt({ciphertext:o},t,{iv:B,mode:V.default.mode.CBC,padding:V.default.pad.Pkcs7}).toString(V.default.enc.Utf8);return JSON.parse(e)}function fr(r,x,n){try{let t=atob(r),B=atob(x);return t=qe(t),B=qe(B),t=He(t),B=He(B),t=we(t),B=we(B),ar(t,B,n)}catch(t){throw t}}function or(r){let t=nr({requestURL:r},"TI52hwg30V08ycUo9"),B=t.sign;return delete t.sign,{body:JSON.stringify(t),auth:B}}function cr(r){return r=JSON.parse(r),r=fr(r.data,r.key,"93838338562359368888868323563256"),{title:r.title,url:r.videos[0].video_fullinfo.at(-1).url}}return Me(sr);})();
+var Douyin=(()=>{var Ge=Object.create;var C0=Object.defineProperty;var Ze=Object.getOwnPropertyDescriptor;var Je=Object.getOwnPropertyNames;var Qe=Object.getPrototypeOf,Ye=Object.prototype.hasOwnProperty;var Ex=(r=>typeof require<"u"?require:typeof Proxy<"u"?new Proxy(r,{get:(x,n)=>(typeof require<"u"?require:x)[n]}):r)(function(r){if(typeof require<"u")return require.apply(this,arguments);throw Error('Dynamic require of "'+r+'" is not supported')});var T=(r,x)=>()=>(x||r((x={exports:{}}).exports,x),x.exports),$e=(r,x)=>{for(var n in x)C0(r,n,{get:x[n],enumerable:!0})},Fx=(r,x,n,t)=>{if(x&&typeof x=="object"||typeof x=="function")for(let B of Je(x))!Ye.call(r,B)&&B!==n&&C0(r,B,{get:()=>x[B],enumerable:!(t=Ze(x,B))||t.enumerable});return r};var Ve=(r,x,n)=>(n=r!=null?Ge(Qe(r)):{},Fx(x||!r||!r.__esModule?C0(n,"default",{value:r,enumerable:!0}):n,r)),Me=r=>Fx(C0({},"__esModule",{value:!0}),r);var Dx=T(()=>{});var O=T((A0,px)=>{(function(r,x){typeof A0=="object"?px.exports=A0=x():typeof define=="function"&&define.amd?define([],x):r.CryptoJS=x()})(A0,function(){var r=r||(function(x,n){var t;if(typeof window<"u"&&window.crypto&&(t=window.crypto),typeof self<"u"&&self.crypto&&(t=self.crypto),typeof globalThis<"u"&&globalThis.crypto&&(t=globalThis.crypto),!t&&typeof window<"u"&&window.msCrypto&&(t=window.msCrypto),!t&&typeof global<"u"&&global.crypto&&(t=global.crypto),!t&&typeof Ex=="function")try{t=Dx()}catch{}var B=function(){if(t){if(typeof t.getRandomValues=="function")try{return t.getRandomValues(new Uint32Array(1))[0]}catch{}if(typeof t.randomBytes=="function")try{return t.randomBytes(4).readInt32LE()}catch{}}throw new Error("Native crypto module could not be used to get secure random number.")},o=Object.create||(function(){function a(){}return function(s){var E;return a.prototype=s,E=new a,a.prototype=null,E}})(),F={},e=F.lib={},c=e.Base=(function(){return{extend:function(a){var s=o(this);return a&&s.mixIn(a),(!s.hasOwnProperty("init")||this.init===s.init)&&(s.init=function(){s.$super.init.apply(this,arguments)}),s.init.prototype=s,s.$super=this,s},create:function(){var a=this.extend();return a.init.apply(a,arguments),a},init:function(){},mixIn:function(a){for(var s in a)a.hasOwnProperty(s)&&(this[s]=a[s]);a.hasOwnProperty("toString")&&(this.toString=a.toString)},clone:function(){return this.init.prototype.extend(this)}}})(),l=e.WordArray=c.extend({init:function(a,s){a=this.words=a||[],s!=n?this.sigBytes=s:this.sigBytes=a.length*4},toString:function(a){return(a||d).stringify(this)},concat:function(a){var s=this.words,E=a.words,A=this.sigBytes,D=a.sigBytes;if(this.clamp(),A%4)for(var p=0;p<D;p++){var H=E[p>>>2]>>>24-p%4*8&255;s[A+p>>>2]|=H<<24-(A+p)%4*8}else for(var S=0;S<D;S+=4)s[A+S>>>2]=E[S>>>2];return this.sigBytes+=D,this},clamp:function(){var a=this.words,s=this.sigBytes;a[s>>>2]&=4294967295<<32-s%4*8,a.length=x.ceil(s/4)},clone:function(){var a=c.clone.call(this);return a.words=this.words.slice(0),a},random:function(a){for(var s=[],E=0;E<a;E+=4)s.push(B());return new l.init(s,a)}}),i=F.enc={},d=i.Hex={stringify:function(a){for(var s=a.words,E=a.sigBytes,A=[],D=0;D<E;D++){var p=s[D>>>2]>>>24-D%4*8&255;A.push((p>>>4).toString(16)),A.push((p&15).toString(16))}return A.join("")},parse:function(a){for(var s=a.length,E=[],A=0;A<s;A+=2)E[A>>>3]|=parseInt(a.substr(A,2),16)<<24-A%8*4;return new l.init(E,s/2)}},f=i.Latin1={stringify:function(a){for(var s=a.words,E=a.sigBytes,A=[],D=0;D<E;D++){var p=s[D>>>2]>>>24-D%4*8&255;A.push(String.fromCharCode(p))}return A.join("")},parse:function(a){for(var s=a.length,E=[],A=0;A<s;A++)E[A>>>2]|=(a.charCodeAt(A)&255)<<24-A%4*8;return new l.init(E,s)}},h=i.Utf8={stringify:function(a){try{return decodeURIComponent(escape(f.stringify(a)))}catch{throw new Error("Malformed UTF-8 data")}},parse:function(a){return f.parse(unescape(encodeURIComponent(a)))}},v=e.BufferedBlockAlgorithm=c.extend({reset:function(){this._data=new l.init,this._nDataBytes=0},_append:function(a){typeof a=="string"&&(a=h.parse(a)),this._data.concat(a),this._nDataBytes+=a.sigBytes},_process:function(a){var s,E=this._data,A=E.words,D=E.sigBytes,p=this.blockSize,H=p*4,S=D/H;a?S=x.ceil(S):S=x.max((S|0)-this._minBufferSize,0);var q=S*p,R=x.min(q*4,D);if(q){for(var b=0;b<q;b+=p)this._doProcessBlock(A,b);s=A.splice(0,q),E.sigBytes-=R}return new l.init(s,R)},clone:function(){var a=c.clone.call(this);return a._data=this._data.clone(),a},_minBufferSize:0}),u=e.Hasher=v.extend({cfg:c.extend(),init:function(a){this.cfg=this.cfg.extend(a),this.reset()},reset:function(){v.reset.call(this),this._doReset()},update:function(a){return this._append(a),this._process(),this},finalize:function(a){a&&this._append(a);var s=this._doFinalize();return s},blockSize:512/32,_createHelper:function(a){return function(s,E){return new a.init(E).finalize(s)}},_createHmacHelper:function(a){return function(s,E){return new C.HMAC.init(a,E).finalize(s)}}}),C=F.algo={};return F})(Math);return r})});var B0=T((E0,bx)=>{(function(r,x){typeof E0=="object"?bx.exports=E0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(E0,function(r){return(function(x){var n=r,t=n.lib,B=t.Base,o=t.WordArray,F=n.x64={},e=F.Word=B.extend({init:function(l,i){this.high=l,this.low=i}}),c=F.WordArray=B.extend({init:function(l,i){l=this.words=l||[],i!=x?this.sigBytes=i:this.sigBytes=l.length*8},toX32:function(){for(var l=this.words,i=l.length,d=[],f=0;f<i;f++){var h=l[f];d.push(h.high),d.push(h.low)}return o.create(d,this.sigBytes)},clone:function(){for(var l=B.clone.call(this),i=l.words=this.words.slice(0),d=i.length,f=0;f<d;f++)i[f]=i[f].clone();return l}})})(),r})});var yx=T((F0,_x)=>{(function(r,x){typeof F0=="object"?_x.exports=F0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(F0,function(r){return(function(){if(typeof ArrayBuffer=="function"){var x=r,n=x.lib,t=n.WordArray,B=t.init,o=t.init=function(F){if(F instanceof ArrayBuffer&&(F=new Uint8Array(F)),(F instanceof Int8Array||typeof Uint8ClampedArray<"u"&&F instanceof Uint8ClampedArray||F instanceof Int16Array||F instanceof Uint16Array||F instanceof Int32Array||F instanceof Uint32Array||F instanceof Float32Array||F instanceof Float64Array)&&(F=new Uint8Array(F.buffer,F.byteOffset,F.byteLength)),F instanceof Uint8Array){for(var e=F.byteLength,c=[],l=0;l<e;l++)c[l>>>2]|=F[l]<<24-l%4*8;B.call(this,c,e)}else B.apply(this,arguments)};o.prototype=t}})(),r.lib.WordArray})});var kx=T((D0,gx)=>{(function(r,x){typeof D0=="object"?gx.exports=D0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(D0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.enc,o=B.Utf16=B.Utf16BE={stringify:function(e){for(var c=e.words,l=e.sigBytes,i=[],d=0;d<l;d+=2){var f=c[d>>>2]>>>16-d%4*8&65535;i.push(String.fromCharCode(f))}return i.join("")},parse:function(e){for(var c=e.length,l=[],i=0;i<c;i++)l[i>>>1]|=e.charCodeAt(i)<<16-i%2*16;return t.create(l,c*2)}};B.Utf16LE={stringify:function(e){for(var c=e.words,l=e.sigBytes,i=[],d=0;d<l;d+=2){var f=F(c[d>>>2]>>>16-d%4*8&65535);i.push(String.fromCharCode(f))}return i.join("")},parse:function(e){for(var c=e.length,l=[],i=0;i<c;i++)l[i>>>1]|=F(e.charCodeAt(i)<<16-i%2*16);return t.create(l,c*2)}};function F(e){return e<<8&4278255360|e>>>8&16711935}})(),r.enc.Utf16})});var e0=T((p0,mx)=>{(function(r,x){typeof p0=="object"?mx.exports=p0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(p0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.enc,o=B.Base64={stringify:function(e){var c=e.words,l=e.sigBytes,i=this._map;e.clamp();for(var d=[],f=0;f<l;f+=3)for(var h=c[f>>>2]>>>24-f%4*8&255,v=c[f+1>>>2]>>>24-(f+1)%4*8&255,u=c[f+2>>>2]>>>24-(f+2)%4*8&255,C=h<<16|v<<8|u,a=0;a<4&&f+a*.75<l;a++)d.push(i.charAt(C>>>6*(3-a)&63));var s=i.charAt(64);if(s)for(;d.length%4;)d.push(s);return d.join("")},parse:function(e){var c=e.length,l=this._map,i=this._reverseMap;if(!i){i=this._reverseMap=[];for(var d=0;d<l.length;d++)i[l.charCodeAt(d)]=d}var f=l.charAt(64);if(f){var h=e.indexOf(f);h!==-1&&(c=h)}return F(e,c,i)},_map:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/="};function F(e,c,l){for(var i=[],d=0,f=0;f<c;f++)if(f%4){var h=l[e.charCodeAt(f-1)]<<f%4*2,v=l[e.charCodeAt(f)]>>>6-f%4*2,u=h|v;i[d>>>2]|=u<<24-d%4*8,d++}return t.create(i,d)}})(),r.enc.Base64})});var wx=T((b0,Sx)=>{(function(r,x){typeof b0=="object"?Sx.exports=b0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(b0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.enc,o=B.Base64url={stringify:function(e,c){c===void 0&&(c=!0);var l=e.words,i=e.sigBytes,d=c?this._safe_map:this._map;e.clamp();for(var f=[],h=0;h<i;h+=3)for(var v=l[h>>>2]>>>24-h%4*8&255,u=l[h+1>>>2]>>>24-(h+1)%4*8&255,C=l[h+2>>>2]>>>24-(h+2)%4*8&255,a=v<<16|u<<8|C,s=0;s<4&&h+s*.75<i;s++)f.push(d.charAt(a>>>6*(3-s)&63));var E=d.charAt(64);if(E)for(;f.length%4;)f.push(E);return f.join("")},parse:function(e,c){c===void 0&&(c=!0);var l=e.length,i=c?this._safe_map:this._map,d=this._reverseMap;if(!d){d=this._reverseMap=[];for(var f=0;f<i.length;f++)d[i.charCodeAt(f)]=f}var h=i.charAt(64);if(h){var v=e.indexOf(h);v!==-1&&(l=v)}return F(e,l,d)},_map:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/=",_safe_map:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"};function F(e,c,l){for(var i=[],d=0,f=0;f<c;f++)if(f%4){var h=l[e.charCodeAt(f-1)]<<f%4*2,v=l[e.charCodeAt(f)]>>>6-f%4*2,u=h|v;i[d>>>2]|=u<<24-d%4*8,d++}return t.create(i,d)}})(),r.enc.Base64url})});var r0=T((_0,Hx)=>{(function(r,x){typeof _0=="object"?Hx.exports=_0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(_0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.algo,e=[];(function(){for(var h=0;h<64;h++)e[h]=x.abs(x.sin(h+1))*4294967296|0})();var c=F.MD5=o.extend({_doReset:function(){this._hash=new B.init([1732584193,4023233417,2562383102,271733878])},_doProcessBlock:function(h,v){for(var u=0;u<16;u++){var C=v+u,a=h[C];h[C]=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360}var s=this._hash.words,E=h[v+0],A=h[v+1],D=h[v+2],p=h[v+3],H=h[v+4],S=h[v+5],q=h[v+6],R=h[v+7],b=h[v+8],w=h[v+9],z=h[v+10],k=h[v+11],P=h[v+12],W=h[v+13],j=h[v+14],U=h[v+15],_=s[0],g=s[1],m=s[2],y=s[3];_=l(_,g,m,y,E,7,e[0]),y=l(y,_,g,m,A,12,e[1]),m=l(m,y,_,g,D,17,e[2]),g=l(g,m,y,_,p,22,e[3]),_=l(_,g,m,y,H,7,e[4]),y=l(y,_,g,m,S,12,e[5]),m=l(m,y,_,g,q,17,e[6]),g=l(g,m,y,_,R,22,e[7]),_=l(_,g,m,y,b,7,e[8]),y=l(y,_,g,m,w,12,e[9]),m=l(m,y,_,g,z,17,e[10]),g=l(g,m,y,_,k,22,e[11]),_=l(_,g,m,y,P,7,e[12]),y=l(y,_,g,m,W,12,e[13]),m=l(m,y,_,g,j,17,e[14]),g=l(g,m,y,_,U,22,e[15]),_=i(_,g,m,y,A,5,e[16]),y=i(y,_,g,m,q,9,e[17]),m=i(m,y,_,g,k,14,e[18]),g=i(g,m,y,_,E,20,e[19]),_=i(_,g,m,y,S,5,e[20]),y=i(y,_,g,m,z,9,e[21]),m=i(m,y,_,g,U,14,e[22]),g=i(g,m,y,_,H,20,e[23]),_=i(_,g,m,y,w,5,e[24]),y=i(y,_,g,m,j,9,e[25]),m=i(m,y,_,g,p,14,e[26]),g=i(g,m,y,_,b,20,e[27]),_=i(_,g,m,y,W,5,e[28]),y=i(y,_,g,m,D,9,e[29]),m=i(m,y,_,g,R,14,e[30]),g=i(g,m,y,_,P,20,e[31]),_=d(_,g,m,y,S,4,e[32]),y=d(y,_,g,m,b,11,e[33]),m=d(m,y,_,g,k,16,e[34]),g=d(g,m,y,_,j,23,e[35]),_=d(_,g,m,y,A,4,e[36]),y=d(y,_,g,m,H,11,e[37]),m=d(m,y,_,g,R,16,e[38]),g=d(g,m,y,_,z,23,e[39]),_=d(_,g,m,y,W,4,e[40]),y=d(y,_,g,m,E,11,e[41]),m=d(m,y,_,g,p,16,e[42]),g=d(g,m,y,_,q,23,e[43]),_=d(_,g,m,y,w,4,e[44]),y=d(y,_,g,m,P,11,e[45]),m=d(m,y,_,g,U,16,e[46]),g=d(g,m,y,_,D,23,e[47]),_=f(_,g,m,y,E,6,e[48]),y=f(y,_,g,m,R,10,e[49]),m=f(m,y,_,g,j,15,e[50]),g=f(g,m,y,_,S,21,e[51]),_=f(_,g,m,y,P,6,e[52]),y=f(y,_,g,m,p,10,e[53]),m=f(m,y,_,g,z,15,e[54]),g=f(g,m,y,_,A,21,e[55]),_=f(_,g,m,y,b,6,e[56]),y=f(y,_,g,m,U,10,e[57]),m=f(m,y,_,g,q,15,e[58]),g=f(g,m,y,_,W,21,e[59]),_=f(_,g,m,y,H,6,e[60]),y=f(y,_,g,m,k,10,e[61]),m=f(m,y,_,g,D,15,e[62]),g=f(g,m,y,_,w,21,e[63]),s[0]=s[0]+_|0,s[1]=s[1]+g|0,s[2]=s[2]+m|0,s[3]=s[3]+y|0},_doFinalize:function(){var h=this._data,v=h.words,u=this._nDataBytes*8,C=h.sigBytes*8;v[C>>>5]|=128<<24-C%32;var a=x.floor(u/4294967296),s=u;v[(C+64>>>9<<4)+15]=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360,v[(C+64>>>9<<4)+14]=(s<<8|s>>>24)&16711935|(s<<24|s>>>8)&4278255360,h.sigBytes=(v.length+1)*4,this._process();for(var E=this._hash,A=E.words,D=0;D<4;D++){var p=A[D];A[D]=(p<<8|p>>>24)&16711935|(p<<24|p>>>8)&4278255360}return E},clone:function(){var h=o.clone.call(this);return h._hash=this._hash.clone(),h}});function l(h,v,u,C,a,s,E){var A=h+(v&u|~v&C)+a+E;return(A<<s|A>>>32-s)+v}function i(h,v,u,C,a,s,E){var A=h+(v&C|u&~C)+a+E;return(A<<s|A>>>32-s)+v}function d(h,v,u,C,a,s,E){var A=h+(v^u^C)+a+E;return(A<<s|A>>>32-s)+v}function f(h,v,u,C,a,s,E){var A=h+(u^(v|~C))+a+E;return(A<<s|A>>>32-s)+v}n.MD5=o._createHelper(c),n.HmacMD5=o._createHmacHelper(c)})(Math),r.MD5})});var ix=T((y0,qx)=>{(function(r,x){typeof y0=="object"?qx.exports=y0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(y0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=n.Hasher,o=x.algo,F=[],e=o.SHA1=B.extend({_doReset:function(){this._hash=new t.init([1732584193,4023233417,2562383102,271733878,3285377520])},_doProcessBlock:function(c,l){for(var i=this._hash.words,d=i[0],f=i[1],h=i[2],v=i[3],u=i[4],C=0;C<80;C++){if(C<16)F[C]=c[l+C]|0;else{var a=F[C-3]^F[C-8]^F[C-14]^F[C-16];F[C]=a<<1|a>>>31}var s=(d<<5|d>>>27)+u+F[C];C<20?s+=(f&h|~f&v)+1518500249:C<40?s+=(f^h^v)+1859775393:C<60?s+=(f&h|f&v|h&v)-1894007588:s+=(f^h^v)-899497514,u=v,v=h,h=f<<30|f>>>2,f=d,d=s}i[0]=i[0]+d|0,i[1]=i[1]+f|0,i[2]=i[2]+h|0,i[3]=i[3]+v|0,i[4]=i[4]+u|0},_doFinalize:function(){var c=this._data,l=c.words,i=this._nDataBytes*8,d=c.sigBytes*8;return l[d>>>5]|=128<<24-d%32,l[(d+64>>>9<<4)+14]=Math.floor(i/4294967296),l[(d+64>>>9<<4)+15]=i,c.sigBytes=l.length*4,this._process(),this._hash},clone:function(){var c=B.clone.call(this);return c._hash=this._hash.clone(),c}});x.SHA1=B._createHelper(e),x.HmacSHA1=B._createHmacHelper(e)})(),r.SHA1})});var k0=T((g0,zx)=>{(function(r,x){typeof g0=="object"?zx.exports=g0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(g0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.algo,e=[],c=[];(function(){function d(u){for(var C=x.sqrt(u),a=2;a<=C;a++)if(!(u%a))return!1;return!0}function f(u){return(u-(u|0))*4294967296|0}for(var h=2,v=0;v<64;)d(h)&&(v<8&&(e[v]=f(x.pow(h,1/2))),c[v]=f(x.pow(h,1/3)),v++),h++})();var l=[],i=F.SHA256=o.extend({_doReset:function(){this._hash=new B.init(e.slice(0))},_doProcessBlock:function(d,f){for(var h=this._hash.words,v=h[0],u=h[1],C=h[2],a=h[3],s=h[4],E=h[5],A=h[6],D=h[7],p=0;p<64;p++){if(p<16)l[p]=d[f+p]|0;else{var H=l[p-15],S=(H<<25|H>>>7)^(H<<14|H>>>18)^H>>>3,q=l[p-2],R=(q<<15|q>>>17)^(q<<13|q>>>19)^q>>>10;l[p]=S+l[p-7]+R+l[p-16]}var b=s&E^~s&A,w=v&u^v&C^u&C,z=(v<<30|v>>>2)^(v<<19|v>>>13)^(v<<10|v>>>22),k=(s<<26|s>>>6)^(s<<21|s>>>11)^(s<<7|s>>>25),P=D+k+b+c[p]+l[p],W=z+w;D=A,A=E,E=s,s=a+P|0,a=C,C=u,u=v,v=P+W|0}h[0]=h[0]+v|0,h[1]=h[1]+u|0,h[2]=h[2]+C|0,h[3]=h[3]+a|0,h[4]=h[4]+s|0,h[5]=h[5]+E|0,h[6]=h[6]+A|0,h[7]=h[7]+D|0},_doFinalize:function(){var d=this._data,f=d.words,h=this._nDataBytes*8,v=d.sigBytes*8;return f[v>>>5]|=128<<24-v%32,f[(v+64>>>9<<4)+14]=x.floor(h/4294967296),f[(v+64>>>9<<4)+15]=h,d.sigBytes=f.length*4,this._process(),this._hash},clone:function(){var d=o.clone.call(this);return d._hash=this._hash.clone(),d}});n.SHA256=o._createHelper(i),n.HmacSHA256=o._createHmacHelper(i)})(Math),r.SHA256})});var Wx=T((m0,Rx)=>{(function(r,x,n){typeof m0=="object"?Rx.exports=m0=x(O(),k0()):typeof define=="function"&&define.amd?define(["./core","./sha256"],x):x(r.CryptoJS)})(m0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=x.algo,o=B.SHA256,F=B.SHA224=o.extend({_doReset:function(){this._hash=new t.init([3238371032,914150663,812702999,4144912697,4290775857,1750603025,1694076839,3204075428])},_doFinalize:function(){var e=o._doFinalize.call(this);return e.sigBytes-=4,e}});x.SHA224=o._createHelper(F),x.HmacSHA224=o._createHmacHelper(F)})(),r.SHA224})});var nx=T((S0,Px)=>{(function(r,x,n){typeof S0=="object"?Px.exports=S0=x(O(),B0()):typeof define=="function"&&define.amd?define(["./core","./x64-core"],x):x(r.CryptoJS)})(S0,function(r){return(function(){var x=r,n=x.lib,t=n.Hasher,B=x.x64,o=B.Word,F=B.WordArray,e=x.algo;function c(){return o.create.apply(o,arguments)}var l=[c(1116352408,3609767458),c(1899447441,602891725),c(3049323471,3964484399),c(3921009573,2173295548),c(961987163,4081628472),c(1508970993,3053834265),c(2453635748,2937671579),c(2870763221,3664609560),c(3624381080,2734883394),c(310598401,1164996542),c(607225278,1323610764),c(1426881987,3590304994),c(1925078388,4068182383),c(2162078206,991336113),c(2614888103,633803317),c(3248222580,3479774868),c(3835390401,2666613458),c(4022224774,944711139),c(264347078,2341262773),c(604807628,2007800933),c(770255983,1495990901),c(1249150122,1856431235),c(1555081692,3175218132),c(1996064986,2198950837),c(2554220882,3999719339),c(2821834349,766784016),c(2952996808,2566594879),c(3210313671,3203337956),c(3336571891,1034457026),c(3584528711,2466948901),c(113926993,3758326383),c(338241895,168717936),c(666307205,1188179964),c(773529912,1546045734),c(1294757372,1522805485),c(1396182291,2643833823),c(1695183700,2343527390),c(1986661051,1014477480),c(2177026350,1206759142),c(2456956037,344077627),c(2730485921,1290863460),c(2820302411,3158454273),c(3259730800,3505952657),c(3345764771,106217008),c(3516065817,3606008344),c(3600352804,1432725776),c(4094571909,1467031594),c(275423344,851169720),c(430227734,3100823752),c(506948616,1363258195),c(659060556,3750685593),c(883997877,3785050280),c(958139571,3318307427),c(1322822218,3812723403),c(1537002063,2003034995),c(1747873779,3602036899),c(1955562222,1575990012),c(2024104815,1125592928),c(2227730452,2716904306),c(2361852424,442776044),c(2428436474,593698344),c(2756734187,3733110249),c(3204031479,2999351573),c(3329325298,3815920427),c(3391569614,3928383900),c(3515267271,566280711),c(3940187606,3454069534),c(4118630271,4000239992),c(116418474,1914138554),c(174292421,2731055270),c(289380356,3203993006),c(460393269,320620315),c(685471733,587496836),c(852142971,1086792851),c(1017036298,365543100),c(1126000580,2618297676),c(1288033470,3409855158),c(1501505948,4234509866),c(1607167915,987167468),c(1816402316,1246189591)],i=[];(function(){for(var f=0;f<80;f++)i[f]=c()})();var d=e.SHA512=t.extend({_doReset:function(){this._hash=new F.init([new o.init(1779033703,4089235720),new o.init(3144134277,2227873595),new o.init(1013904242,4271175723),new o.init(2773480762,1595750129),new o.init(1359893119,2917565137),new o.init(2600822924,725511199),new o.init(528734635,4215389547),new o.init(1541459225,327033209)])},_doProcessBlock:function(f,h){for(var v=this._hash.words,u=v[0],C=v[1],a=v[2],s=v[3],E=v[4],A=v[5],D=v[6],p=v[7],H=u.high,S=u.low,q=C.high,R=C.low,b=a.high,w=a.low,z=s.high,k=s.low,P=E.high,W=E.low,j=A.high,U=A.low,_=D.high,g=D.low,m=p.high,y=p.low,N=H,X=S,K=q,L=R,a0=b,t0=w,rx=z,f0=k,Q=P,G=W,h0=j,o0=U,l0=_,c0=g,tx=m,s0=y,Y=0;Y<80;Y++){var J,M,u0=i[Y];if(Y<16)M=u0.high=f[h+Y*2]|0,J=u0.low=f[h+Y*2+1]|0;else{var fx=i[Y-15],i0=fx.high,v0=fx.low,ze=(i0>>>1|v0<<31)^(i0>>>8|v0<<24)^i0>>>7,ox=(v0>>>1|i0<<31)^(v0>>>8|i0<<24)^(v0>>>7|i0<<25),cx=i[Y-2],n0=cx.high,d0=cx.low,Re=(n0>>>19|d0<<13)^(n0<<3|d0>>>29)^n0>>>6,sx=(d0>>>19|n0<<13)^(d0<<3|n0>>>29)^(d0>>>6|n0<<26),vx=i[Y-7],We=vx.high,Pe=vx.low,dx=i[Y-16],je=dx.high,Bx=dx.low;J=ox+Pe,M=ze+We+(J>>>0<ox>>>0?1:0),J=J+sx,M=M+Re+(J>>>0<sx>>>0?1:0),J=J+Bx,M=M+je+(J>>>0<Bx>>>0?1:0),u0.high=M,u0.low=J}var Le=Q&h0^~Q&l0,hx=G&o0^~G&c0,Te=N&K^N&a0^K&a0,Oe=X&L^X&t0^L&t0,Ue=(N>>>28|X<<4)^(N<<30|X>>>2)^(N<<25|X>>>7),lx=(X>>>28|N<<4)^(X<<30|N>>>2)^(X<<25|N>>>7),Xe=(Q>>>14|G<<18)^(Q>>>18|G<<14)^(Q<<23|G>>>9),Ne=(G>>>14|Q<<18)^(G>>>18|Q<<14)^(G<<23|Q>>>9),ux=l[Y],Ie=ux.high,Cx=ux.low,Z=s0+Ne,x0=tx+Xe+(Z>>>0<s0>>>0?1:0),Z=Z+hx,x0=x0+Le+(Z>>>0<hx>>>0?1:0),Z=Z+Cx,x0=x0+Ie+(Z>>>0<Cx>>>0?1:0),Z=Z+J,x0=x0+M+(Z>>>0<J>>>0?1:0),Ax=lx+Oe,Ke=Ue+Te+(Ax>>>0<lx>>>0?1:0);tx=l0,s0=c0,l0=h0,c0=o0,h0=Q,o0=G,G=f0+Z|0,Q=rx+x0+(G>>>0<f0>>>0?1:0)|0,rx=a0,f0=t0,a0=K,t0=L,K=N,L=X,X=Z+Ax|0,N=x0+Ke+(X>>>0<Z>>>0?1:0)|0}S=u.low=S+X,u.high=H+N+(S>>>0<X>>>0?1:0),R=C.low=R+L,C.high=q+K+(R>>>0<L>>>0?1:0),w=a.low=w+t0,a.high=b+a0+(w>>>0<t0>>>0?1:0),k=s.low=k+f0,s.high=z+rx+(k>>>0<f0>>>0?1:0),W=E.low=W+G,E.high=P+Q+(W>>>0<G>>>0?1:0),U=A.low=U+o0,A.high=j+h0+(U>>>0<o0>>>0?1:0),g=D.low=g+c0,D.high=_+l0+(g>>>0<c0>>>0?1:0),y=p.low=y+s0,p.high=m+tx+(y>>>0<s0>>>0?1:0)},_doFinalize:function(){var f=this._data,h=f.words,v=this._nDataBytes*8,u=f.sigBytes*8;h[u>>>5]|=128<<24-u%32,h[(u+128>>>10<<5)+30]=Math.floor(v/4294967296),h[(u+128>>>10<<5)+31]=v,f.sigBytes=h.length*4,this._process();var C=this._hash.toX32();return C},clone:function(){var f=t.clone.call(this);return f._hash=this._hash.clone(),f},blockSize:1024/32});x.SHA512=t._createHelper(d),x.HmacSHA512=t._createHmacHelper(d)})(),r.SHA512})});var Lx=T((w0,jx)=>{(function(r,x,n){typeof w0=="object"?jx.exports=w0=x(O(),B0(),nx()):typeof define=="function"&&define.amd?define(["./core","./x64-core","./sha512"],x):x(r.CryptoJS)})(w0,function(r){return(function(){var x=r,n=x.x64,t=n.Word,B=n.WordArray,o=x.algo,F=o.SHA512,e=o.SHA384=F.extend({_doReset:function(){this._hash=new B.init([new t.init(3418070365,3238371032),new t.init(1654270250,914150663),new t.init(2438529370,812702999),new t.init(355462360,4144912697),new t.init(1731405415,4290775857),new t.init(2394180231,1750603025),new t.init(3675008525,1694076839),new t.init(1203062813,3204075428)])},_doFinalize:function(){var c=F._doFinalize.call(this);return c.sigBytes-=16,c}});x.SHA384=F._createHelper(e),x.HmacSHA384=F._createHmacHelper(e)})(),r.SHA384})});var Ox=T((H0,Tx)=>{(function(r,x,n){typeof H0=="object"?Tx.exports=H0=x(O(),B0()):typeof define=="function"&&define.amd?define(["./core","./x64-core"],x):x(r.CryptoJS)})(H0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.x64,e=F.Word,c=n.algo,l=[],i=[],d=[];(function(){for(var v=1,u=0,C=0;C<24;C++){l[v+5*u]=(C+1)*(C+2)/2%64;var a=u%5,s=(2*v+3*u)%5;v=a,u=s}for(var v=0;v<5;v++)for(var u=0;u<5;u++)i[v+5*u]=u+(2*v+3*u)%5*5;for(var E=1,A=0;A<24;A++){for(var D=0,p=0,H=0;H<7;H++){if(E&1){var S=(1<<H)-1;S<32?p^=1<<S:D^=1<<S-32}E&128?E=E<<1^113:E<<=1}d[A]=e.create(D,p)}})();var f=[];(function(){for(var v=0;v<25;v++)f[v]=e.create()})();var h=c.SHA3=o.extend({cfg:o.cfg.extend({outputLength:512}),_doReset:function(){for(var v=this._state=[],u=0;u<25;u++)v[u]=new e.init;this.blockSize=(1600-2*this.cfg.outputLength)/32},_doProcessBlock:function(v,u){for(var C=this._state,a=this.blockSize/2,s=0;s<a;s++){var E=v[u+2*s],A=v[u+2*s+1];E=(E<<8|E>>>24)&16711935|(E<<24|E>>>8)&4278255360,A=(A<<8|A>>>24)&16711935|(A<<24|A>>>8)&4278255360;var D=C[s];D.high^=A,D.low^=E}for(var p=0;p<24;p++){for(var H=0;H<5;H++){for(var S=0,q=0,R=0;R<5;R++){var D=C[H+5*R];S^=D.high,q^=D.low}var b=f[H];b.high=S,b.low=q}for(var H=0;H<5;H++)for(var w=f[(H+4)%5],z=f[(H+1)%5],k=z.high,P=z.low,S=w.high^(k<<1|P>>>31),q=w.low^(P<<1|k>>>31),R=0;R<5;R++){var D=C[H+5*R];D.high^=S,D.low^=q}for(var W=1;W<25;W++){var S,q,D=C[W],j=D.high,U=D.low,_=l[W];_<32?(S=j<<_|U>>>32-_,q=U<<_|j>>>32-_):(S=U<<_-32|j>>>64-_,q=j<<_-32|U>>>64-_);var g=f[i[W]];g.high=S,g.low=q}var m=f[0],y=C[0];m.high=y.high,m.low=y.low;for(var H=0;H<5;H++)for(var R=0;R<5;R++){var W=H+5*R,D=C[W],N=f[W],X=f[(H+1)%5+5*R],K=f[(H+2)%5+5*R];D.high=N.high^~X.high&K.high,D.low=N.low^~X.low&K.low}var D=C[0],L=d[p];D.high^=L.high,D.low^=L.low}},_doFinalize:function(){var v=this._data,u=v.words,C=this._nDataBytes*8,a=v.sigBytes*8,s=this.blockSize*32;u[a>>>5]|=1<<24-a%32,u[(x.ceil((a+1)/s)*s>>>5)-1]|=128,v.sigBytes=u.length*4,this._process();for(var E=this._state,A=this.cfg.outputLength/8,D=A/8,p=[],H=0;H<D;H++){var S=E[H],q=S.high,R=S.low;q=(q<<8|q>>>24)&16711935|(q<<24|q>>>8)&4278255360,R=(R<<8|R>>>24)&16711935|(R<<24|R>>>8)&4278255360,p.push(R),p.push(q)}return new B.init(p,A)},clone:function(){for(var v=o.clone.call(this),u=v._state=this._state.slice(0),C=0;C<25;C++)u[C]=u[C].clone();return v}});n.SHA3=o._createHelper(h),n.HmacSHA3=o._createHmacHelper(h)})(Math),r.SHA3})});var Xx=T((q0,Ux)=>{(function(r,x){typeof q0=="object"?Ux.exports=q0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(q0,function(r){return(function(x){var n=r,t=n.lib,B=t.WordArray,o=t.Hasher,F=n.algo,e=B.create([0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,7,4,13,1,10,6,15,3,12,0,9,5,2,14,11,8,3,10,14,4,9,15,8,1,2,7,0,6,13,11,5,12,1,9,11,10,0,8,12,4,13,3,7,15,14,5,6,2,4,0,5,9,7,12,2,10,14,1,3,8,11,6,15,13]),c=B.create([5,14,7,0,9,2,11,4,13,6,15,8,1,10,3,12,6,11,3,7,0,13,5,10,14,15,8,12,4,9,1,2,15,5,1,3,7,14,6,9,11,8,12,2,10,0,4,13,8,6,4,1,3,11,15,0,5,12,2,13,9,7,10,14,12,15,10,4,1,5,8,7,6,2,13,14,0,3,9,11]),l=B.create([11,14,15,12,5,8,7,9,11,13,14,15,6,7,9,8,7,6,8,13,11,9,7,15,7,12,15,9,11,7,13,12,11,13,6,7,14,9,13,15,14,8,13,6,5,12,7,5,11,12,14,15,14,15,9,8,9,14,5,6,8,6,5,12,9,15,5,11,6,8,13,12,5,12,13,14,11,8,5,6]),i=B.create([8,9,9,11,13,15,15,5,7,7,8,11,14,14,12,6,9,13,15,7,12,8,9,11,7,7,12,7,6,15,13,11,9,7,15,11,8,6,6,14,12,13,5,14,13,13,7,5,15,5,8,11,14,14,6,14,6,9,12,9,12,5,15,8,8,5,12,9,12,5,14,6,8,13,6,5,15,13,11,11]),d=B.create([0,1518500249,1859775393,2400959708,2840853838]),f=B.create([1352829926,1548603684,1836072691,2053994217,0]),h=F.RIPEMD160=o.extend({_doReset:function(){this._hash=B.create([1732584193,4023233417,2562383102,271733878,3285377520])},_doProcessBlock:function(A,D){for(var p=0;p<16;p++){var H=D+p,S=A[H];A[H]=(S<<8|S>>>24)&16711935|(S<<24|S>>>8)&4278255360}var q=this._hash.words,R=d.words,b=f.words,w=e.words,z=c.words,k=l.words,P=i.words,W,j,U,_,g,m,y,N,X,K;m=W=q[0],y=j=q[1],N=U=q[2],X=_=q[3],K=g=q[4];for(var L,p=0;p<80;p+=1)L=W+A[D+w[p]]|0,p<16?L+=v(j,U,_)+R[0]:p<32?L+=u(j,U,_)+R[1]:p<48?L+=C(j,U,_)+R[2]:p<64?L+=a(j,U,_)+R[3]:L+=s(j,U,_)+R[4],L=L|0,L=E(L,k[p]),L=L+g|0,W=g,g=_,_=E(U,10),U=j,j=L,L=m+A[D+z[p]]|0,p<16?L+=s(y,N,X)+b[0]:p<32?L+=a(y,N,X)+b[1]:p<48?L+=C(y,N,X)+b[2]:p<64?L+=u(y,N,X)+b[3]:L+=v(y,N,X)+b[4],L=L|0,L=E(L,P[p]),L=L+K|0,m=K,K=X,X=E(N,10),N=y,y=L;L=q[1]+U+X|0,q[1]=q[2]+_+K|0,q[2]=q[3]+g+m|0,q[3]=q[4]+W+y|0,q[4]=q[0]+j+N|0,q[0]=L},_doFinalize:function(){var A=this._data,D=A.words,p=this._nDataBytes*8,H=A.sigBytes*8;D[H>>>5]|=128<<24-H%32,D[(H+64>>>9<<4)+14]=(p<<8|p>>>24)&16711935|(p<<24|p>>>8)&4278255360,A.sigBytes=(D.length+1)*4,this._process();for(var S=this._hash,q=S.words,R=0;R<5;R++){var b=q[R];q[R]=(b<<8|b>>>24)&16711935|(b<<24|b>>>8)&4278255360}return S},clone:function(){var A=o.clone.call(this);return A._hash=this._hash.clone(),A}});function v(A,D,p){return A^D^p}function u(A,D,p){return A&D|~A&p}function C(A,D,p){return(A|~D)^p}function a(A,D,p){return A&p|D&~p}function s(A,D,p){return A^(D|~p)}function E(A,D){return A<<D|A>>>32-D}n.RIPEMD160=o._createHelper(h),n.HmacRIPEMD160=o._createHmacHelper(h)})(Math),r.RIPEMD160})});var R0=T((z0,Nx)=>{(function(r,x){typeof z0=="object"?Nx.exports=z0=x(O()):typeof define=="function"&&define.amd?define(["./core"],x):x(r.CryptoJS)})(z0,function(r){(function(){var x=r,n=x.lib,t=n.Base,B=x.enc,o=B.Utf8,F=x.algo,e=F.HMAC=t.extend({init:function(c,l){c=this._hasher=new c.init,typeof l=="string"&&(l=o.parse(l));var i=c.blockSize,d=i*4;l.sigBytes>d&&(l=c.finalize(l)),l.clamp();for(var f=this._oKey=l.clone(),h=this._iKey=l.clone(),v=f.words,u=h.words,C=0;C<i;C++)v[C]^=1549556828,u[C]^=909522486;f.sigBytes=h.sigBytes=d,this.reset()},reset:function(){var c=this._hasher;c.reset(),c.update(this._iKey)},update:function(c){return this._hasher.update(c),this},finalize:function(c){var l=this._hasher,i=l.finalize(c);l.reset();var d=l.finalize(this._oKey.clone().concat(i));return d}})})()})});var Kx=T((W0,Ix)=>{(function(r,x,n){typeof W0=="object"?Ix.exports=W0=x(O(),k0(),R0()):typeof define=="function"&&define.amd?define(["./core","./sha256","./hmac"],x):x(r.CryptoJS)})(W0,function(r){return(function(){var x=r,n=x.lib,t=n.Base,B=n.WordArray,o=x.algo,F=o.SHA256,e=o.HMAC,c=o.PBKDF2=t.extend({cfg:t.extend({keySize:128/32,hasher:F,iterations:25e4}),init:function(l){this.cfg=this.cfg.extend(l)},compute:function(l,i){for(var d=this.cfg,f=e.create(d.hasher,l),h=B.create(),v=B.create([1]),u=h.words,C=v.words,a=d.keySize,s=d.iterations;u.length<a;){var E=f.update(i).finalize(v);f.reset();for(var A=E.words,D=A.length,p=E,H=1;H<s;H++){p=f.finalize(p),f.reset();for(var S=p.words,q=0;q<D;q++)A[q]^=S[q]}h.concat(E),C[0]++}return h.sigBytes=a*4,h}});x.PBKDF2=function(l,i,d){return c.create(d).compute(l,i)}})(),r.PBKDF2})});var $=T((P0,Gx)=>{(function(r,x,n){typeof P0=="object"?Gx.exports=P0=x(O(),ix(),R0()):typeof define=="function"&&define.amd?define(["./core","./sha1","./hmac"],x):x(r.CryptoJS)})(P0,function(r){return(function(){var x=r,n=x.lib,t=n.Base,B=n.WordArray,o=x.algo,F=o.MD5,e=o.EvpKDF=t.extend({cfg:t.extend({keySize:128/32,hasher:F,iterations:1}),init:function(c){this.cfg=this.cfg.extend(c)},compute:function(c,l){for(var i,d=this.cfg,f=d.hasher.create(),h=B.create(),v=h.words,u=d.keySize,C=d.iterations;v.length<u;){i&&f.update(i),i=f.update(c).finalize(l),f.reset();for(var a=1;a<C;a++)i=f.finalize(i),f.reset();h.concat(i)}return h.sigBytes=u*4,h}});x.EvpKDF=function(c,l,i){return e.create(i).compute(c,l)}})(),r.EvpKDF})});var I=T((j0,Zx)=>{(function(r,x,n){typeof j0=="object"?Zx.exports=j0=x(O(),$()):typeof define=="function"&&define.amd?define(["./core","./evpkdf"],x):x(r.CryptoJS)})(j0,function(r){r.lib.Cipher||(function(x){var n=r,t=n.lib,B=t.Base,o=t.WordArray,F=t.BufferedBlockAlgorithm,e=n.enc,c=e.Utf8,l=e.Base64,i=n.algo,d=i.EvpKDF,f=t.Cipher=F.extend({cfg:B.extend(),createEncryptor:function(b,w){return this.create(this._ENC_XFORM_MODE,b,w)},createDecryptor:function(b,w){return this.create(this._DEC_XFORM_MODE,b,w)},init:function(b,w,z){this.cfg=this.cfg.extend(z),this._xformMode=b,this._key=w,this.reset()},reset:function(){F.reset.call(this),this._doReset()},process:function(b){return this._append(b),this._process()},finalize:function(b){b&&this._append(b);var w=this._doFinalize();return w},keySize:128/32,ivSize:128/32,_ENC_XFORM_MODE:1,_DEC_XFORM_MODE:2,_createHelper:(function(){function b(w){return typeof w=="string"?R:H}return function(w){return{encrypt:function(z,k,P){return b(k).encrypt(w,z,k,P)},decrypt:function(z,k,P){return b(k).decrypt(w,z,k,P)}}}})()}),h=t.StreamCipher=f.extend({_doFinalize:function(){var b=this._process(!0);return b},blockSize:1}),v=n.mode={},u=t.BlockCipherMode=B.extend({createEncryptor:function(b,w){return this.Encryptor.create(b,w)},createDecryptor:function(b,w){return this.Decryptor.create(b,w)},init:function(b,w){this._cipher=b,this._iv=w}}),C=v.CBC=(function(){var b=u.extend();b.Encryptor=b.extend({processBlock:function(z,k){var P=this._cipher,W=P.blockSize;w.call(this,z,k,W),P.encryptBlock(z,k),this._prevBlock=z.slice(k,k+W)}}),b.Decryptor=b.extend({processBlock:function(z,k){var P=this._cipher,W=P.blockSize,j=z.slice(k,k+W);P.decryptBlock(z,k),w.call(this,z,k,W),this._prevBlock=j}});function w(z,k,P){var W,j=this._iv;j?(W=j,this._iv=x):W=this._prevBlock;for(var U=0;U<P;U++)z[k+U]^=W[U]}return b})(),a=n.pad={},s=a.Pkcs7={pad:function(b,w){for(var z=w*4,k=z-b.sigBytes%z,P=k<<24|k<<16|k<<8|k,W=[],j=0;j<k;j+=4)W.push(P);var U=o.create(W,k);b.concat(U)},unpad:function(b){var w=b.words[b.sigBytes-1>>>2]&255;b.sigBytes-=w}},E=t.BlockCipher=f.extend({cfg:f.cfg.extend({mode:C,padding:s}),reset:function(){var b;f.reset.call(this);var w=this.cfg,z=w.iv,k=w.mode;this._xformMode==this._ENC_XFORM_MODE?b=k.createEncryptor:(b=k.createDecryptor,this._minBufferSize=1),this._mode&&this._mode.__creator==b?this._mode.init(this,z&&z.words):(this._mode=b.call(k,this,z&&z.words),this._mode.__creator=b)},_doProcessBlock:function(b,w){this._mode.processBlock(b,w)},_doFinalize:function(){var b,w=this.cfg.padding;return this._xformMode==this._ENC_XFORM_MODE?(w.pad(this._data,this.blockSize),b=this._process(!0)):(b=this._process(!0),w.unpad(b)),b},blockSize:128/32}),A=t.CipherParams=B.extend({init:function(b){this.mixIn(b)},toString:function(b){return(b||this.formatter).stringify(this)}}),D=n.format={},p=D.OpenSSL={stringify:function(b){var w,z=b.ciphertext,k=b.salt;return k?w=o.create([1398893684,1701076831]).concat(k).concat(z):w=z,w.toString(l)},parse:function(b){var w,z=l.parse(b),k=z.words;return k[0]==1398893684&&k[1]==1701076831&&(w=o.create(k.slice(2,4)),k.splice(0,4),z.sigBytes-=16),A.create({ciphertext:z,salt:w})}},H=t.SerializableCipher=B.extend({cfg:B.extend({format:p}),encrypt:function(b,w,z,k){k=this.cfg.extend(k);var P=b.createEncryptor(z,k),W=P.finalize(w),j=P.cfg;return A.create({ciphertext:W,key:z,iv:j.iv,algorithm:b,mode:j.mode,padding:j.padding,blockSize:b.blockSize,formatter:k.format})},decrypt:function(b,w,z,k){k=this.cfg.extend(k),w=this._parse(w,k.format);var P=b.createDecryptor(z,k).finalize(w.ciphertext);return P},_parse:function(b,w){return typeof b=="string"?w.parse(b,this):b}}),S=n.kdf={},q=S.OpenSSL={execute:function(b,w,z,k,P){if(k||(k=o.random(64/8)),P)var W=d.create({keySize:w+z,hasher:P}).compute(b,k);else var W=d.create({keySize:w+z}).compute(b,k);var j=o.create(W.words.slice(w),z*4);return W.sigBytes=w*4,A.create({key:W,iv:j,salt:k})}},R=t.PasswordBasedCipher=H.extend({cfg:H.cfg.extend({kdf:q}),encrypt:function(b,w,z,k){k=this.cfg.extend(k);var P=k.kdf.execute(z,b.keySize,b.ivSize,k.salt,k.hasher);k.iv=P.iv;var W=H.encrypt.call(this,b,w,P.key,k);return W.mixIn(P),W},decrypt:function(b,w,z,k){k=this.cfg.extend(k),w=this._parse(w,k.format);var P=k.kdf.execute(z,b.keySize,b.ivSize,w.salt,k.hasher);k.iv=P.iv;var W=H.decrypt.call(this,b,w,P.key,k);return W}})})()})});var Qx=T((L0,Jx)=>{(function(r,x,n){typeof L0=="object"?Jx.exports=L0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(L0,function(r){return r.mode.CFB=(function(){var x=r.lib.BlockCipherMode.extend();x.Encryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize;n.call(this,t,B,F,o),this._prevBlock=t.slice(B,B+F)}}),x.Decryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize,e=t.slice(B,B+F);n.call(this,t,B,F,o),this._prevBlock=e}});function n(t,B,o,F){var e,c=this._iv;c?(e=c.slice(0),this._iv=void 0):e=this._prevBlock,F.encryptBlock(e,0);for(var l=0;l<o;l++)t[B+l]^=e[l]}return x})(),r.mode.CFB})});var $x=T((T0,Yx)=>{(function(r,x,n){typeof T0=="object"?Yx.exports=T0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(T0,function(r){return r.mode.CTR=(function(){var x=r.lib.BlockCipherMode.extend(),n=x.Encryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize,e=this._iv,c=this._counter;e&&(c=this._counter=e.slice(0),this._iv=void 0);var l=c.slice(0);o.encryptBlock(l,0),c[F-1]=c[F-1]+1|0;for(var i=0;i<F;i++)t[B+i]^=l[i]}});return x.Decryptor=n,x})(),r.mode.CTR})});var Mx=T((O0,Vx)=>{(function(r,x,n){typeof O0=="object"?Vx.exports=O0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(O0,function(r){return r.mode.CTRGladman=(function(){var x=r.lib.BlockCipherMode.extend();function n(o){if((o>>24&255)===255){var F=o>>16&255,e=o>>8&255,c=o&255;F===255?(F=0,e===255?(e=0,c===255?c=0:++c):++e):++F,o=0,o+=F<<16,o+=e<<8,o+=c}else o+=1<<24;return o}function t(o){return(o[0]=n(o[0]))===0&&(o[1]=n(o[1])),o}var B=x.Encryptor=x.extend({processBlock:function(o,F){var e=this._cipher,c=e.blockSize,l=this._iv,i=this._counter;l&&(i=this._counter=l.slice(0),this._iv=void 0),t(i);var d=i.slice(0);e.encryptBlock(d,0);for(var f=0;f<c;f++)o[F+f]^=d[f]}});return x.Decryptor=B,x})(),r.mode.CTRGladman})});var ee=T((U0,xe)=>{(function(r,x,n){typeof U0=="object"?xe.exports=U0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(U0,function(r){return r.mode.OFB=(function(){var x=r.lib.BlockCipherMode.extend(),n=x.Encryptor=x.extend({processBlock:function(t,B){var o=this._cipher,F=o.blockSize,e=this._iv,c=this._keystream;e&&(c=this._keystream=e.slice(0),this._iv=void 0),o.encryptBlock(c,0);for(var l=0;l<F;l++)t[B+l]^=c[l]}});return x.Decryptor=n,x})(),r.mode.OFB})});var te=T((X0,re)=>{(function(r,x,n){typeof X0=="object"?re.exports=X0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(X0,function(r){return r.mode.ECB=(function(){var x=r.lib.BlockCipherMode.extend();return x.Encryptor=x.extend({processBlock:function(n,t){this._cipher.encryptBlock(n,t)}}),x.Decryptor=x.extend({processBlock:function(n,t){this._cipher.decryptBlock(n,t)}}),x})(),r.mode.ECB})});var ne=T((N0,ie)=>{(function(r,x,n){typeof N0=="object"?ie.exports=N0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(N0,function(r){return r.pad.AnsiX923={pad:function(x,n){var t=x.sigBytes,B=n*4,o=B-t%B,F=t+o-1;x.clamp(),x.words[F>>>2]|=o<<24-F%4*8,x.sigBytes+=o},unpad:function(x){var n=x.words[x.sigBytes-1>>>2]&255;x.sigBytes-=n}},r.pad.Ansix923})});var fe=T((I0,ae)=>{(function(r,x,n){typeof I0=="object"?ae.exports=I0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(I0,function(r){return r.pad.Iso10126={pad:function(x,n){var t=n*4,B=t-x.sigBytes%t;x.concat(r.lib.WordArray.random(B-1)).concat(r.lib.WordArray.create([B<<24],1))},unpad:function(x){var n=x.words[x.sigBytes-1>>>2]&255;x.sigBytes-=n}},r.pad.Iso10126})});var ce=T((K0,oe)=>{(function(r,x,n){typeof K0=="object"?oe.exports=K0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(K0,function(r){return r.pad.Iso97971={pad:function(x,n){x.concat(r.lib.WordArray.create([2147483648],1)),r.pad.ZeroPadding.pad(x,n)},unpad:function(x){r.pad.ZeroPadding.unpad(x),x.sigBytes--}},r.pad.Iso97971})});var ve=T((G0,se)=>{(function(r,x,n){typeof G0=="object"?se.exports=G0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(G0,function(r){return r.pad.ZeroPadding={pad:function(x,n){var t=n*4;x.clamp(),x.sigBytes+=t-(x.sigBytes%t||t)},unpad:function(x){for(var n=x.words,t=x.sigBytes-1,t=x.sigBytes-1;t>=0;t--)if(n[t>>>2]>>>24-t%4*8&255){x.sigBytes=t+1;break}}},r.pad.ZeroPadding})});var Be=T((Z0,de)=>{(function(r,x,n){typeof Z0=="object"?de.exports=Z0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(Z0,function(r){return r.pad.NoPadding={pad:function(){},unpad:function(){}},r.pad.NoPadding})});var le=T((J0,he)=>{(function(r,x,n){typeof J0=="object"?he.exports=J0=x(O(),I()):typeof define=="function"&&define.amd?define(["./core","./cipher-core"],x):x(r.CryptoJS)})(J0,function(r){return(function(x){var n=r,t=n.lib,B=t.CipherParams,o=n.enc,F=o.Hex,e=n.format,c=e.Hex={stringify:function(l){return l.ciphertext.toString(F)},parse:function(l){var i=F.parse(l);return B.create({ciphertext:i})}}})(),r.format.Hex})});var Ce=T((Q0,ue)=>{(function(r,x,n){typeof Q0=="object"?ue.exports=Q0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(Q0,function(r){return(function(){var x=r,n=x.lib,t=n.BlockCipher,B=x.algo,o=[],F=[],e=[],c=[],l=[],i=[],d=[],f=[],h=[],v=[];(function(){for(var a=[],s=0;s<256;s++)s<128?a[s]=s<<1:a[s]=s<<1^283;for(var E=0,A=0,s=0;s<256;s++){var D=A^A<<1^A<<2^A<<3^A<<4;D=D>>>8^D&255^99,o[E]=D,F[D]=E;var p=a[E],H=a[p],S=a[H],q=a[D]*257^D*16843008;e[E]=q<<24|q>>>8,c[E]=q<<16|q>>>16,l[E]=q<<8|q>>>24,i[E]=q;var q=S*16843009^H*65537^p*257^E*16843008;d[D]=q<<24|q>>>8,f[D]=q<<16|q>>>16,h[D]=q<<8|q>>>24,v[D]=q,E?(E=p^a[a[a[S^p]]],A^=a[a[A]]):E=A=1}})();var u=[0,1,2,4,8,16,32,64,128,27,54],C=B.AES=t.extend({_doReset:function(){var a;if(!(this._nRounds&&this._keyPriorReset===this._key)){for(var s=this._keyPriorReset=this._key,E=s.words,A=s.sigBytes/4,D=this._nRounds=A+6,p=(D+1)*4,H=this._keySchedule=[],S=0;S<p;S++)S<A?H[S]=E[S]:(a=H[S-1],S%A?A>6&&S%A==4&&(a=o[a>>>24]<<24|o[a>>>16&255]<<16|o[a>>>8&255]<<8|o[a&255]):(a=a<<8|a>>>24,a=o[a>>>24]<<24|o[a>>>16&255]<<16|o[a>>>8&255]<<8|o[a&255],a^=u[S/A|0]<<24),H[S]=H[S-A]^a);for(var q=this._invKeySchedule=[],R=0;R<p;R++){var S=p-R;if(R%4)var a=H[S];else var a=H[S-4];R<4||S<=4?q[R]=a:q[R]=d[o[a>>>24]]^f[o[a>>>16&255]]^h[o[a>>>8&255]]^v[o[a&255]]}}},encryptBlock:function(a,s){this._doCryptBlock(a,s,this._keySchedule,e,c,l,i,o)},decryptBlock:function(a,s){var E=a[s+1];a[s+1]=a[s+3],a[s+3]=E,this._doCryptBlock(a,s,this._invKeySchedule,d,f,h,v,F);var E=a[s+1];a[s+1]=a[s+3],a[s+3]=E},_doCryptBlock:function(a,s,E,A,D,p,H,S){for(var q=this._nRounds,R=a[s]^E[0],b=a[s+1]^E[1],w=a[s+2]^E[2],z=a[s+3]^E[3],k=4,P=1;P<q;P++){var W=A[R>>>24]^D[b>>>16&255]^p[w>>>8&255]^H[z&255]^E[k++],j=A[b>>>24]^D[w>>>16&255]^p[z>>>8&255]^H[R&255]^E[k++],U=A[w>>>24]^D[z>>>16&255]^p[R>>>8&255]^H[b&255]^E[k++],_=A[z>>>24]^D[R>>>16&255]^p[b>>>8&255]^H[w&255]^E[k++];R=W,b=j,w=U,z=_}var W=(S[R>>>24]<<24|S[b>>>16&255]<<16|S[w>>>8&255]<<8|S[z&255])^E[k++],j=(S[b>>>24]<<24|S[w>>>16&255]<<16|S[z>>>8&255]<<8|S[R&255])^E[k++],U=(S[w>>>24]<<24|S[z>>>16&255]<<16|S[R>>>8&255]<<8|S[b&255])^E[k++],_=(S[z>>>24]<<24|S[R>>>16&255]<<16|S[b>>>8&255]<<8|S[w&255])^E[k++];a[s]=W,a[s+1]=j,a[s+2]=U,a[s+3]=_},keySize:256/32});x.AES=t._createHelper(C)})(),r.AES})});var Ee=T((Y0,Ae)=>{(function(r,x,n){typeof Y0=="object"?Ae.exports=Y0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(Y0,function(r){return(function(){var x=r,n=x.lib,t=n.WordArray,B=n.BlockCipher,o=x.algo,F=[57,49,41,33,25,17,9,1,58,50,42,34,26,18,10,2,59,51,43,35,27,19,11,3,60,52,44,36,63,55,47,39,31,23,15,7,62,54,46,38,30,22,14,6,61,53,45,37,29,21,13,5,28,20,12,4],e=[14,17,11,24,1,5,3,28,15,6,21,10,23,19,12,4,26,8,16,7,27,20,13,2,41,52,31,37,47,55,30,40,51,45,33,48,44,49,39,56,34,53,46,42,50,36,29,32],c=[1,2,4,6,8,10,12,14,15,17,19,21,23,25,27,28],l=[{0:8421888,268435456:32768,536870912:8421378,805306368:2,1073741824:512,1342177280:8421890,1610612736:8389122,1879048192:8388608,2147483648:514,2415919104:8389120,2684354560:33280,2952790016:8421376,3221225472:32770,3489660928:8388610,3758096384:0,4026531840:33282,134217728:0,402653184:8421890,671088640:33282,939524096:32768,1207959552:8421888,1476395008:512,1744830464:8421378,2013265920:2,2281701376:8389120,2550136832:33280,2818572288:8421376,3087007744:8389122,3355443200:8388610,3623878656:32770,3892314112:514,4160749568:8388608,1:32768,268435457:2,536870913:8421888,805306369:8388608,1073741825:8421378,1342177281:33280,1610612737:512,1879048193:8389122,2147483649:8421890,2415919105:8421376,2684354561:8388610,2952790017:33282,3221225473:514,3489660929:8389120,3758096385:32770,4026531841:0,134217729:8421890,402653185:8421376,671088641:8388608,939524097:512,1207959553:32768,1476395009:8388610,1744830465:2,2013265921:33282,2281701377:32770,2550136833:8389122,2818572289:514,3087007745:8421888,3355443201:8389120,3623878657:0,3892314113:33280,4160749569:8421378},{0:1074282512,16777216:16384,33554432:524288,50331648:1074266128,67108864:1073741840,83886080:1074282496,100663296:1073758208,117440512:16,134217728:540672,150994944:1073758224,167772160:1073741824,184549376:540688,201326592:524304,218103808:0,234881024:16400,251658240:1074266112,8388608:1073758208,25165824:540688,41943040:16,58720256:1073758224,75497472:1074282512,92274688:1073741824,109051904:524288,125829120:1074266128,142606336:524304,159383552:0,176160768:16384,192937984:1074266112,209715200:1073741840,226492416:540672,243269632:1074282496,260046848:16400,268435456:0,285212672:1074266128,301989888:1073758224,318767104:1074282496,335544320:1074266112,352321536:16,369098752:540688,385875968:16384,402653184:16400,419430400:524288,436207616:524304,452984832:1073741840,469762048:540672,486539264:1073758208,503316480:1073741824,520093696:1074282512,276824064:540688,293601280:524288,310378496:1074266112,327155712:16384,343932928:1073758208,360710144:1074282512,377487360:16,394264576:1073741824,411041792:1074282496,427819008:1073741840,444596224:1073758224,461373440:524304,478150656:0,494927872:16400,511705088:1074266128,528482304:540672},{0:260,1048576:0,2097152:67109120,3145728:65796,4194304:65540,5242880:67108868,6291456:67174660,7340032:67174400,8388608:67108864,9437184:67174656,10485760:65792,11534336:67174404,12582912:67109124,13631488:65536,14680064:4,15728640:256,524288:67174656,1572864:67174404,2621440:0,3670016:67109120,4718592:67108868,5767168:65536,6815744:65540,7864320:260,8912896:4,9961472:256,11010048:67174400,12058624:65796,13107200:65792,14155776:67109124,15204352:67174660,16252928:67108864,16777216:67174656,17825792:65540,18874368:65536,19922944:67109120,20971520:256,22020096:67174660,23068672:67108868,24117248:0,25165824:67109124,26214400:67108864,27262976:4,28311552:65792,29360128:67174400,30408704:260,31457280:65796,32505856:67174404,17301504:67108864,18350080:260,19398656:67174656,20447232:0,21495808:65540,22544384:67109120,23592960:256,24641536:67174404,25690112:65536,26738688:67174660,27787264:65796,28835840:67108868,29884416:67109124,30932992:67174400,31981568:4,33030144:65792},{0:2151682048,65536:2147487808,131072:4198464,196608:2151677952,262144:0,327680:4198400,393216:2147483712,458752:4194368,524288:2147483648,589824:4194304,655360:64,720896:2147487744,786432:2151678016,851968:4160,917504:4096,983040:2151682112,32768:2147487808,98304:64,163840:2151678016,229376:2147487744,294912:4198400,360448:2151682112,425984:0,491520:2151677952,557056:4096,622592:2151682048,688128:4194304,753664:4160,819200:2147483648,884736:4194368,950272:4198464,1015808:2147483712,1048576:4194368,1114112:4198400,1179648:2147483712,1245184:0,1310720:4160,1376256:2151678016,1441792:2151682048,1507328:2147487808,1572864:2151682112,1638400:2147483648,1703936:2151677952,1769472:4198464,1835008:2147487744,1900544:4194304,1966080:64,2031616:4096,1081344:2151677952,1146880:2151682112,1212416:0,1277952:4198400,1343488:4194368,1409024:2147483648,1474560:2147487808,1540096:64,1605632:2147483712,1671168:4096,1736704:2147487744,1802240:2151678016,1867776:4160,1933312:2151682048,1998848:4194304,2064384:4198464},{0:128,4096:17039360,8192:262144,12288:536870912,16384:537133184,20480:16777344,24576:553648256,28672:262272,32768:16777216,36864:537133056,40960:536871040,45056:553910400,49152:553910272,53248:0,57344:17039488,61440:553648128,2048:17039488,6144:553648256,10240:128,14336:17039360,18432:262144,22528:537133184,26624:553910272,30720:536870912,34816:537133056,38912:0,43008:553910400,47104:16777344,51200:536871040,55296:553648128,59392:16777216,63488:262272,65536:262144,69632:128,73728:536870912,77824:553648256,81920:16777344,86016:553910272,90112:537133184,94208:16777216,98304:553910400,102400:553648128,106496:17039360,110592:537133056,114688:262272,118784:536871040,122880:0,126976:17039488,67584:553648256,71680:16777216,75776:17039360,79872:537133184,83968:536870912,88064:17039488,92160:128,96256:553910272,100352:262272,104448:553910400,108544:0,112640:553648128,116736:16777344,120832:262144,124928:537133056,129024:536871040},{0:268435464,256:8192,512:270532608,768:270540808,1024:268443648,1280:2097152,1536:2097160,1792:268435456,2048:0,2304:268443656,2560:2105344,2816:8,3072:270532616,3328:2105352,3584:8200,3840:270540800,128:270532608,384:270540808,640:8,896:2097152,1152:2105352,1408:268435464,1664:268443648,1920:8200,2176:2097160,2432:8192,2688:268443656,2944:270532616,3200:0,3456:270540800,3712:2105344,3968:268435456,4096:268443648,4352:270532616,4608:270540808,4864:8200,5120:2097152,5376:268435456,5632:268435464,5888:2105344,6144:2105352,6400:0,6656:8,6912:270532608,7168:8192,7424:268443656,7680:270540800,7936:2097160,4224:8,4480:2105344,4736:2097152,4992:268435464,5248:268443648,5504:8200,5760:270540808,6016:270532608,6272:270540800,6528:270532616,6784:8192,7040:2105352,7296:2097160,7552:0,7808:268435456,8064:268443656},{0:1048576,16:33555457,32:1024,48:1049601,64:34604033,80:0,96:1,112:34603009,128:33555456,144:1048577,160:33554433,176:34604032,192:34603008,208:1025,224:1049600,240:33554432,8:34603009,24:0,40:33555457,56:34604032,72:1048576,88:33554433,104:33554432,120:1025,136:1049601,152:33555456,168:34603008,184:1048577,200:1024,216:34604033,232:1,248:1049600,256:33554432,272:1048576,288:33555457,304:34603009,320:1048577,336:33555456,352:34604032,368:1049601,384:1025,400:34604033,416:1049600,432:1,448:0,464:34603008,480:33554433,496:1024,264:1049600,280:33555457,296:34603009,312:1,328:33554432,344:1048576,360:1025,376:34604032,392:33554433,408:34603008,424:0,440:34604033,456:1049601,472:1024,488:33555456,504:1048577},{0:134219808,1:131072,2:134217728,3:32,4:131104,5:134350880,6:134350848,7:2048,8:134348800,9:134219776,10:133120,11:134348832,12:2080,13:0,14:134217760,15:133152,2147483648:2048,2147483649:134350880,2147483650:134219808,2147483651:134217728,2147483652:134348800,2147483653:133120,2147483654:133152,2147483655:32,2147483656:134217760,2147483657:2080,2147483658:131104,2147483659:134350848,2147483660:0,2147483661:134348832,2147483662:134219776,2147483663:131072,16:133152,17:134350848,18:32,19:2048,20:134219776,21:134217760,22:134348832,23:131072,24:0,25:131104,26:134348800,27:134219808,28:134350880,29:133120,30:2080,31:134217728,2147483664:131072,2147483665:2048,2147483666:134348832,2147483667:133152,2147483668:32,2147483669:134348800,2147483670:134217728,2147483671:134219808,2147483672:134350880,2147483673:134217760,2147483674:134219776,2147483675:0,2147483676:133120,2147483677:2080,2147483678:131104,2147483679:134350848}],i=[4160749569,528482304,33030144,2064384,129024,8064,504,2147483679],d=o.DES=B.extend({_doReset:function(){for(var u=this._key,C=u.words,a=[],s=0;s<56;s++){var E=F[s]-1;a[s]=C[E>>>5]>>>31-E%32&1}for(var A=this._subKeys=[],D=0;D<16;D++){for(var p=A[D]=[],H=c[D],s=0;s<24;s++)p[s/6|0]|=a[(e[s]-1+H)%28]<<31-s%6,p[4+(s/6|0)]|=a[28+(e[s+24]-1+H)%28]<<31-s%6;p[0]=p[0]<<1|p[0]>>>31;for(var s=1;s<7;s++)p[s]=p[s]>>>(s-1)*4+3;p[7]=p[7]<<5|p[7]>>>27}for(var S=this._invSubKeys=[],s=0;s<16;s++)S[s]=A[15-s]},encryptBlock:function(u,C){this._doCryptBlock(u,C,this._subKeys)},decryptBlock:function(u,C){this._doCryptBlock(u,C,this._invSubKeys)},_doCryptBlock:function(u,C,a){this._lBlock=u[C],this._rBlock=u[C+1],f.call(this,4,252645135),f.call(this,16,65535),h.call(this,2,858993459),h.call(this,8,16711935),f.call(this,1,1431655765);for(var s=0;s<16;s++){for(var E=a[s],A=this._lBlock,D=this._rBlock,p=0,H=0;H<8;H++)p|=l[H][((D^E[H])&i[H])>>>0];this._lBlock=D,this._rBlock=A^p}var S=this._lBlock;this._lBlock=this._rBlock,this._rBlock=S,f.call(this,1,1431655765),h.call(this,8,16711935),h.call(this,2,858993459),f.call(this,16,65535),f.call(this,4,252645135),u[C]=this._lBlock,u[C+1]=this._rBlock},keySize:64/32,ivSize:64/32,blockSize:64/32});function f(u,C){var a=(this._lBlock>>>u^this._rBlock)&C;this._rBlock^=a,this._lBlock^=a<<u}function h(u,C){var a=(this._rBlock>>>u^this._lBlock)&C;this._lBlock^=a,this._rBlock^=a<<u}x.DES=B._createHelper(d);var v=o.TripleDES=B.extend({_doReset:function(){var u=this._key,C=u.words;if(C.length!==2&&C.length!==4&&C.length<6)throw new Error("Invalid key length - 3DES requires the key length to be 64, 128, 192 or >192.");var a=C.slice(0,2),s=C.length<4?C.slice(0,2):C.slice(2,4),E=C.length<6?C.slice(0,2):C.slice(4,6);this._des1=d.createEncryptor(t.create(a)),this._des2=d.createEncryptor(t.create(s)),this._des3=d.createEncryptor(t.create(E))},encryptBlock:function(u,C){this._des1.encryptBlock(u,C),this._des2.decryptBlock(u,C),this._des3.encryptBlock(u,C)},decryptBlock:function(u,C){this._des3.decryptBlock(u,C),this._des2.encryptBlock(u,C),this._des1.decryptBlock(u,C)},keySize:192/32,ivSize:64/32,blockSize:64/32});x.TripleDES=B._createHelper(v)})(),r.TripleDES})});var De=T(($0,Fe)=>{(function(r,x,n){typeof $0=="object"?Fe.exports=$0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})($0,function(r){return(function(){var x=r,n=x.lib,t=n.StreamCipher,B=x.algo,o=B.RC4=t.extend({_doReset:function(){for(var c=this._key,l=c.words,i=c.sigBytes,d=this._S=[],f=0;f<256;f++)d[f]=f;for(var f=0,h=0;f<256;f++){var v=f%i,u=l[v>>>2]>>>24-v%4*8&255;h=(h+d[f]+u)%256;var C=d[f];d[f]=d[h],d[h]=C}this._i=this._j=0},_doProcessBlock:function(c,l){c[l]^=F.call(this)},keySize:256/32,ivSize:0});function F(){for(var c=this._S,l=this._i,i=this._j,d=0,f=0;f<4;f++){l=(l+1)%256,i=(i+c[l])%256;var h=c[l];c[l]=c[i],c[i]=h,d|=c[(c[l]+c[i])%256]<<24-f*8}return this._i=l,this._j=i,d}x.RC4=t._createHelper(o);var e=B.RC4Drop=o.extend({cfg:o.cfg.extend({drop:192}),_doReset:function(){o._doReset.call(this);for(var c=this.cfg.drop;c>0;c--)F.call(this)}});x.RC4Drop=t._createHelper(e)})(),r.RC4})});var be=T((V0,pe)=>{(function(r,x,n){typeof V0=="object"?pe.exports=V0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(V0,function(r){return(function(){var x=r,n=x.lib,t=n.StreamCipher,B=x.algo,o=[],F=[],e=[],c=B.Rabbit=t.extend({_doReset:function(){for(var i=this._key.words,d=this.cfg.iv,f=0;f<4;f++)i[f]=(i[f]<<8|i[f]>>>24)&16711935|(i[f]<<24|i[f]>>>8)&4278255360;var h=this._X=[i[0],i[3]<<16|i[2]>>>16,i[1],i[0]<<16|i[3]>>>16,i[2],i[1]<<16|i[0]>>>16,i[3],i[2]<<16|i[1]>>>16],v=this._C=[i[2]<<16|i[2]>>>16,i[0]&4294901760|i[1]&65535,i[3]<<16|i[3]>>>16,i[1]&4294901760|i[2]&65535,i[0]<<16|i[0]>>>16,i[2]&4294901760|i[3]&65535,i[1]<<16|i[1]>>>16,i[3]&4294901760|i[0]&65535];this._b=0;for(var f=0;f<4;f++)l.call(this);for(var f=0;f<8;f++)v[f]^=h[f+4&7];if(d){var u=d.words,C=u[0],a=u[1],s=(C<<8|C>>>24)&16711935|(C<<24|C>>>8)&4278255360,E=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360,A=s>>>16|E&4294901760,D=E<<16|s&65535;v[0]^=s,v[1]^=A,v[2]^=E,v[3]^=D,v[4]^=s,v[5]^=A,v[6]^=E,v[7]^=D;for(var f=0;f<4;f++)l.call(this)}},_doProcessBlock:function(i,d){var f=this._X;l.call(this),o[0]=f[0]^f[5]>>>16^f[3]<<16,o[1]=f[2]^f[7]>>>16^f[5]<<16,o[2]=f[4]^f[1]>>>16^f[7]<<16,o[3]=f[6]^f[3]>>>16^f[1]<<16;for(var h=0;h<4;h++)o[h]=(o[h]<<8|o[h]>>>24)&16711935|(o[h]<<24|o[h]>>>8)&4278255360,i[d+h]^=o[h]},blockSize:128/32,ivSize:64/32});function l(){for(var i=this._X,d=this._C,f=0;f<8;f++)F[f]=d[f];d[0]=d[0]+1295307597+this._b|0,d[1]=d[1]+3545052371+(d[0]>>>0<F[0]>>>0?1:0)|0,d[2]=d[2]+886263092+(d[1]>>>0<F[1]>>>0?1:0)|0,d[3]=d[3]+1295307597+(d[2]>>>0<F[2]>>>0?1:0)|0,d[4]=d[4]+3545052371+(d[3]>>>0<F[3]>>>0?1:0)|0,d[5]=d[5]+886263092+(d[4]>>>0<F[4]>>>0?1:0)|0,d[6]=d[6]+1295307597+(d[5]>>>0<F[5]>>>0?1:0)|0,d[7]=d[7]+3545052371+(d[6]>>>0<F[6]>>>0?1:0)|0,this._b=d[7]>>>0<F[7]>>>0?1:0;for(var f=0;f<8;f++){var h=i[f]+d[f],v=h&65535,u=h>>>16,C=((v*v>>>17)+v*u>>>15)+u*u,a=((h&4294901760)*h|0)+((h&65535)*h|0);e[f]=C^a}i[0]=e[0]+(e[7]<<16|e[7]>>>16)+(e[6]<<16|e[6]>>>16)|0,i[1]=e[1]+(e[0]<<8|e[0]>>>24)+e[7]|0,i[2]=e[2]+(e[1]<<16|e[1]>>>16)+(e[0]<<16|e[0]>>>16)|0,i[3]=e[3]+(e[2]<<8|e[2]>>>24)+e[1]|0,i[4]=e[4]+(e[3]<<16|e[3]>>>16)+(e[2]<<16|e[2]>>>16)|0,i[5]=e[5]+(e[4]<<8|e[4]>>>24)+e[3]|0,i[6]=e[6]+(e[5]<<16|e[5]>>>16)+(e[4]<<16|e[4]>>>16)|0,i[7]=e[7]+(e[6]<<8|e[6]>>>24)+e[5]|0}x.Rabbit=t._createHelper(c)})(),r.Rabbit})});var ye=T((M0,_e)=>{(function(r,x,n){typeof M0=="object"?_e.exports=M0=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(M0,function(r){return(function(){var x=r,n=x.lib,t=n.StreamCipher,B=x.algo,o=[],F=[],e=[],c=B.RabbitLegacy=t.extend({_doReset:function(){var i=this._key.words,d=this.cfg.iv,f=this._X=[i[0],i[3]<<16|i[2]>>>16,i[1],i[0]<<16|i[3]>>>16,i[2],i[1]<<16|i[0]>>>16,i[3],i[2]<<16|i[1]>>>16],h=this._C=[i[2]<<16|i[2]>>>16,i[0]&4294901760|i[1]&65535,i[3]<<16|i[3]>>>16,i[1]&4294901760|i[2]&65535,i[0]<<16|i[0]>>>16,i[2]&4294901760|i[3]&65535,i[1]<<16|i[1]>>>16,i[3]&4294901760|i[0]&65535];this._b=0;for(var v=0;v<4;v++)l.call(this);for(var v=0;v<8;v++)h[v]^=f[v+4&7];if(d){var u=d.words,C=u[0],a=u[1],s=(C<<8|C>>>24)&16711935|(C<<24|C>>>8)&4278255360,E=(a<<8|a>>>24)&16711935|(a<<24|a>>>8)&4278255360,A=s>>>16|E&4294901760,D=E<<16|s&65535;h[0]^=s,h[1]^=A,h[2]^=E,h[3]^=D,h[4]^=s,h[5]^=A,h[6]^=E,h[7]^=D;for(var v=0;v<4;v++)l.call(this)}},_doProcessBlock:function(i,d){var f=this._X;l.call(this),o[0]=f[0]^f[5]>>>16^f[3]<<16,o[1]=f[2]^f[7]>>>16^f[5]<<16,o[2]=f[4]^f[1]>>>16^f[7]<<16,o[3]=f[6]^f[3]>>>16^f[1]<<16;for(var h=0;h<4;h++)o[h]=(o[h]<<8|o[h]>>>24)&16711935|(o[h]<<24|o[h]>>>8)&4278255360,i[d+h]^=o[h]},blockSize:128/32,ivSize:64/32});function l(){for(var i=this._X,d=this._C,f=0;f<8;f++)F[f]=d[f];d[0]=d[0]+1295307597+this._b|0,d[1]=d[1]+3545052371+(d[0]>>>0<F[0]>>>0?1:0)|0,d[2]=d[2]+886263092+(d[1]>>>0<F[1]>>>0?1:0)|0,d[3]=d[3]+1295307597+(d[2]>>>0<F[2]>>>0?1:0)|0,d[4]=d[4]+3545052371+(d[3]>>>0<F[3]>>>0?1:0)|0,d[5]=d[5]+886263092+(d[4]>>>0<F[4]>>>0?1:0)|0,d[6]=d[6]+1295307597+(d[5]>>>0<F[5]>>>0?1:0)|0,d[7]=d[7]+3545052371+(d[6]>>>0<F[6]>>>0?1:0)|0,this._b=d[7]>>>0<F[7]>>>0?1:0;for(var f=0;f<8;f++){var h=i[f]+d[f],v=h&65535,u=h>>>16,C=((v*v>>>17)+v*u>>>15)+u*u,a=((h&4294901760)*h|0)+((h&65535)*h|0);e[f]=C^a}i[0]=e[0]+(e[7]<<16|e[7]>>>16)+(e[6]<<16|e[6]>>>16)|0,i[1]=e[1]+(e[0]<<8|e[0]>>>24)+e[7]|0,i[2]=e[2]+(e[1]<<16|e[1]>>>16)+(e[0]<<16|e[0]>>>16)|0,i[3]=e[3]+(e[2]<<8|e[2]>>>24)+e[1]|0,i[4]=e[4]+(e[3]<<16|e[3]>>>16)+(e[2]<<16|e[2]>>>16)|0,i[5]=e[5]+(e[4]<<8|e[4]>>>24)+e[3]|0,i[6]=e[6]+(e[5]<<16|e[5]>>>16)+(e[4]<<16|e[4]>>>16)|0,i[7]=e[7]+(e[6]<<8|e[6]>>>24)+e[5]|0}x.RabbitLegacy=t._createHelper(c)})(),r.RabbitLegacy})});var ke=T((xx,ge)=>{(function(r,x,n){typeof xx=="object"?ge.exports=xx=x(O(),e0(),r0(),$(),I()):typeof define=="function"&&define.amd?define(["./core","./enc-base64","./md5","./evpkdf","./cipher-core"],x):x(r.CryptoJS)})(xx,function(r){return(function(){var x=r,n=x.lib,t=n.BlockCipher,B=x.algo;let o=16,F=[608135816,2242054355,320440878,57701188,2752067618,698298832,137296536,3964562569,1160258022,953160567,3193202383,887688300,3232508343,3380367581,1065670069,3041331479,2450970073,2306472731],e=[[3509652390,2564797868,805139163,3491422135,3101798381,1780907670,3128725573,4046225305,614570311,3012652279,134345442,2240740374,1667834072,1901547113,2757295779,4103290238,227898511,1921955416,1904987480,2182433518,2069144605,3260701109,2620446009,720527379,3318853667,677414384,3393288472,3101374703,2390351024,1614419982,1822297739,2954791486,3608508353,3174124327,2024746970,1432378464,3864339955,2857741204,1464375394,1676153920,1439316330,715854006,3033291828,289532110,2706671279,2087905683,3018724369,1668267050,732546397,1947742710,3462151702,2609353502,2950085171,1814351708,2050118529,680887927,999245976,1800124847,3300911131,1713906067,1641548236,4213287313,1216130144,1575780402,4018429277,3917837745,3693486850,3949271944,596196993,3549867205,258830323,2213823033,772490370,2760122372,1774776394,2652871518,566650946,4142492826,1728879713,2882767088,1783734482,3629395816,2517608232,2874225571,1861159788,326777828,3124490320,2130389656,2716951837,967770486,1724537150,2185432712,2364442137,1164943284,2105845187,998989502,3765401048,2244026483,1075463327,1455516326,1322494562,910128902,469688178,1117454909,936433444,3490320968,3675253459,1240580251,122909385,2157517691,634681816,4142456567,3825094682,3061402683,2540495037,79693498,3249098678,1084186820,1583128258,426386531,1761308591,1047286709,322548459,995290223,1845252383,2603652396,3431023940,2942221577,3202600964,3727903485,1712269319,422464435,3234572375,1170764815,3523960633,3117677531,1434042557,442511882,3600875718,1076654713,1738483198,4213154764,2393238008,3677496056,1014306527,4251020053,793779912,2902807211,842905082,4246964064,1395751752,1040244610,2656851899,3396308128,445077038,3742853595,3577915638,679411651,2892444358,2354009459,1767581616,3150600392,3791627101,3102740896,284835224,4246832056,1258075500,768725851,2589189241,3069724005,3532540348,1274779536,3789419226,2764799539,1660621633,3471099624,4011903706,913787905,3497959166,737222580,2514213453,2928710040,3937242737,1804850592,3499020752,2949064160,2386320175,2390070455,2415321851,4061277028,2290661394,2416832540,1336762016,1754252060,3520065937,3014181293,791618072,3188594551,3933548030,2332172193,3852520463,3043980520,413987798,3465142937,3030929376,4245938359,2093235073,3534596313,375366246,2157278981,2479649556,555357303,3870105701,2008414854,3344188149,4221384143,3956125452,2067696032,3594591187,2921233993,2428461,544322398,577241275,1471733935,610547355,4027169054,1432588573,1507829418,2025931657,3646575487,545086370,48609733,2200306550,1653985193,298326376,1316178497,3007786442,2064951626,458293330,2589141269,3591329599,3164325604,727753846,2179363840,146436021,1461446943,4069977195,705550613,3059967265,3887724982,4281599278,3313849956,1404054877,2845806497,146425753,1854211946],[1266315497,3048417604,3681880366,3289982499,290971e4,1235738493,2632868024,2414719590,3970600049,1771706367,1449415276,3266420449,422970021,1963543593,2690192192,3826793022,1062508698,1531092325,1804592342,2583117782,2714934279,4024971509,1294809318,4028980673,1289560198,2221992742,1669523910,35572830,157838143,1052438473,1016535060,1802137761,1753167236,1386275462,3080475397,2857371447,1040679964,2145300060,2390574316,1461121720,2956646967,4031777805,4028374788,33600511,2920084762,1018524850,629373528,3691585981,3515945977,2091462646,2486323059,586499841,988145025,935516892,3367335476,2599673255,2839830854,265290510,3972581182,2759138881,3795373465,1005194799,847297441,406762289,1314163512,1332590856,1866599683,4127851711,750260880,613907577,1450815602,3165620655,3734664991,3650291728,3012275730,3704569646,1427272223,778793252,1343938022,2676280711,2052605720,1946737175,3164576444,3914038668,3967478842,3682934266,1661551462,3294938066,4011595847,840292616,3712170807,616741398,312560963,711312465,1351876610,322626781,1910503582,271666773,2175563734,1594956187,70604529,3617834859,1007753275,1495573769,4069517037,2549218298,2663038764,504708206,2263041392,3941167025,2249088522,1514023603,1998579484,1312622330,694541497,2582060303,2151582166,1382467621,776784248,2618340202,3323268794,2497899128,2784771155,503983604,4076293799,907881277,423175695,432175456,1378068232,4145222326,3954048622,3938656102,3820766613,2793130115,2977904593,26017576,3274890735,3194772133,1700274565,1756076034,4006520079,3677328699,720338349,1533947780,354530856,688349552,3973924725,1637815568,332179504,3949051286,53804574,2852348879,3044236432,1282449977,3583942155,3416972820,4006381244,1617046695,2628476075,3002303598,1686838959,431878346,2686675385,1700445008,1080580658,1009431731,832498133,3223435511,2605976345,2271191193,2516031870,1648197032,4164389018,2548247927,300782431,375919233,238389289,3353747414,2531188641,2019080857,1475708069,455242339,2609103871,448939670,3451063019,1395535956,2413381860,1841049896,1491858159,885456874,4264095073,4001119347,1565136089,3898914787,1108368660,540939232,1173283510,2745871338,3681308437,4207628240,3343053890,4016749493,1699691293,1103962373,3625875870,2256883143,3830138730,1031889488,3479347698,1535977030,4236805024,3251091107,2132092099,1774941330,1199868427,1452454533,157007616,2904115357,342012276,595725824,1480756522,206960106,497939518,591360097,863170706,2375253569,3596610801,1814182875,2094937945,3421402208,1082520231,3463918190,2785509508,435703966,3908032597,1641649973,2842273706,3305899714,1510255612,2148256476,2655287854,3276092548,4258621189,236887753,3681803219,274041037,1734335097,3815195456,3317970021,1899903192,1026095262,4050517792,356393447,2410691914,3873677099,3682840055],[3913112168,2491498743,4132185628,2489919796,1091903735,1979897079,3170134830,3567386728,3557303409,857797738,1136121015,1342202287,507115054,2535736646,337727348,3213592640,1301675037,2528481711,1895095763,1721773893,3216771564,62756741,2142006736,835421444,2531993523,1442658625,3659876326,2882144922,676362277,1392781812,170690266,3921047035,1759253602,3611846912,1745797284,664899054,1329594018,3901205900,3045908486,2062866102,2865634940,3543621612,3464012697,1080764994,553557557,3656615353,3996768171,991055499,499776247,1265440854,648242737,3940784050,980351604,3713745714,1749149687,3396870395,4211799374,3640570775,1161844396,3125318951,1431517754,545492359,4268468663,3499529547,1437099964,2702547544,3433638243,2581715763,2787789398,1060185593,1593081372,2418618748,4260947970,69676912,2159744348,86519011,2512459080,3838209314,1220612927,3339683548,133810670,1090789135,1078426020,1569222167,845107691,3583754449,4072456591,1091646820,628848692,1613405280,3757631651,526609435,236106946,48312990,2942717905,3402727701,1797494240,859738849,992217954,4005476642,2243076622,3870952857,3732016268,765654824,3490871365,2511836413,1685915746,3888969200,1414112111,2273134842,3281911079,4080962846,172450625,2569994100,980381355,4109958455,2819808352,2716589560,2568741196,3681446669,3329971472,1835478071,660984891,3704678404,4045999559,3422617507,3040415634,1762651403,1719377915,3470491036,2693910283,3642056355,3138596744,1364962596,2073328063,1983633131,926494387,3423689081,2150032023,4096667949,1749200295,3328846651,309677260,2016342300,1779581495,3079819751,111262694,1274766160,443224088,298511866,1025883608,3806446537,1145181785,168956806,3641502830,3584813610,1689216846,3666258015,3200248200,1692713982,2646376535,4042768518,1618508792,1610833997,3523052358,4130873264,2001055236,3610705100,2202168115,4028541809,2961195399,1006657119,2006996926,3186142756,1430667929,3210227297,1314452623,4074634658,4101304120,2273951170,1399257539,3367210612,3027628629,1190975929,2062231137,2333990788,2221543033,2438960610,1181637006,548689776,2362791313,3372408396,3104550113,3145860560,296247880,1970579870,3078560182,3769228297,1714227617,3291629107,3898220290,166772364,1251581989,493813264,448347421,195405023,2709975567,677966185,3703036547,1463355134,2715995803,1338867538,1343315457,2802222074,2684532164,233230375,2599980071,2000651841,3277868038,1638401717,4028070440,3237316320,6314154,819756386,300326615,590932579,1405279636,3267499572,3150704214,2428286686,3959192993,3461946742,1862657033,1266418056,963775037,2089974820,2263052895,1917689273,448879540,3550394620,3981727096,150775221,3627908307,1303187396,508620638,2975983352,2726630617,1817252668,1876281319,1457606340,908771278,3720792119,3617206836,2455994898,1729034894,1080033504],[976866871,3556439503,2881648439,1522871579,1555064734,1336096578,3548522304,2579274686,3574697629,3205460757,3593280638,3338716283,3079412587,564236357,2993598910,1781952180,1464380207,3163844217,3332601554,1699332808,1393555694,1183702653,3581086237,1288719814,691649499,2847557200,2895455976,3193889540,2717570544,1781354906,1676643554,2592534050,3230253752,1126444790,2770207658,2633158820,2210423226,2615765581,2414155088,3127139286,673620729,2805611233,1269405062,4015350505,3341807571,4149409754,1057255273,2012875353,2162469141,2276492801,2601117357,993977747,3918593370,2654263191,753973209,36408145,2530585658,25011837,3520020182,2088578344,530523599,2918365339,1524020338,1518925132,3760827505,3759777254,1202760957,3985898139,3906192525,674977740,4174734889,2031300136,2019492241,3983892565,4153806404,3822280332,352677332,2297720250,60907813,90501309,3286998549,1016092578,2535922412,2839152426,457141659,509813237,4120667899,652014361,1966332200,2975202805,55981186,2327461051,676427537,3255491064,2882294119,3433927263,1307055953,942726286,933058658,2468411793,3933900994,4215176142,1361170020,2001714738,2830558078,3274259782,1222529897,1679025792,2729314320,3714953764,1770335741,151462246,3013232138,1682292957,1483529935,471910574,1539241949,458788160,3436315007,1807016891,3718408830,978976581,1043663428,3165965781,1927990952,4200891579,2372276910,3208408903,3533431907,1412390302,2931980059,4132332400,1947078029,3881505623,4168226417,2941484381,1077988104,1320477388,886195818,18198404,3786409e3,2509781533,112762804,3463356488,1866414978,891333506,18488651,661792760,1628790961,3885187036,3141171499,876946877,2693282273,1372485963,791857591,2686433993,3759982718,3167212022,3472953795,2716379847,445679433,3561995674,3504004811,3574258232,54117162,3331405415,2381918588,3769707343,4154350007,1140177722,4074052095,668550556,3214352940,367459370,261225585,2610173221,4209349473,3468074219,3265815641,314222801,3066103646,3808782860,282218597,3406013506,3773591054,379116347,1285071038,846784868,2669647154,3771962079,3550491691,2305946142,453669953,1268987020,3317592352,3279303384,3744833421,2610507566,3859509063,266596637,3847019092,517658769,3462560207,3443424879,370717030,4247526661,2224018117,4143653529,4112773975,2788324899,2477274417,1456262402,2901442914,1517677493,1846949527,2295493580,3734397586,2176403920,1280348187,1908823572,3871786941,846861322,1172426758,3287448474,3383383037,1655181056,3139813346,901632758,1897031941,2986607138,3066810236,3447102507,1393639104,373351379,950779232,625454576,3124240540,4148612726,2007998917,544563296,2244738638,2330496472,2058025392,1291430526,424198748,50039436,29584100,3605783033,2429876329,2791104160,1057563949,3255363231,3075367218,3463963227,1469046755,985887462]];var c={pbox:[],sbox:[]};function l(v,u){let C=u>>24&255,a=u>>16&255,s=u>>8&255,E=u&255,A=v.sbox[0][C]+v.sbox[1][a];return A=A^v.sbox[2][s],A=A+v.sbox[3][E],A}function i(v,u,C){let a=u,s=C,E;for(let A=0;A<o;++A)a=a^v.pbox[A],s=l(v,a)^s,E=a,a=s,s=E;return E=a,a=s,s=E,s=s^v.pbox[o],a=a^v.pbox[o+1],{left:a,right:s}}function d(v,u,C){let a=u,s=C,E;for(let A=o+1;A>1;--A)a=a^v.pbox[A],s=l(v,a)^s,E=a,a=s,s=E;return E=a,a=s,s=E,s=s^v.pbox[1],a=a^v.pbox[0],{left:a,right:s}}function f(v,u,C){for(let D=0;D<4;D++){v.sbox[D]=[];for(let p=0;p<256;p++)v.sbox[D][p]=e[D][p]}let a=0;for(let D=0;D<o+2;D++)v.pbox[D]=F[D]^u[a],a++,a>=C&&(a=0);let s=0,E=0,A=0;for(let D=0;D<o+2;D+=2)A=i(v,s,E),s=A.left,E=A.right,v.pbox[D]=s,v.pbox[D+1]=E;for(let D=0;D<4;D++)for(let p=0;p<256;p+=2)A=i(v,s,E),s=A.left,E=A.right,v.sbox[D][p]=s,v.sbox[D][p+1]=E;return!0}var h=B.Blowfish=t.extend({_doReset:function(){if(this._keyPriorReset!==this._key){var v=this._keyPriorReset=this._key,u=v.words,C=v.sigBytes/4;f(c,u,C)}},encryptBlock:function(v,u){var C=i(c,v[u],v[u+1]);v[u]=C.left,v[u+1]=C.right},decryptBlock:function(v,u){var C=d(c,v[u],v[u+1]);v[u]=C.left,v[u+1]=C.right},blockSize:64/32,keySize:128/32,ivSize:64/32});x.Blowfish=t._createHelper(h)})(),r.Blowfish})});var Se=T((ex,me)=>{(function(r,x,n){typeof ex=="object"?me.exports=ex=x(O(),B0(),yx(),kx(),e0(),wx(),r0(),ix(),k0(),Wx(),nx(),Lx(),Ox(),Xx(),R0(),Kx(),$(),I(),Qx(),$x(),Mx(),ee(),te(),ne(),fe(),ce(),ve(),Be(),le(),Ce(),Ee(),De(),be(),ye(),ke()):typeof define=="function"&&define.amd?define(["./core","./x64-core","./lib-typedarrays","./enc-utf16","./enc-base64","./enc-base64url","./md5","./sha1","./sha256","./sha224","./sha512","./sha384","./sha3","./ripemd160","./hmac","./pbkdf2","./evpkdf","./cipher-core","./mode-cfb","./mode-ctr","./mode-ctr-gladman","./mode-ofb","./mode-ecb","./pad-ansix923","./pad-iso10126","./pad-iso97971","./pad-zeropadding","./pad-nopadding","./format-hex","./aes","./tripledes","./rc4","./rabbit","./rabbit-legacy","./blowfish"],x):r.CryptoJS=x(r.CryptoJS)})(ex,function(r){return r})});var sr={};$e(sr,{genOuput:()=>cr,genReq:()=>or});var V=Ve(Se(),1);function ax(r,x){let[n,t,B,o]=r;function F(i,d,f,h,v,u,C){return i=i+(d&f|~d&h)+v+C|0,(i<<u|i>>>32-u)+d|0}function e(i,d,f,h,v,u,C){return i=i+(d&h|f&~h)+v+C|0,(i<<u|i>>>32-u)+d|0}function c(i,d,f,h,v,u,C){return i=i+(d^f^h)+v+C|0,(i<<u|i>>>32-u)+d|0}function l(i,d,f,h,v,u,C){return i=i+(f^(d|~h))+v+C|0,(i<<u|i>>>32-u)+d|0}n=F(n,t,B,o,x[0],7,-680876936),o=F(o,n,t,B,x[1],12,-389564586),B=F(B,o,n,t,x[2],17,606105819),t=F(t,B,o,n,x[3],22,-1044525330),n=F(n,t,B,o,x[4],7,-176418897),o=F(o,n,t,B,x[5],12,1200080426),B=F(B,o,n,t,x[6],17,-1473231341),t=F(t,B,o,n,x[7],22,-45705983),n=F(n,t,B,o,x[8],7,1770035416),o=F(o,n,t,B,x[9],12,-1958414417),B=F(B,o,n,t,x[10],17,-42063),t=F(t,B,o,n,x[11],22,-1990404162),n=F(n,t,B,o,x[12],7,1804603682),o=F(o,n,t,B,x[13],12,-40341101),B=F(B,o,n,t,x[14],17,-1502002290),t=F(t,B,o,n,x[15],22,1236535329),n=e(n,t,B,o,x[1],5,-165796510),o=e(o,n,t,B,x[6],9,-1069501632),B=e(B,o,n,t,x[11],14,643717713),t=e(t,B,o,n,x[0],20,-373897302),n=e(n,t,B,o,x[5],5,-701558691),o=e(o,n,t,B,x[10],9,38016083),B=e(B,o,n,t,x[15],14,-660478335),t=e(t,B,o,n,x[4],20,-405537848),n=e(n,t,B,o,x[9],5,568446438),o=e(o,n,t,B,x[14],9,-1019803690),B=e(B,o,n,t,x[3],14,-187363961),t=e(t,B,o,n,x[8],20,1163531501),n=e(n,t,B,o,x[13],5,-1444681467),o=e(o,n,t,B,x[2],9,-51403784),B=e(B,o,n,t,x[7],14,1735328473),t=e(t,B,o,n,x[12],20,-1926607734),n=c(n,t,B,o,x[5],4,-378558),o=c(o,n,t,B,x[8],11,-2022574463),B=c(B,o,n,t,x[11],16,1839030562),t=c(t,B,o,n,x[14],23,-35309556),n=c(n,t,B,o,x[1],4,-1530992060),o=c(o,n,t,B,x[4],11,1272893353),B=c(B,o,n,t,x[7],16,-155497632),t=c(t,B,o,n,x[10],23,-1094730640),n=c(n,t,B,o,x[13],4,681279174),o=c(o,n,t,B,x[0],11,-358537222),B=c(B,o,n,t,x[3],16,-722521979),t=c(t,B,o,n,x[6],23,76029189),n=c(n,t,B,o,x[9],4,-640364487),o=c(o,n,t,B,x[12],11,-421815835),B=c(B,o,n,t,x[15],16,530742520),t=c(t,B,o,n,x[2],23,-995338651),n=l(n,t,B,o,x[0],6,-198630844),o=l(o,n,t,B,x[7],10,1126891415),B=l(B,o,n,t,x[14],15,-1416354905),t=l(t,B,o,n,x[5],21,-57434055),n=l(n,t,B,o,x[12],6,1700485571),o=l(o,n,t,B,x[3],10,-1894986606),B=l(B,o,n,t,x[10],15,-1051523),t=l(t,B,o,n,x[1],21,-2054922799),n=l(n,t,B,o,x[8],6,1873313359),o=l(o,n,t,B,x[15],10,-30611744),B=l(B,o,n,t,x[6],15,-1560198380),t=l(t,B,o,n,x[13],21,1309151649),n=l(n,t,B,o,x[4],6,-145523070),o=l(o,n,t,B,x[11],10,-1120210379),B=l(B,o,n,t,x[2],15,718787259),t=l(t,B,o,n,x[9],21,-343485551),r[0]=r[0]+n|0,r[1]=r[1]+t|0,r[2]=r[2]+B|0,r[3]=r[3]+o|0}function xr(r){let x=[];for(let n=0;n<64;n+=4)x[n>>2]=r.charCodeAt(n)+(r.charCodeAt(n+1)<<8)+(r.charCodeAt(n+2)<<16)+(r.charCodeAt(n+3)<<24);return x}function er(r){let x=r.length,n=[1732584193,-271733879,-1732584194,271733878],t,B;for(t=64;t<=x;t+=64)ax(n,xr(r.substring(t-64,t)));for(r=r.substring(t-64),B=new Array(16).fill(0),t=0;t<r.length;t++)B[t>>2]|=r.charCodeAt(t)<<(t%4<<3);B[t>>2]|=128<<(t%4<<3),t>55&&(ax(n,B),B=new Array(16).fill(0));let o=x*8;return B[14]=o&4294967295,B[15]=o/4294967296|0,ax(n,B),n}function rr(r){let x="";for(let n=0;n<4;n++)x+="0123456789abcdef"[r>>n*8+4&15]+"0123456789abcdef"[r>>n*8&15];return x}function tr(r){return er(r).map(rr).join("")}function ir(r,x,n,t){let F=Object.keys(r).sort().map(c=>c+"="+r[c]).join("&")+"&salt="+x+"&ts="+n+"&secret="+t;return tr(F).replace(/b/g,"#").replace(/d/g,"F").replace(/#/g,"C")}function nr(r,x){let n=Math.floor(Date.now()/1e3),t=Math.random().toString(36).substring(2,10),B=ir(r,t,n,x);return{...r,time:n,key:t,sign:B}}function we(r){return r.split("").map(x=>{let n="ZYXABCDEFGHIJKLMNOPQRSTUVWzyxabcdefghijklmnopqrstuvw9876543210-_".indexOf(x);return n===-1?x:"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"[n]}).join("")}function He(r,x=8){let n="";for(let t=0;t<r.length;t+=x){let B=r.slice(t,t+x);n+=B.split("").reverse().join("")}return n}function qe(r,x=90){let n=[];for(let t=0;t<r.length;t++)n.push(String.fromCharCode(r.charCodeAt(t)^x));return n.join("")}function ar(r,x,n){if(typeof V.default>"u")throw new Error("CryptoJS library is required for aesDecrypt. Please include crypto-js in your project.");let t=V.default.enc.Utf8.parse(n),B=V.default.enc.Base64.parse(x),o=V.default.enc.Base64.parse(r),e=V.default.AES.decrypt({ciphertext:o},t,{iv:B,mode:V.default.mode.CBC,padding:V.default.pad.Pkcs7}).toString(V.default.enc.Utf8);return JSON.parse(e)}function fr(r,x,n){try{let t=atob(r),B=atob(x);return t=qe(t),B=qe(B),t=He(t),B=He(B),t=we(t),B=we(B),ar(t,B,n)}catch(t){throw t}}function or(r){let t=nr({requestURL:r},"TI52hwg30V08ycUo9"),B=t.sign;return delete t.sign,{body:JSON.stringify(t),auth:B}}function cr(r){return r=JSON.parse(r),r=fr(r.data,r.key,"93838338562359368888868323563256"),{title:r.title,url:r.videos[0].video_fullinfo.at(-1).url,cover:r.cover||""}}return Me(sr);})();
 /*! Bundled license information:
 
 crypto-js/ripemd160.js:
@@ -902,5 +902,6 @@ export function genOuput(resp) {
   return {
     title: resp.title,
     url: resp.videos[0].video_fullinfo.at(-1).url,
+    cover: resp.cover || "",
   };
 }
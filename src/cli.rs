@@ -0,0 +1,431 @@
+use crate::{
+    AllDown, EntryKind, ExtractError, Extracted, Extractor, FormatPolicy, MediaInfo, PlaylistEntry,
+    PlaylistFilter,
+    download::{AudioFormat, DownloadOptions, DownloadState, download},
+    parse_date,
+};
+use anyhow::{Context, bail};
+use std::{env, path::PathBuf, sync::Arc, time::Duration};
+
+pub const USAGE: &str =
+    "用法: unidown <url> [-o <目录>] [--threads <线程数>] [--items <序号>] [--format <策略>] [--embed-subs] [--audio <格式>]
+                  [--since <日期>] [--until <日期>] [--max <数量>] [--only <类型>] [--skip-downloaded]
+                  [--segment-time <分钟>] [--segment-size <MB>] [--slideshow] [--third-party]
+
+参数:
+  -o, --output <目录>     保存目录，默认为当前目录
+  -t, --threads <线程数>  每个文件的下载线程数，默认为 16
+  -i, --items <序号>      列表（分 P、合集等）中要下载的项，如 1,3-5，默认全部
+  -f, --format <策略>     清晰度、编码和音轨策略，如 best、avc、1080p、hevc,4k、flac、dolby，默认 best
+      --embed-subs        把字幕、弹幕封装进视频（输出 mkv），默认只保存在视频旁边
+  -a, --audio <格式>      只下载音频，保存为 m4a、mp3、opus 或 flac
+      --since <日期>      只下载该日期及之后发布的项，如 2024-01-01
+      --until <日期>      只下载该日期及之前发布的项
+      --max <数量>        列表最多下载多少项
+      --only <类型>       列表只下载 video（视频）或 image（图文）
+      --skip-downloaded   跳过下载记录里已有的项
+      --segment-time <分钟>  直播录制每段的时长，默认 60 分钟，0 表示不按时长分段
+      --segment-size <MB>    直播录制每段的大小，默认不限，按 Ctrl+C 停止录制
+      --slideshow         图文作品额外合成幻灯片视频
+      --third-party       抖音原生解析失败时把链接发给第三方接口 hellotik.app，默认关闭
+  -h, --help              显示帮助";
+
+/// 命令行的解析结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    /// 没有参数，启动图形界面
+    Gui,
+    /// 打印 [`USAGE`] 后退出
+    Help,
+    Download(CliArgs),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliArgs {
+    pub url: String,
+    pub output_dir: Option<PathBuf>,
+    pub threads: Option<usize>,
+    /// 从 1 开始的列表序号
+    pub items: Option<Vec<usize>>,
+    pub format: Option<FormatPolicy>,
+    pub embed_subtitles: bool,
+    pub audio_format: Option<AudioFormat>,
+    /// 列表的日期、数量筛选，在 `items` 之后应用
+    pub filter: PlaylistFilter,
+    pub segment_duration: Option<Duration>,
+    /// 字节数
+    pub segment_size: Option<u64>,
+    pub slideshow: bool,
+    /// 允许抖音原生解析失败时改用第三方接口，默认不允许
+    pub third_party: bool,
+}
+
+impl CliArgs {
+    /// 解析命令行参数（不含程序名），不在这里打印或退出，交给调用方处理
+    pub fn parse(args: impl IntoIterator<Item = String>) -> anyhow::Result<CliCommand> {
+        let mut args = args.into_iter().peekable();
+        if args.peek().is_none() {
+            return Ok(CliCommand::Gui);
+        }
+        let mut url = None;
+        let mut output_dir = None;
+        let mut threads = None;
+        let mut items = None;
+        let mut format = None;
+        let mut embed_subtitles = false;
+        let mut audio_format = None;
+        let mut filter = PlaylistFilter::default();
+        let mut segment_duration = Some(DownloadOptions::DEFAULT_SEGMENT_DURATION);
+        let mut segment_size = None;
+        let mut slideshow = false;
+        let mut third_party = false;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => return Ok(CliCommand::Help),
+                "-o" | "--output" => {
+                    let dir = args.next().context("-o 后面需要一个目录")?;
+                    output_dir = Some(PathBuf::from(dir));
+                }
+                "-t" | "--threads" => {
+                    let n = args.next().context("--threads 后面需要一个数字")?;
+                    let n: usize = n.parse().context("线程数必须是正整数")?;
+                    if n == 0 {
+                        bail!("线程数必须是正整数");
+                    }
+                    threads = Some(n);
+                }
+                "-i" | "--items" => {
+                    let list = args.next().context("--items 后面需要序号列表")?;
+                    items = Some(parse_items(&list)?);
+                }
+                "-f" | "--format" => {
+                    let policy = args.next().context("--format 后面需要格式策略")?;
+                    format = Some(FormatPolicy::parse(&policy)?);
+                }
+                "--embed-subs" => embed_subtitles = true,
+                "-a" | "--audio" => {
+                    let format = args.next().context("--audio 后面需要音频格式")?;
+                    audio_format = Some(AudioFormat::parse(&format)?);
+                }
+                "--since" => {
+                    let date = args.next().context("--since 后面需要日期")?;
+                    filter.since = Some(parse_date(&date).context("日期格式应为 YYYY-MM-DD")?);
+                }
+                "--until" => {
+                    let date = args.next().context("--until 后面需要日期")?;
+                    let date = parse_date(&date).context("日期格式应为 YYYY-MM-DD")?;
+                    // 包含当天
+                    filter.until = Some(date + 86400);
+                }
+                "--max" => {
+                    let n = args.next().context("--max 后面需要一个数字")?;
+                    let n: usize = n.parse().context("数量必须是正整数")?;
+                    if n == 0 {
+                        bail!("数量必须是正整数");
+                    }
+                    filter.max_count = Some(n);
+                }
+                "--only" => {
+                    let kind = args.next().context("--only 后面需要作品类型")?;
+                    filter.kind = Some(EntryKind::parse(&kind)?);
+                }
+                "--skip-downloaded" => filter.skip_downloaded = true,
+                "--segment-time" => {
+                    let n = args.next().context("--segment-time 后面需要分钟数")?;
+                    let n: u64 = n.parse().context("分段时长必须是非负整数")?;
+                    segment_duration = (n > 0).then(|| Duration::from_secs(n * 60));
+                }
+                "--segment-size" => {
+                    let n = args.next().context("--segment-size 后面需要 MB 数")?;
+                    let n: u64 = n.parse().context("分段大小必须是正整数")?;
+                    if n == 0 {
+                        bail!("分段大小必须是正整数");
+                    }
+                    segment_size = Some(n * 1024 * 1024);
+                }
+                "--slideshow" => slideshow = true,
+                "--third-party" => third_party = true,
+                _ if arg.starts_with('-') => bail!("未知参数: {arg}\n\n{USAGE}"),
+                _ if url.is_none() => url = Some(arg),
+                _ => bail!("只能传入一个链接\n\n{USAGE}"),
+            }
+        }
+        let url = url.with_context(|| format!("缺少链接\n\n{USAGE}"))?;
+        Ok(CliCommand::Download(Self {
+            url,
+            output_dir,
+            threads,
+            items,
+            format,
+            embed_subtitles,
+            audio_format,
+            filter,
+            segment_duration,
+            segment_size,
+            slideshow,
+            third_party,
+        }))
+    }
+}
+
+/// 解析 `1,3-5` 这样的序号列表
+fn parse_items(list: &str) -> anyhow::Result<Vec<usize>> {
+    let mut items = Vec::new();
+    for part in list.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (start, end) = part.split_once('-').unwrap_or((part, part));
+        let start: usize = start.trim().parse().context("序号必须是正整数")?;
+        let end: usize = end.trim().parse().context("序号必须是正整数")?;
+        if start == 0 || start > end {
+            bail!("无效的序号范围: {part}");
+        }
+        items.extend(start..=end);
+    }
+    if items.is_empty() {
+        bail!("序号列表为空");
+    }
+    Ok(items)
+}
+
+/// 无界面模式：解析、下载、合并，进度和最终路径打印到 stdout
+pub async fn run_cli(args: CliArgs) -> anyhow::Result<Vec<PathBuf>> {
+    let output_dir = match args.output_dir {
+        Some(dir) => dir,
+        None => env::current_dir().context("找不到下载目录")?,
+    };
+    tokio::fs::create_dir_all(&output_dir)
+        .await
+        .context("无法创建下载目录")?;
+    let options = DownloadOptions {
+        output_dir,
+        threads: args.threads.unwrap_or(DownloadOptions::DEFAULT_THREADS),
+        embed_subtitles: args.embed_subtitles,
+        segment_duration: args.segment_duration,
+        segment_size: args.segment_size,
+        audio_format: args.audio_format,
+        slideshow: args.slideshow,
+    };
+    let policy = args.format.unwrap_or_default();
+    let all_down = AllDown::all_sites(args.third_party);
+    let extracted = all_down
+        .extract(&args.url)
+        .await?
+        .context(ExtractError::Empty)?;
+    let playlist = match extracted {
+        Extracted::Media(info) => return Ok(vec![download_media(info, &options, &policy).await?]),
+        Extracted::Playlist(playlist) => playlist,
+    };
+    println!(
+        "列表: {}（共 {} 项）",
+        playlist.title,
+        playlist.entries.len()
+    );
+    let entries = select_entries(playlist.entries, args.items.as_deref())?;
+    let selected = args.filter.select(&entries);
+    let entries: Vec<_> = entries
+        .into_iter()
+        .zip(selected)
+        .filter_map(|(entry, selected)| selected.then_some(entry))
+        .collect();
+    if args.filter != PlaylistFilter::default() {
+        println!("筛选后剩余 {} 项", entries.len());
+    }
+    let mut paths = Vec::with_capacity(entries.len());
+    let mut failed = 0;
+    for entry in entries {
+        println!("解析: {}", entry.title);
+        let result = async {
+            let mut paths = Vec::new();
+            for info in all_down.extract_media(&entry.input).await? {
+                paths.push(download_media(info, &options, &policy).await?);
+            }
+            anyhow::Ok(paths)
+        }
+        .await;
+        match result {
+            Ok(entry_paths) => paths.extend(entry_paths),
+            Err(e) => {
+                failed += 1;
+                eprintln!("{} 下载失败: {:#}", entry.title, e);
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{} 项下载失败", failed);
+    }
+    Ok(paths)
+}
+
+fn select_entries(
+    entries: Vec<PlaylistEntry>,
+    items: Option<&[usize]>,
+) -> anyhow::Result<Vec<PlaylistEntry>> {
+    let Some(items) = items else {
+        return Ok(entries);
+    };
+    items
+        .iter()
+        .map(|&i| {
+            entries
+                .get(i - 1)
+                .cloned()
+                .with_context(|| format!("第 {i} 项不存在，列表共 {} 项", entries.len()))
+        })
+        .collect()
+}
+
+async fn download_media(
+    mut info: MediaInfo,
+    options: &DownloadOptions,
+    policy: &FormatPolicy,
+) -> anyhow::Result<PathBuf> {
+    println!("标题: {}", info.title);
+    info.apply_policy(policy);
+    if options.audio_format.is_some() {
+        info.keep_audio_only();
+    }
+    if !info.formats.is_empty() {
+        for stream in &info.streams {
+            println!("[{}] {}", stream.kind.label(), stream.format.describe());
+        }
+    }
+    let labels: Vec<_> = info.streams.iter().map(|s| s.kind.label()).collect();
+    let is_live = info.is_live();
+    let state = DownloadState::new(&info);
+    let mut task = tokio::spawn(download(info, options.clone(), state.clone()));
+    let mut ticker = tokio::time::interval(Duration::from_secs(1));
+    let path = loop {
+        tokio::select! {
+            res = &mut task => break res??,
+            _ = ticker.tick() => print_progress(&labels, &state, is_live),
+            // 直播录制按 Ctrl+C 正常结束，已录制的文件保留
+            _ = tokio::signal::ctrl_c(), if is_live && !state.is_stop_requested() => {
+                println!("正在停止录制...");
+                state.request_stop();
+            }
+        }
+    };
+    print_progress(&labels, &state, is_live);
+    println!("{}", path.display());
+    Ok(path)
+}
+
+fn print_progress(labels: &[&str], state: &Arc<DownloadState>, is_live: bool) {
+    if is_live {
+        println!("[直播] {}", state.live_display());
+        return;
+    }
+    let mut line = labels
+        .iter()
+        .zip(&state.streams)
+        .map(|(label, progress)| format!("[{}] {}", label, progress.display().0))
+        .collect::<Vec<_>>()
+        .join("  ");
+    if state.streams.len() > 1 {
+        line.push_str(&format!("  [合并] {}", state.merge_display()));
+    }
+    println!("{line}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> anyhow::Result<CliCommand> {
+        CliArgs::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(&[]).unwrap(), CliCommand::Gui);
+        assert_eq!(parse(&["url", "-h"]).unwrap(), CliCommand::Help);
+        assert_eq!(parse(&["--help", "-x"]).unwrap(), CliCommand::Help);
+        assert_eq!(
+            parse(&["https://b23.tv/abc"]).unwrap(),
+            CliCommand::Download(CliArgs {
+                url: "https://b23.tv/abc".into(),
+                output_dir: None,
+                threads: None,
+                items: None,
+                format: None,
+                embed_subtitles: false,
+                audio_format: None,
+                filter: PlaylistFilter::default(),
+                segment_duration: Some(DownloadOptions::DEFAULT_SEGMENT_DURATION),
+                segment_size: None,
+                slideshow: false,
+                third_party: false,
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "-o",
+                "out",
+                "https://b23.tv/abc",
+                "--threads",
+                "4",
+                "-i",
+                "1,3-5",
+                "-f",
+                "avc,1080p",
+                "--embed-subs",
+                "-a",
+                "mp3",
+                "--since",
+                "2024-01-01",
+                "--until",
+                "2024-01-31",
+                "--max",
+                "10",
+                "--only",
+                "image",
+                "--skip-downloaded",
+                "--segment-time",
+                "30",
+                "--segment-size",
+                "2048",
+                "--slideshow",
+                "--third-party"
+            ])
+            .unwrap(),
+            CliCommand::Download(CliArgs {
+                url: "https://b23.tv/abc".into(),
+                output_dir: Some(PathBuf::from("out")),
+                threads: Some(4),
+                items: Some(vec![1, 3, 4, 5]),
+                format: Some(FormatPolicy::parse("avc,1080p").unwrap()),
+                embed_subtitles: true,
+                audio_format: Some(AudioFormat::Mp3),
+                filter: PlaylistFilter {
+                    since: Some(1_704_067_200),
+                    until: Some(1_706_745_600),
+                    max_count: Some(10),
+                    skip_downloaded: true,
+                    kind: Some(EntryKind::Gallery),
+                },
+                segment_duration: Some(Duration::from_secs(1800)),
+                segment_size: Some(2048 * 1024 * 1024),
+                slideshow: true,
+                third_party: true,
+            })
+        );
+        assert!(parse(&["--threads", "0", "url"]).is_err());
+        assert!(parse(&["-o"]).is_err());
+        assert!(parse(&["-x", "url"]).is_err());
+        assert!(parse(&["url1", "url2"]).is_err());
+        assert!(parse(&["-i", "0", "url"]).is_err());
+        assert!(parse(&["-i", "3-1", "url"]).is_err());
+        assert!(parse(&["-f", "fast", "url"]).is_err());
+        assert!(parse(&["--since", "2024-13-01", "url"]).is_err());
+        assert!(parse(&["--max", "0", "url"]).is_err());
+        assert!(parse(&["-a", "wav", "url"]).is_err());
+        assert!(parse(&["--only", "live", "url"]).is_err());
+        assert!(matches!(
+            parse(&["--segment-time", "0", "url"]).unwrap(),
+            CliCommand::Download(CliArgs {
+                segment_duration: None,
+                ..
+            })
+        ));
+        assert!(parse(&["--segment-time", "-1", "url"]).is_err());
+    }
+}
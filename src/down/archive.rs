@@ -0,0 +1,82 @@
+use crate::{FFMPEG_DIR, MediaInfo, PartOf};
+use lazy_static::lazy_static;
+use parking_lot::Mutex;
+use std::{collections::HashSet, path::PathBuf};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+use tracing::warn;
+
+lazy_static! {
+    static ref ARCHIVE: Mutex<HashSet<String>> = Mutex::new(load());
+}
+
+/// 已下载记录，保存在 `~/.unidown/archive.txt`，每行一个 [`MediaInfo::id`](crate::MediaInfo)
+fn archive_path() -> PathBuf {
+    FFMPEG_DIR.join("archive.txt")
+}
+
+fn load() -> HashSet<String> {
+    std::fs::read_to_string(archive_path())
+        .map(|text| {
+            text.lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub fn is_downloaded(id: &str) -> bool {
+    ARCHIVE.lock().contains(id)
+}
+
+/// 记录下载完成，多 P 视频的最后一 P 完成时把整个视频也记上
+pub async fn mark_finished(info: &MediaInfo) {
+    mark_downloaded(&info.id).await;
+    if let Some(part_of) = &info.part_of
+        && is_complete(&ARCHIVE.lock(), part_of)
+    {
+        mark_downloaded(&part_of.id).await;
+    }
+}
+
+fn is_complete(archive: &HashSet<String>, part_of: &PartOf) -> bool {
+    part_of.parts.iter().all(|id| archive.contains(id))
+}
+
+/// 记录下载完成，写入失败只记录日志
+pub async fn mark_downloaded(id: &str) {
+    if id.is_empty() || !ARCHIVE.lock().insert(id.to_string()) {
+        return;
+    }
+    let result = async {
+        tokio::fs::create_dir_all(FFMPEG_DIR.as_path()).await?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(archive_path())
+            .await?;
+        file.write_all(format!("{}\n", id).as_bytes()).await
+    }
+    .await;
+    if let Err(e) = result {
+        warn!(err = ?e, "保存下载记录失败");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_complete() {
+        let part_of = PartOf {
+            id: "BV17x411w7KC".into(),
+            parts: vec!["BV17x411w7KC_p1".into(), "BV17x411w7KC_p2".into()],
+        };
+        let mut archive = HashSet::from(["BV17x411w7KC_p1".to_string()]);
+        assert!(!is_complete(&archive, &part_of));
+        archive.insert("BV17x411w7KC_p2".into());
+        assert!(is_complete(&archive, &part_of));
+    }
+}
@@ -0,0 +1,430 @@
+use crate::{EntryKind, Playlist, PlaylistEntry, bilibili::check_code};
+use anyhow::Context as _;
+use lazy_static::lazy_static;
+use md5::{Digest, Md5};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use regex::Regex;
+use reqwest::Client;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tracing::info;
+
+/// 收藏夹、合集、系列和 UP 主投稿这几种可以批量下载的列表
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BiliList {
+    Favorites(u64),
+    Season { mid: u64, id: u64 },
+    Series { mid: u64, id: u64 },
+    Space(u64),
+}
+
+pub(crate) fn extract_list_id(url: &str) -> Option<BiliList> {
+    lazy_static! {
+        static ref FAV_REGEX: Regex =
+            Regex::new(r"(?i)(?:favlist\?(?:.*&)?fid=|/(?:medialist/detail|list)/ml)(\d+)")
+                .unwrap();
+        static ref SEASON_REGEX: Regex = Regex::new(
+            r"(?i)space\.bilibili\.com/(\d+)/(?:channel/collectiondetail\?sid=(\d+)|lists/(\d+)\?(?:.*&)?type=season)"
+        )
+        .unwrap();
+        static ref SERIES_REGEX: Regex = Regex::new(
+            r"(?i)space\.bilibili\.com/(\d+)/(?:channel/seriesdetail\?sid=(\d+)|lists/(\d+)\?(?:.*&)?type=series)"
+        )
+        .unwrap();
+        // 只认主页和投稿页，动态、收藏夹等其他子页面不能当成「下载全部投稿」
+        static ref SPACE_REGEX: Regex = Regex::new(
+            r"(?i)space\.bilibili\.com/(\d+)(?:/video|/upload(?:/video)?)?/?(?:[?#]|$)"
+        )
+        .unwrap();
+    }
+    let num =
+        |caps: &regex::Captures, i: usize| -> Option<u64> { caps.get(i)?.as_str().parse().ok() };
+    if let Some(caps) = FAV_REGEX.captures(url) {
+        return Some(BiliList::Favorites(num(&caps, 1)?));
+    }
+    if let Some(caps) = SEASON_REGEX.captures(url) {
+        return Some(BiliList::Season {
+            mid: num(&caps, 1)?,
+            id: num(&caps, 2).or_else(|| num(&caps, 3))?,
+        });
+    }
+    if let Some(caps) = SERIES_REGEX.captures(url) {
+        return Some(BiliList::Series {
+            mid: num(&caps, 1)?,
+            id: num(&caps, 2).or_else(|| num(&caps, 3))?,
+        });
+    }
+    let caps = SPACE_REGEX.captures(url)?;
+    Some(BiliList::Space(num(&caps, 1)?))
+}
+
+/// 翻页获取整个列表，每一项都是一个视频
+pub(crate) async fn get_list(list: BiliList, client: &Client) -> anyhow::Result<Playlist> {
+    // WBI 密钥一天才换一次，整个列表共用
+    let mixin_key = match list {
+        BiliList::Space(_) => get_mixin_key(client).await?,
+        _ => String::new(),
+    };
+    let mut title = String::new();
+    let mut entries = Vec::new();
+    for page in 1.. {
+        let (list_page, has_more) = match list {
+            BiliList::Favorites(id) => get_favorites_page(id, page, client).await?,
+            BiliList::Season { mid, id } => get_season_page(mid, id, page, client).await?,
+            BiliList::Series { mid, id } => get_series_page(mid, id, page, client).await?,
+            BiliList::Space(mid) => get_space_page(mid, page, &mixin_key, client).await?,
+        };
+        if title.is_empty() {
+            title = list_page.title;
+        }
+        let is_empty = list_page.entries.is_empty();
+        entries.extend(list_page.entries);
+        info!(page, count = entries.len(), "已获取列表");
+        if !has_more || is_empty {
+            break;
+        }
+        // 翻页太快容易触发风控
+        tokio::time::sleep(Duration::from_millis(300)).await;
+    }
+    let id = match list {
+        BiliList::Favorites(id) => format!("ml{}", id),
+        BiliList::Season { id, .. } => format!("season{}", id),
+        BiliList::Series { id, .. } => format!("series{}", id),
+        BiliList::Space(mid) => format!("space{}", mid),
+    };
+    Ok(Playlist { id, title, entries })
+}
+
+struct ListPage {
+    title: String,
+    entries: Vec<PlaylistEntry>,
+}
+
+fn video_entry(
+    item: &serde_json::Value,
+    duration: Option<Duration>,
+    time_key: &str,
+) -> Option<PlaylistEntry> {
+    let bvid = item.get("bvid")?.as_str()?;
+    Some(PlaylistEntry {
+        id: bvid.to_string(),
+        input: format!("https://www.bilibili.com/video/{}", bvid),
+        title: item.get("title")?.as_str()?.to_string(),
+        duration,
+        upload_time: item.get(time_key).and_then(|t| t.as_u64()),
+        kind: EntryKind::Video,
+    })
+}
+
+fn seconds(item: &serde_json::Value, key: &str) -> Option<Duration> {
+    item.get(key)
+        .and_then(|d| d.as_u64())
+        .map(Duration::from_secs)
+}
+
+async fn get_json(
+    url: &str,
+    query: &[(&str, String)],
+    client: &Client,
+) -> anyhow::Result<serde_json::Value> {
+    let body: serde_json::Value = client.get(url).query(query).send().await?.json().await?;
+    check_code(&body)?;
+    body.get("data").cloned().context("无法获取列表")
+}
+
+async fn get_favorites_page(
+    media_id: u64,
+    page: u32,
+    client: &Client,
+) -> anyhow::Result<(ListPage, bool)> {
+    let data = get_json(
+        "https://api.bilibili.com/x/v3/fav/resource/list",
+        &[
+            ("media_id", media_id.to_string()),
+            ("pn", page.to_string()),
+            ("ps", "20".to_string()),
+            ("platform", "web".to_string()),
+        ],
+        client,
+    )
+    .await?;
+    let title = data
+        .pointer("/info/title")
+        .and_then(|t| t.as_str())
+        .unwrap_or("收藏夹")
+        .to_string();
+    // 失效的视频 title 为「已失效视频」，attr 不为 0
+    let entries = data
+        .get("medias")
+        .and_then(|m| m.as_array())
+        .into_iter()
+        .flatten()
+        .filter(|m| m.get("attr").and_then(|a| a.as_u64()).unwrap_or(0) == 0)
+        .filter_map(|m| video_entry(m, seconds(m, "duration"), "pubtime"))
+        .collect();
+    let has_more = data
+        .get("has_more")
+        .and_then(|h| h.as_bool())
+        .unwrap_or(false);
+    Ok((ListPage { title, entries }, has_more))
+}
+
+async fn get_season_page(
+    mid: u64,
+    season_id: u64,
+    page: u32,
+    client: &Client,
+) -> anyhow::Result<(ListPage, bool)> {
+    let data = get_json(
+        "https://api.bilibili.com/x/polymer/web-space/seasons_archives_list",
+        &[
+            ("mid", mid.to_string()),
+            ("season_id", season_id.to_string()),
+            ("page_num", page.to_string()),
+            ("page_size", "30".to_string()),
+        ],
+        client,
+    )
+    .await?;
+    let title = data
+        .pointer("/meta/name")
+        .and_then(|t| t.as_str())
+        .unwrap_or("合集")
+        .to_string();
+    archives_page(&data, title, page, 30)
+}
+
+async fn get_series_page(
+    mid: u64,
+    series_id: u64,
+    page: u32,
+    client: &Client,
+) -> anyhow::Result<(ListPage, bool)> {
+    let data = get_json(
+        "https://api.bilibili.com/x/series/archives",
+        &[
+            ("mid", mid.to_string()),
+            ("series_id", series_id.to_string()),
+            ("pn", page.to_string()),
+            ("ps", "30".to_string()),
+        ],
+        client,
+    )
+    .await?;
+    archives_page(&data, format!("系列 {}", series_id), page, 30)
+}
+
+/// 合集和系列的返回结构相同
+fn archives_page(
+    data: &serde_json::Value,
+    title: String,
+    page: u32,
+    page_size: u64,
+) -> anyhow::Result<(ListPage, bool)> {
+    let entries = data
+        .get("archives")
+        .and_then(|a| a.as_array())
+        .context("无法获取列表")?
+        .iter()
+        .filter_map(|a| video_entry(a, seconds(a, "duration"), "pubdate"))
+        .collect();
+    let total = data
+        .pointer("/page/total")
+        .and_then(|t| t.as_u64())
+        .unwrap_or(0);
+    Ok((ListPage { title, entries }, page as u64 * page_size < total))
+}
+
+async fn get_space_page(
+    mid: u64,
+    page: u32,
+    mixin_key: &str,
+    client: &Client,
+) -> anyhow::Result<(ListPage, bool)> {
+    let params = vec![
+        ("mid", mid.to_string()),
+        ("pn", page.to_string()),
+        ("ps", "30".to_string()),
+        ("order", "pubdate".to_string()),
+    ];
+    let data = get_json(
+        "https://api.bilibili.com/x/space/wbi/arc/search",
+        &sign_params(params, mixin_key, unix_now()?),
+        client,
+    )
+    .await?;
+    let entries = data
+        .pointer("/list/vlist")
+        .and_then(|v| v.as_array())
+        .context("无法获取投稿列表")?
+        .iter()
+        .filter_map(|v| {
+            // 投稿列表的时长是「12:34」这样的字符串
+            let duration = v
+                .get("length")
+                .and_then(|l| l.as_str())
+                .and_then(parse_length);
+            video_entry(v, duration, "created")
+        })
+        .collect::<Vec<_>>();
+    let title = data
+        .pointer("/list/vlist/0/author")
+        .and_then(|a| a.as_str())
+        .map(|author| format!("{} 的投稿", author))
+        .unwrap_or_else(|| format!("UP 主 {} 的投稿", mid));
+    let total = data
+        .pointer("/page/count")
+        .and_then(|t| t.as_u64())
+        .unwrap_or(0);
+    Ok((ListPage { title, entries }, page as u64 * 30 < total))
+}
+
+fn parse_length(length: &str) -> Option<Duration> {
+    length
+        .split(':')
+        .try_fold(0, |secs, part| Some(secs * 60 + part.parse::<u64>().ok()?))
+        .map(Duration::from_secs)
+}
+
+/// 从导航接口的 wbi_img 算出 WBI 签名用的 mixin key
+async fn get_mixin_key(client: &Client) -> anyhow::Result<String> {
+    let body: serde_json::Value = client
+        .get("https://api.bilibili.com/x/web-interface/nav")
+        .send()
+        .await?
+        .json()
+        .await?;
+    // 未登录时 code 为 -101，但仍然会返回 wbi_img
+    let key = |name: &str| {
+        body.pointer(&format!("/data/wbi_img/{}", name))
+            .and_then(|u| u.as_str())
+            .and_then(|u| u.rsplit('/').next())
+            .and_then(|f| f.split('.').next())
+            .map(String::from)
+            .context("无法获取 WBI 密钥")
+    };
+    Ok(mixin_key(&key("img_url")?, &key("sub_url")?))
+}
+
+fn unix_now() -> anyhow::Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+fn mixin_key(img_key: &str, sub_key: &str) -> String {
+    const MIXIN_KEY_ENC_TAB: [usize; 64] = [
+        46, 47, 18, 2, 53, 8, 23, 32, 15, 50, 10, 31, 58, 3, 45, 35, 27, 43, 5, 49, 33, 9, 42, 19,
+        29, 28, 14, 39, 12, 38, 41, 13, 37, 48, 7, 16, 24, 55, 40, 61, 26, 17, 0, 1, 60, 51, 30, 4,
+        22, 25, 54, 21, 56, 59, 6, 63, 57, 62, 11, 36, 20, 34, 44, 52,
+    ];
+    let raw: Vec<char> = format!("{}{}", img_key, sub_key).chars().collect();
+    MIXIN_KEY_ENC_TAB
+        .iter()
+        .filter_map(|&i| raw.get(i))
+        .take(32)
+        .collect()
+}
+
+/// 给请求参数加上 WBI 签名，算法见 bilibili-API-collect
+fn sign_params(
+    mut params: Vec<(&'static str, String)>,
+    mixin_key: &str,
+    wts: u64,
+) -> Vec<(&'static str, String)> {
+    // 和 JS 的 encodeURIComponent 一致
+    const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+        .remove(b'-')
+        .remove(b'_')
+        .remove(b'.')
+        .remove(b'~');
+    params.push(("wts", wts.to_string()));
+    params.sort_by(|a, b| a.0.cmp(b.0));
+    for (_, value) in params.iter_mut() {
+        value.retain(|c| !"!'()*".contains(c));
+    }
+    let query = params
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, utf8_percent_encode(v, COMPONENT)))
+        .collect::<Vec<_>>()
+        .join("&");
+    let w_rid = format!("{:x}", Md5::digest(format!("{}{}", query, mixin_key)));
+    params.push(("w_rid", w_rid));
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_list_id() {
+        assert_eq!(
+            extract_list_id("https://space.bilibili.com/123/favlist?fid=456&ftype=create"),
+            Some(BiliList::Favorites(456))
+        );
+        assert_eq!(
+            extract_list_id("https://www.bilibili.com/medialist/detail/ml456"),
+            Some(BiliList::Favorites(456))
+        );
+        assert_eq!(
+            extract_list_id("https://space.bilibili.com/123/channel/collectiondetail?sid=789"),
+            Some(BiliList::Season { mid: 123, id: 789 })
+        );
+        assert_eq!(
+            extract_list_id("https://space.bilibili.com/123/lists/789?type=series"),
+            Some(BiliList::Series { mid: 123, id: 789 })
+        );
+        assert_eq!(
+            extract_list_id("https://space.bilibili.com/123/upload/video"),
+            Some(BiliList::Space(123))
+        );
+        assert_eq!(
+            extract_list_id("https://www.bilibili.com/video/BV17x411w7KC"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_space() {
+        for url in [
+            "https://space.bilibili.com/2",
+            "https://space.bilibili.com/2/",
+            "https://space.bilibili.com/2?spm_id_from=333.1007",
+            "https://space.bilibili.com/2/video",
+            "https://space.bilibili.com/2/upload/video",
+        ] {
+            assert_eq!(extract_list_id(url), Some(BiliList::Space(2)), "{url}");
+        }
+        for url in [
+            "https://space.bilibili.com/2/favlist",
+            "https://space.bilibili.com/2/dynamic",
+            "https://space.bilibili.com/2/fans/follow",
+        ] {
+            assert_eq!(extract_list_id(url), None, "{url}");
+        }
+    }
+
+    #[test]
+    fn test_wbi_sign() {
+        let mixin_key = mixin_key(
+            "7cd084941338484aae1ad9425b84077c",
+            "4932caff0ff746eab6f01bf08b70ac45",
+        );
+        assert_eq!(mixin_key, "ea1db124af3c7062474693fa704f4ff8");
+        let params = vec![
+            ("foo", "114".to_string()),
+            ("bar", "514".to_string()),
+            ("zab", "1919810".to_string()),
+        ];
+        let signed = sign_params(params, &mixin_key, 1702204169);
+        assert_eq!(
+            signed.last(),
+            Some(&("w_rid", "8f6f2b5b3d485fe1886cec6a0be8c5d4".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_length() {
+        assert_eq!(parse_length("12:34"), Some(Duration::from_secs(754)));
+        assert_eq!(parse_length("1:00:00"), Some(Duration::from_secs(3600)));
+        assert_eq!(parse_length("abc"), None);
+    }
+}
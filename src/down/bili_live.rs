@@ -0,0 +1,241 @@
+use crate::{
+    Extracted, Extractor, MediaInfo, Metadata, Stream, StreamKind, Thumbnail,
+    bilibili::{build_client, check_code, get_headers},
+    find_url, traced,
+};
+use anyhow::{Context as _, bail};
+use async_trait::async_trait;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::{Client, Url};
+use tracing::{info_span, warn};
+
+#[derive(Default)]
+pub struct BiliLive {}
+
+impl BiliLive {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[async_trait]
+impl Extractor for BiliLive {
+    fn name(&self) -> &'static str {
+        "bilibili 直播"
+    }
+
+    fn matches(&self, input: &str) -> bool {
+        extract_room_id(input).is_some()
+    }
+
+    async fn extract(&self, input: &str) -> anyhow::Result<Option<Extracted>> {
+        let input = find_url(input).unwrap_or(input);
+        let room_id = extract_room_id(input).context("无效的直播间地址")?;
+        let client = build_client("https://live.bilibili.com/").context("无法创建客户端")?;
+        let room = traced(
+            info_span!("获取直播间", room_id),
+            get_room(room_id, &client),
+        )
+        .await?;
+        if !room.is_live {
+            bail!("直播间 {} 未开播", room.room_id);
+        }
+        let uploader = get_uploader(room.uid, &client)
+            .await
+            .inspect_err(|e| warn!(err = ?e, "获取主播信息失败"))
+            .ok();
+        let title = match &uploader {
+            Some(name) => format!("{} {}", name, room.title),
+            None => room.title,
+        };
+        let room_url = Url::parse(&format!("https://live.bilibili.com/{}", room.room_id))?;
+        Ok(Some(Extracted::Media(MediaInfo {
+            id: format!("live{}", room.room_id),
+            title,
+            streams: vec![Stream::new(StreamKind::Live, room_url, "flv")],
+            formats: Vec::new(),
+            thumbnails: room
+                .cover
+                .map(|url| Thumbnail { url })
+                .into_iter()
+                .collect(),
+            subtitles: Vec::new(),
+            metadata: Metadata {
+                uploader,
+                description: room.description,
+                upload_time: None,
+            },
+            headers: get_headers("https://live.bilibili.com/"),
+            part_of: None,
+            input: String::new(),
+        })))
+    }
+}
+
+/// 直播间号，短号和长号都可以
+pub(crate) fn extract_room_id(url: &str) -> Option<u64> {
+    lazy_static! {
+        static ref ROOM_REGEX: Regex =
+            Regex::new(r"(?i)\blive\.bilibili\.com/(?:h5/|blanc/)?(\d+)").unwrap();
+    }
+    ROOM_REGEX.captures(url)?.get(1)?.as_str().parse().ok()
+}
+
+struct Room {
+    /// 真实房间号，短号会被换成长号
+    room_id: u64,
+    uid: u64,
+    title: String,
+    description: Option<String>,
+    cover: Option<Url>,
+    is_live: bool,
+}
+
+async fn get_room(room_id: u64, client: &Client) -> anyhow::Result<Room> {
+    let body: serde_json::Value = client
+        .get("https://api.live.bilibili.com/room/v1/Room/get_info")
+        .query(&[("room_id", room_id)])
+        .send()
+        .await?
+        .json()
+        .await?;
+    check_code(&body)?;
+    let data = body.get("data").context("无法获取直播间信息")?;
+    Ok(Room {
+        room_id: data
+            .get("room_id")
+            .and_then(|id| id.as_u64())
+            .unwrap_or(room_id),
+        uid: data.get("uid").and_then(|u| u.as_u64()).unwrap_or(0),
+        title: data
+            .get("title")
+            .and_then(|t| t.as_str())
+            .context("无法获取直播间标题")?
+            .to_string(),
+        description: data
+            .get("description")
+            .and_then(|d| d.as_str())
+            .filter(|d| !d.is_empty())
+            .map(String::from),
+        cover: data
+            .get("user_cover")
+            .and_then(|c| c.as_str())
+            .and_then(|c| Url::parse(c).ok()),
+        // 0 未开播，1 直播中，2 轮播
+        is_live: data.get("live_status").and_then(|s| s.as_u64()) == Some(1),
+    })
+}
+
+async fn get_uploader(uid: u64, client: &Client) -> anyhow::Result<String> {
+    let body: serde_json::Value = client
+        .get("https://api.live.bilibili.com/live_user/v1/Master/info")
+        .query(&[("uid", uid)])
+        .send()
+        .await?
+        .json()
+        .await?;
+    check_code(&body)?;
+    body.pointer("/data/info/uname")
+        .and_then(|n| n.as_str())
+        .map(String::from)
+        .context("无法获取主播名")
+}
+
+/// 录制用的真实流地址，有效期有限，每次重连都要重新获取
+#[derive(Debug, Clone)]
+pub struct LiveStream {
+    pub url: Url,
+    /// flv 或 ts，决定录制文件的封装格式
+    pub ext: &'static str,
+}
+
+/// 获取直播流，优先 http-flv，没有时退回 HLS；已下播时返回 `None`
+pub(crate) async fn get_live_stream(
+    room_id: u64,
+    client: &Client,
+) -> anyhow::Result<Option<LiveStream>> {
+    let body: serde_json::Value = client
+        .get("https://api.live.bilibili.com/xlive/web-room/v2/index/getRoomPlayInfo")
+        .query(&[
+            ("room_id", room_id.to_string().as_str()),
+            ("protocol", "0,1"),
+            ("format", "0,1,2"),
+            ("codec", "0,1"),
+            ("qn", "10000"),
+            ("platform", "web"),
+            ("ptype", "8"),
+        ])
+        .send()
+        .await?
+        .json()
+        .await?;
+    check_code(&body)?;
+    let data = body.get("data").context("无法获取直播流")?;
+    if data.get("live_status").and_then(|s| s.as_u64()) != Some(1) {
+        return Ok(None);
+    }
+    let streams = data
+        .pointer("/playurl_info/playurl/stream")
+        .and_then(|s| s.as_array())
+        .context("无法获取直播流")?;
+    // avc 兼容性最好，hevc 只在没有 avc 时使用
+    let candidates = [
+        ("http_stream", "flv", "flv"),
+        ("http_hls", "ts", "ts"),
+        ("http_hls", "fmp4", "ts"),
+    ];
+    for (protocol, format, ext) in candidates {
+        let codecs = streams
+            .iter()
+            .filter(|s| s.get("protocol_name").and_then(|p| p.as_str()) == Some(protocol))
+            .filter_map(|s| s.get("format")?.as_array())
+            .flatten()
+            .filter(|f| f.get("format_name").and_then(|n| n.as_str()) == Some(format))
+            .filter_map(|f| f.get("codec")?.as_array())
+            .flatten()
+            .collect::<Vec<_>>();
+        let codec = codecs
+            .iter()
+            .find(|c| c.get("codec_name").and_then(|n| n.as_str()) == Some("avc"))
+            .or_else(|| codecs.first());
+        if let Some(url) = codec.and_then(|c| codec_url(c)) {
+            return Ok(Some(LiveStream { url, ext }));
+        }
+    }
+    bail!("没有可以录制的直播流")
+}
+
+fn codec_url(codec: &serde_json::Value) -> Option<Url> {
+    let base_url = codec.get("base_url")?.as_str()?;
+    let info = codec.get("url_info")?.as_array()?.first()?;
+    let host = info.get("host")?.as_str()?;
+    let extra = info.get("extra")?.as_str()?;
+    Url::parse(&format!("{}{}{}", host, base_url, extra)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_room_id() {
+        assert_eq!(extract_room_id("https://live.bilibili.com/6"), Some(6));
+        assert_eq!(
+            extract_room_id("https://live.bilibili.com/h5/21452505?broadcast_type=0"),
+            Some(21452505)
+        );
+        assert_eq!(
+            extract_room_id("https://live.bilibili.com/blanc/1029?liteVersion=true"),
+            Some(1029)
+        );
+        assert_eq!(
+            extract_room_id("https://live.bilibili.com/p/eden/area-tags"),
+            None
+        );
+        assert_eq!(
+            extract_room_id("https://www.bilibili.com/video/BV17x411w7KC"),
+            None
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use crate::{FFMPEG_DIR, bilibili::check_code};
+use anyhow::{Context as _, bail};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use reqwest::{Client, Url, header};
+use std::path::PathBuf;
+use tokio::{fs, io::AsyncWriteExt};
+
+/// 扫码登录成功后 B 站会下发的 cookie
+const COOKIE_NAMES: &[&str] = &[
+    "SESSDATA",
+    "bili_jct",
+    "DedeUserID",
+    "DedeUserID__ckMd5",
+    "sid",
+];
+
+lazy_static! {
+    static ref BILI_SESSION: RwLock<Option<BiliSession>> = RwLock::new(BiliSession::load());
+}
+
+/// B 站登录信息，保存在 `~/.unidown/bilibili.json`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BiliSession {
+    pub cookies: Vec<(String, String)>,
+}
+
+impl BiliSession {
+    /// 解析浏览器里复制的 `SESSDATA=xxx; bili_jct=yyy`，只有一个值时当作 SESSDATA
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let s = s.trim();
+        let cookies: Vec<_> = if s.contains('=') {
+            s.split(';')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+                .filter(|(k, v)| !k.is_empty() && !v.is_empty())
+                .collect()
+        } else if s.is_empty() {
+            Vec::new()
+        } else {
+            vec![("SESSDATA".to_string(), s.to_string())]
+        };
+        let session = Self { cookies };
+        if session.get("SESSDATA").is_none() {
+            bail!("cookie 中没有 SESSDATA");
+        }
+        Ok(session)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.cookies
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// 拼成 Cookie 请求头
+    pub fn cookie_header(&self) -> String {
+        self.cookies
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
+    fn path() -> PathBuf {
+        FFMPEG_DIR.join("bilibili.json")
+    }
+
+    fn load() -> Option<Self> {
+        let text = std::fs::read_to_string(Self::path()).ok()?;
+        let value: serde_json::Value = serde_json::from_str(&text).ok()?;
+        let cookies = value
+            .get("cookies")?
+            .as_object()?
+            .iter()
+            .filter_map(|(k, v)| Some((k.clone(), v.as_str()?.to_string())))
+            .collect();
+        Some(Self { cookies })
+    }
+
+    async fn save(&self) -> anyhow::Result<()> {
+        let cookies: serde_json::Map<_, _> = self
+            .cookies
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone().into()))
+            .collect();
+        let value = serde_json::json!({ "cookies": cookies });
+        fs::create_dir_all(FFMPEG_DIR.as_path()).await?;
+        // cookie 等同于账号密码，只允许自己读写
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(Self::path()).await?;
+        // 旧版本创建的文件权限可能更宽，重新收紧
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))
+                .await?;
+        }
+        file.write_all(value.to_string().as_bytes()).await?;
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// 当前的登录信息，没有登录时为 `None`
+pub fn bili_session() -> Option<BiliSession> {
+    BILI_SESSION.read().clone()
+}
+
+/// 保存或清除登录信息，之后创建的客户端都会带上新的 cookie
+pub async fn set_bili_session(session: Option<BiliSession>) -> anyhow::Result<()> {
+    match &session {
+        Some(session) => session.save().await.context("无法保存登录信息")?,
+        None => {
+            let _ = fs::remove_file(BiliSession::path()).await;
+        }
+    }
+    *BILI_SESSION.write() = session;
+    Ok(())
+}
+
+/// 检查 cookie 是否有效，返回用户名
+pub async fn bili_user_name(session: &BiliSession) -> anyhow::Result<String> {
+    let body: serde_json::Value = Client::new()
+        .get("https://api.bilibili.com/x/web-interface/nav")
+        .header(header::COOKIE, session.cookie_header())
+        .send()
+        .await?
+        .json()
+        .await?;
+    let data = body.get("data").context("无法获取用户信息")?;
+    if !data
+        .get("isLogin")
+        .and_then(|l| l.as_bool())
+        .unwrap_or(false)
+    {
+        bail!("登录已失效");
+    }
+    let name = data
+        .get("uname")
+        .and_then(|n| n.as_str())
+        .context("无法获取用户名")?;
+    Ok(name.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QrStatus {
+    /// 还没扫码
+    Waiting,
+    /// 已扫码，等待在手机上确认
+    Scanned,
+    Expired,
+    Confirmed(BiliSession),
+}
+
+/// 扫码登录，二维码内容为 `url`
+#[derive(Debug, Clone)]
+pub struct QrLogin {
+    pub url: String,
+    key: String,
+}
+
+impl QrLogin {
+    pub async fn generate(client: &Client) -> anyhow::Result<Self> {
+        let body: serde_json::Value = client
+            .get("https://passport.bilibili.com/x/passport-login/web/qrcode/generate")
+            .send()
+            .await?
+            .json()
+            .await?;
+        check_code(&body)?;
+        let data = body.get("data").context("无法获取二维码")?;
+        let get = |name: &str| {
+            data.get(name)
+                .and_then(|v| v.as_str())
+                .map(String::from)
+                .context("无法获取二维码")
+        };
+        Ok(Self {
+            url: get("url")?,
+            key: get("qrcode_key")?,
+        })
+    }
+
+    pub async fn poll(&self, client: &Client) -> anyhow::Result<QrStatus> {
+        let resp = client
+            .get("https://passport.bilibili.com/x/passport-login/web/qrcode/poll")
+            .query(&[("qrcode_key", &self.key)])
+            .send()
+            .await?;
+        let mut cookies: Vec<_> = resp
+            .cookies()
+            .map(|c| (c.name().to_string(), c.value().to_string()))
+            .collect();
+        let body: serde_json::Value = resp.json().await?;
+        check_code(&body)?;
+        let data = body.get("data").context("无法获取扫码状态")?;
+        match data.get("code").and_then(|c| c.as_i64()) {
+            Some(0) => {}
+            Some(86101) => return Ok(QrStatus::Waiting),
+            Some(86090) => return Ok(QrStatus::Scanned),
+            Some(86038) => return Ok(QrStatus::Expired),
+            code => bail!("扫码登录失败: {:?}", code),
+        }
+        // 没有拿到 Set-Cookie 时从跳转链接的参数里取
+        if !cookies.iter().any(|(k, _)| k == "SESSDATA")
+            && let Some(url) = data
+                .get("url")
+                .and_then(|u| u.as_str())
+                .and_then(|u| Url::parse(u).ok())
+        {
+            cookies = url
+                .query_pairs()
+                .filter(|(k, _)| COOKIE_NAMES.contains(&k.as_ref()))
+                .map(|(k, v)| (k.into_owned(), v.into_owned()))
+                .collect();
+        }
+        cookies.retain(|(k, _)| COOKIE_NAMES.contains(&k.as_str()));
+        if !cookies.iter().any(|(k, _)| k == "SESSDATA") {
+            bail!("扫码成功但没有拿到 SESSDATA");
+        }
+        Ok(QrStatus::Confirmed(BiliSession { cookies }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_session() {
+        let session = BiliSession::parse("SESSDATA=abc%2C123; bili_jct=def ;").unwrap();
+        assert_eq!(session.get("SESSDATA"), Some("abc%2C123"));
+        assert_eq!(session.get("bili_jct"), Some("def"));
+        assert_eq!(session.cookie_header(), "SESSDATA=abc%2C123; bili_jct=def");
+        assert_eq!(
+            BiliSession::parse(" abc ").unwrap().cookie_header(),
+            "SESSDATA=abc"
+        );
+        assert!(BiliSession::parse("bili_jct=def").is_err());
+        assert!(BiliSession::parse("").is_err());
+    }
+}
@@ -1,42 +1,36 @@
 use crate::{
-    Parser, TOKIO_RT,
-    abort::AbortOnDrop,
-    fd::{ProgressState, download_segment},
-    ffmpeg::ffmpeg,
-    sanitize::{self, sanitize},
+    EntryKind, Extracted, Extractor, Format, FormatPolicy, MediaInfo, Metadata, PartOf, Playlist,
+    PlaylistEntry, Stream, StreamKind, Subtitle, Thumbnail,
+    bili_list::{extract_list_id, get_list},
+    bili_live::extract_room_id,
+    bili_login::bili_session,
+    find_url, traced,
 };
 use anyhow::{Context as _, anyhow, bail};
-use fast_down::utils::gen_unique_path;
-use gpui::{
-    AnyView, App, AppContext, Context, IntoElement, ParentElement, Render, SharedString, Styled,
-    Task, Timer, Window, div,
-};
-use gpui_component::{StyledExt, h_flex, progress::Progress, v_flex};
+use async_trait::async_trait;
 use lazy_static::lazy_static;
 use regex::Regex;
 use reqwest::{
     Client, ClientBuilder, Url,
-    header::{self, HeaderMap},
-};
-use std::{
-    env,
-    sync::{
-        Arc,
-        atomic::{AtomicBool, AtomicU64, Ordering},
-    },
-    time::Duration,
+    header::{self, HeaderMap, HeaderValue},
 };
-use tracing::{Instrument, info_span};
+use std::{collections::HashMap, time::Duration};
+use tracing::{info_span, warn};
 
-fn get_headers(referer: &str) -> HeaderMap {
-    HeaderMap::from_iter( [
-        (header::REFERER, referer.parse().unwrap()),
+/// 登录后会带上 cookie，API 和 CDN 请求都用这组请求头
+pub(crate) fn get_headers(referer: &str) -> HeaderMap {
+    let mut headers = HeaderMap::from_iter( [
+        (header::REFERER, referer.parse().unwrap_or(HeaderValue::from_static("https://www.bilibili.com/"))),
         (header::ORIGIN, "https://www.bilibili.com".parse().unwrap()),
         (header::USER_AGENT, "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36".parse().unwrap()),
-    ])
+    ]);
+    if let Some(cookie) = bili_session().and_then(|s| s.cookie_header().parse().ok()) {
+        headers.insert(header::COOKIE, cookie);
+    }
+    headers
 }
 
-fn build_client(url: &str) -> anyhow::Result<Client> {
+pub(crate) fn build_client(url: &str) -> anyhow::Result<Client> {
     let client = ClientBuilder::new()
         .default_headers(get_headers(url))
         .build()?;
@@ -52,197 +46,93 @@ impl BiliDown {
     }
 }
 
-impl Parser for BiliDown {
-    fn parse(
-        &self,
-        input: &str,
-        _: &mut Window,
-        cx: &mut App,
-    ) -> Task<anyhow::Result<Option<AnyView>>> {
-        let header = Arc::new(get_headers(input));
-        let bvid = extract_bvid(input).map(|s| s.to_string());
-        let client = build_client(input);
-        cx.spawn(async move |cx| {
-            let client = client.context("无法创建客户端")?;
-            let bvid = bvid.context("无效的 BV 号")?;
-            let output_dir = dirs::desktop_dir()
-                .or_else(dirs::download_dir)
-                .or_else(|| env::current_dir().ok())
-                .context("找不到下载目录")?;
-            let client_cl = client.clone();
-            let (title, (video_url, audio_url)) = TOKIO_RT
-                .spawn(async move {
-                    tokio::try_join!(get_title(&bvid, &client_cl), get_info(&bvid, &client_cl))
-                })
-                .await??;
-            let video_state = ProgressState::new();
-            let audio_state = ProgressState::new();
-            let frame = Arc::new(AtomicU64::new(0));
-            let merge_speed = Arc::new(AtomicU64::new(0));
-            let is_finished = Arc::new(AtomicBool::new(false));
-            let task_handle = {
-                let is_finished = is_finished.clone();
-                let (video_state, audio_state, frame, merge_speed) = (
-                    video_state.clone(),
-                    audio_state.clone(),
-                    frame.clone(),
-                    merge_speed.clone(),
-                );
-                let (title, client) = (title.clone(), client.clone());
-                TOKIO_RT.spawn(async move {
-                    let _guard = scopeguard::guard((), |_| {
-                        is_finished.store(true, Ordering::Relaxed);
-                    });
-                    let video_path = download_segment(
-                        video_url,
-                        &title,
-                        "mp4",
-                        &output_dir,
-                        &client,
-                        &video_state,
-                        16,
-                        header.clone(),
-                    )
-                    .await?;
-                    let audio_path = download_segment(
-                        audio_url,
-                        &title,
-                        "mp3",
-                        &output_dir,
-                        &client,
-                        &audio_state,
-                        16,
-                        header,
-                    )
-                    .await?;
-                    let merge_filename = sanitize(format!(
-                        "{}-合并.mp4",
-                        sanitize::truncate_to_bytes(&title, 230)
-                    ));
-                    let merge_path = gen_unique_path(soft_canonicalize::soft_canonicalize(
-                        output_dir.join(merge_filename),
-                    )?)
-                    .await?;
-                    let span = info_span!("合并音视频");
-                    ffmpeg(
-                        [
-                            "-i",
-                            &video_path.to_string_lossy(),
-                            "-i",
-                            &audio_path.to_string_lossy(),
-                            "-c",
-                            "copy",
-                            "-y",
-                            &merge_path.to_string_lossy(),
-                        ],
-                        move |info| {
-                            frame.store(info.frame, Ordering::Relaxed);
-                            merge_speed.store((info.speed * 1000.) as u64, Ordering::Relaxed);
-                        },
-                    )
-                    .instrument(span)
-                    .await?;
-                    let _ = tokio::fs::remove_file(&audio_path).await;
-                    let _ = tokio::fs::remove_file(&video_path).await;
-                    Ok(())
-                })
-            };
-            let view = cx.new(|cx| {
-                let finished_flag = is_finished.clone();
-                cx.spawn(async move |view, cx| {
-                    loop {
-                        if finished_flag.load(Ordering::Relaxed) {
-                            break;
-                        }
-                        Timer::after(Duration::from_millis(100)).await;
-                        if view.update(cx, |_, cx| cx.notify()).is_err() {
-                            break;
-                        }
-                    }
-                })
-                .detach();
-                BiliView {
-                    title,
-                    video_state,
-                    audio_state,
-                    frame,
-                    merge_speed,
-                    is_finished,
-                    _guard: AbortOnDrop(task_handle),
-                }
-            })?;
-            Ok(Some(view.into()))
-        })
+#[async_trait]
+impl Extractor for BiliDown {
+    fn name(&self) -> &'static str {
+        "bilibili"
     }
-}
-
-pub struct BiliView {
-    title: SharedString,
-    video_state: Arc<ProgressState>,
-    audio_state: Arc<ProgressState>,
-    frame: Arc<AtomicU64>,
-    merge_speed: Arc<AtomicU64>,
-    is_finished: Arc<AtomicBool>,
-    _guard: AbortOnDrop<anyhow::Result<()>>,
-}
 
-impl Render for BiliView {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        let (video_text, video_pct) = self.video_state.display();
-        let (audio_text, audio_pct) = self.audio_state.display();
-
-        let frame = self.frame.load(Ordering::Relaxed);
-        let merge_speed = self.merge_speed.load(Ordering::Relaxed) as f64 / 1000.;
-        let merge_text = format!("frame: {} | speed: {:.2}x", frame, merge_speed);
-        let done = self.is_finished.load(Ordering::Relaxed);
-
-        v_flex()
-            .p_4()
-            .gap_4()
-            .child(div().child(self.title.clone()).text_2xl().font_bold())
-            .child(self.render_row("视频", video_text, video_pct))
-            .child(self.render_row("音频", audio_text, audio_pct))
-            .child(
-                h_flex()
-                    .justify_between()
-                    .child(div().child("合并处理").text_lg().font_bold())
-                    .child(merge_text),
-            )
-            .child(
-                div()
-                    .child(if done {
-                        "全部完成，请检查桌面"
-                    } else {
-                        "下载还未完成，请耐心等待，点解析按钮可以打断下载并重试"
-                    })
-                    .text_2xl()
-                    .font_bold(),
+    fn matches(&self, input: &str) -> bool {
+        lazy_static! {
+            // av 号只认裸 id，避免把别的链接里的 codec=av1 之类当成 av 号
+            static ref BILI_REGEX: Regex = Regex::new(
+                r"(?i)\b(bilibili\.com|b23\.tv|bili2233\.cn)\b|\bBV\w{10}\b|^\s*av\d+\s*$"
             )
+            .unwrap();
+        }
+        // 直播间交给 BiliLive
+        BILI_REGEX.is_match(input) && extract_room_id(input).is_none()
     }
-}
 
-impl BiliView {
-    fn render_row(&self, label: &str, text: String, pct: f32) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .child(
-                h_flex()
-                    .justify_between()
-                    .child(div().child(label.to_string()).text_lg().font_bold())
-                    .child(text),
+    async fn extract(&self, input: &str) -> anyhow::Result<Option<Extracted>> {
+        let input = match find_url(input) {
+            Some(url) if is_short_url(url) => resolve_short_url(url).await?,
+            Some(url) => url.to_string(),
+            None => input.to_string(),
+        };
+        let input = input.as_str();
+        let client = build_client(input).context("无法创建客户端")?;
+        if let Some(id) = extract_bangumi_id(input) {
+            let span = info_span!("解析番剧", ?id);
+            return traced(span, extract_bangumi(id, input, &client))
+                .await
+                .map(Some);
+        }
+        if let Some(list) = extract_list_id(input) {
+            let playlist = traced(info_span!("获取列表", ?list), get_list(list, &client)).await?;
+            return Ok(Some(Extracted::Playlist(playlist)));
+        }
+        let bvid = extract_bvid(input).context("无效的 BV 号或 av 号")?;
+        let bvid = bvid.as_str();
+        let view = traced(info_span!("获取视频信息", bvid), get_view(bvid, &client)).await?;
+        let page = match extract_page(input) {
+            Some(p) => view
+                .pages
+                .iter()
+                .find(|page| page.page == p)
+                .with_context(|| format!("视频没有 P{p}"))?,
+            // 多 P 视频没有指定 p 时交给用户挑选
+            None if view.pages.len() > 1 => {
+                return Ok(Some(Extracted::Playlist(view.playlist(bvid))));
+            }
+            None => view.pages.first().context("无法获取视频列表")?,
+        };
+        let span = info_span!("获取播放地址", bvid, cid = page.cid);
+        let formats = traced(span, get_formats(bvid, page.cid, &client)).await?;
+        let mut subtitles = get_subtitles(bvid, page.cid, &client).await;
+        subtitles.push(danmaku(page.cid));
+        let (id, title, part_of) = if view.pages.len() > 1 {
+            (
+                page_id(bvid, page.page),
+                format!("{} P{} {}", view.title, page.page, page.part),
+                Some(view.part_of(bvid)),
             )
-            .child(Progress::new().value(pct))
+        } else {
+            (bvid.to_string(), view.title.clone(), None)
+        };
+        let mut info = MediaInfo {
+            id,
+            title,
+            streams: Vec::new(),
+            formats,
+            thumbnails: view
+                .pic
+                .clone()
+                .map(|url| Thumbnail { url })
+                .into_iter()
+                .collect(),
+            subtitles,
+            metadata: view.metadata.clone(),
+            headers: get_headers(input),
+            part_of,
+            input: String::new(),
+        };
+        info.apply_policy(&FormatPolicy::BEST);
+        Ok(Some(Extracted::Media(info)))
     }
 }
 
-async fn get_cid(bvid: &str, client: &Client) -> anyhow::Result<u64> {
-    let body: serde_json::Value = client
-        .get("https://api.bilibili.com/x/player/pagelist")
-        .query(&[("bvid", bvid)])
-        .send()
-        .await?
-        .json()
-        .await?;
+pub(crate) fn check_code(body: &serde_json::Value) -> anyhow::Result<()> {
     let code = body
         .get("code")
         .and_then(|c| c.as_i64())
@@ -254,18 +144,55 @@ async fn get_cid(bvid: &str, client: &Client) -> anyhow::Result<u64> {
             .unwrap_or("未知错误");
         bail!("bilibili API 错误: code: {}, message: {}", code, msg);
     }
-    let first_page = body
-        .get("data")
-        .and_then(|data| data.get(0))
-        .context("无法获取视频列表")?;
-    let cid = first_page
-        .get("cid")
-        .and_then(|v| v.as_u64())
-        .context("无法获取 cid")?;
-    Ok(cid)
+    Ok(())
 }
 
-async fn get_title(bvid: &str, client: &Client) -> anyhow::Result<SharedString> {
+struct VideoView {
+    title: String,
+    pic: Option<Url>,
+    metadata: Metadata,
+    pages: Vec<Page>,
+}
+
+/// 分 P 信息
+struct Page {
+    cid: u64,
+    page: u32,
+    part: String,
+    duration: u64,
+}
+
+impl VideoView {
+    /// 收藏夹、合集里的项只有 bvid，分 P 全部下载完才算下载了这一项
+    fn part_of(&self, bvid: &str) -> PartOf {
+        PartOf {
+            id: bvid.to_string(),
+            parts: self.pages.iter().map(|p| page_id(bvid, p.page)).collect(),
+        }
+    }
+
+    fn playlist(&self, bvid: &str) -> Playlist {
+        let entries = self
+            .pages
+            .iter()
+            .map(|page| PlaylistEntry {
+                id: page_id(bvid, page.page),
+                input: format!("https://www.bilibili.com/video/{}?p={}", bvid, page.page),
+                title: format!("P{} {}", page.page, page.part),
+                duration: Some(Duration::from_secs(page.duration)),
+                upload_time: self.metadata.upload_time,
+                kind: EntryKind::Video,
+            })
+            .collect();
+        Playlist {
+            id: bvid.to_string(),
+            title: self.title.clone(),
+            entries,
+        }
+    }
+}
+
+async fn get_view(bvid: &str, client: &Client) -> anyhow::Result<VideoView> {
     let body: serde_json::Value = client
         .get("https://api.bilibili.com/x/web-interface/view")
         .query(&[("bvid", bvid)])
@@ -273,73 +200,619 @@ async fn get_title(bvid: &str, client: &Client) -> anyhow::Result<SharedString>
         .await?
         .json()
         .await?;
-    let code = body
-        .get("code")
-        .and_then(|c| c.as_i64())
-        .ok_or_else(|| anyhow!("bilibili API 错误: 没有 code"))?;
-    if code != 0 {
-        let msg = body
-            .get("message")
-            .and_then(|m| m.as_str())
-            .unwrap_or("未知错误");
-        bail!("bilibili API 错误: code: {}, message: {}", code, msg);
-    }
-    let title = body
-        .get("data")
-        .and_then(|data| data.get("title"))
+    check_code(&body)?;
+    let data = body.get("data").context("无法获取视频信息")?;
+    let title = data
+        .get("title")
         .and_then(|t| t.as_str())
-        .context("无法获取标题")?;
-    Ok(SharedString::new(title))
+        .context("无法获取标题")?
+        .to_string();
+    let pic = data
+        .get("pic")
+        .and_then(|p| p.as_str())
+        .and_then(|p| p.parse().ok());
+    let pages = data
+        .get("pages")
+        .and_then(|p| p.as_array())
+        .context("无法获取视频列表")?
+        .iter()
+        .map(|page| {
+            Some(Page {
+                cid: page.get("cid")?.as_u64()?,
+                page: page.get("page")?.as_u64()? as u32,
+                part: page.get("part")?.as_str()?.to_string(),
+                duration: page.get("duration").and_then(|d| d.as_u64()).unwrap_or(0),
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .context("无法解析分 P 信息")?;
+    let metadata = Metadata {
+        uploader: data
+            .pointer("/owner/name")
+            .and_then(|n| n.as_str())
+            .map(String::from),
+        description: data
+            .get("desc")
+            .and_then(|d| d.as_str())
+            .filter(|d| !d.is_empty())
+            .map(String::from),
+        upload_time: data.get("pubdate").and_then(|p| p.as_u64()),
+    };
+    Ok(VideoView {
+        title,
+        pic,
+        metadata,
+        pages,
+    })
+}
+
+fn is_short_url(url: &str) -> bool {
+    lazy_static! {
+        static ref SHORT_URL_REGEX: Regex =
+            Regex::new(r"(?i)^https?://(b23\.tv|bili2233\.cn)/").unwrap();
+    }
+    SHORT_URL_REGEX.is_match(url)
+}
+
+/// 跟随 b23.tv 短链接的跳转，返回最终的链接
+async fn resolve_short_url(url: &str) -> anyhow::Result<String> {
+    let resp = Client::builder()
+        .default_headers(get_headers("https://www.bilibili.com/"))
+        .build()?
+        .get(url)
+        .send()
+        .await
+        .context("无法打开短链接")?;
+    let resolved = resp.url().to_string();
+    if is_short_url(&resolved) {
+        bail!("短链接没有跳转: {}", url);
+    }
+    Ok(resolved)
 }
 
-fn extract_bvid(url: &str) -> Option<&str> {
+/// 提取 BV 号，旧的 av 号会转换成 BV 号
+fn extract_bvid(url: &str) -> Option<String> {
     lazy_static! {
         static ref BVID_REGEX: Regex = Regex::new(r"\bBV\w{10}\b").unwrap();
+        // 只认裸 id 或路径里的 /av123
+        static ref AID_REGEX: Regex = Regex::new(r"(?i)(?:^|/)av(\d+)\b").unwrap();
+    }
+    if let Some(m) = BVID_REGEX.find(url) {
+        return Some(m.as_str().to_string());
     }
-    BVID_REGEX
+    let aid = AID_REGEX.captures(url)?.get(1)?.as_str().parse().ok()?;
+    Some(av_to_bv(aid))
+}
+
+/// av 号转 BV 号，算法见 bilibili-API-collect
+fn av_to_bv(aid: u64) -> String {
+    const XOR_CODE: u64 = 23442827791579;
+    const MAX_AID: u64 = 1 << 51;
+    const ALPHABET: &[u8] = b"FcwAPNKTMug3GV5Lj7EJnHpWsx4tb8haYeviqBz6rkCy12mUSDQX9RdoZf";
+    const ENCODE_MAP: [usize; 9] = [8, 7, 0, 5, 1, 3, 2, 4, 6];
+    let mut bvid = [0u8; 9];
+    let mut tmp = (MAX_AID | aid) ^ XOR_CODE;
+    for i in ENCODE_MAP {
+        bvid[i] = ALPHABET[(tmp % 58) as usize];
+        tmp /= 58;
+    }
+    format!("BV1{}", String::from_utf8_lossy(&bvid))
+}
+
+/// 多 P 视频每一 P 的 id，如 `BV17x411w7KC_p2`
+fn page_id(bvid: &str, page: u32) -> String {
+    format!("{}_p{}", bvid, page)
+}
+
+fn extract_page(url: &str) -> Option<u32> {
+    lazy_static! {
+        static ref PAGE_REGEX: Regex = Regex::new(r"[?&]p=(\d+)").unwrap();
+    }
+    PAGE_REGEX
         .captures(url)
-        .and_then(|c| c.get(0).map(|m| m.as_str()))
+        .and_then(|c| c.get(1))
+        .and_then(|m| m.as_str().parse().ok())
+}
+
+/// 获取 CC 字幕和 AI 字幕，AI 字幕需要登录；获取失败不影响视频下载
+async fn get_subtitles(bvid: &str, cid: u64, client: &Client) -> Vec<Subtitle> {
+    let result = async {
+        let body: serde_json::Value = client
+            .get("https://api.bilibili.com/x/player/v2")
+            .query(&[("bvid", bvid), ("cid", &cid.to_string())])
+            .send()
+            .await?
+            .json()
+            .await?;
+        check_code(&body)?;
+        let subtitles = body
+            .pointer("/data/subtitle/subtitles")
+            .and_then(|s| s.as_array())
+            .map(|list| {
+                list.iter()
+                    .filter_map(|subtitle| {
+                        let url = subtitle.get("subtitle_url")?.as_str()?;
+                        // 字幕地址通常省略了协议，如 //aisubtitle.hdslb.com/...
+                        let url = match url.strip_prefix("//") {
+                            Some(rest) => format!("https://{}", rest),
+                            None => url.to_string(),
+                        };
+                        Some(Subtitle {
+                            lang: subtitle.get("lan")?.as_str()?.to_string(),
+                            name: subtitle.get("lan_doc")?.as_str()?.to_string(),
+                            url: url.parse().ok()?,
+                            ext: "json".to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        anyhow::Ok(subtitles)
+    }
+    .await;
+    result.unwrap_or_else(|e| {
+        warn!(err = ?e, "获取字幕列表失败");
+        Vec::new()
+    })
+}
+
+/// 弹幕 XML，下载时会转换成 ASS
+fn danmaku(cid: u64) -> Subtitle {
+    Subtitle {
+        lang: "danmaku".to_string(),
+        name: "弹幕".to_string(),
+        url: format!("https://api.bilibili.com/x/v1/dm/list.so?oid={}", cid)
+            .parse()
+            .unwrap(),
+        ext: "xml".to_string(),
+    }
+}
+
+/// 番剧、电影等 PGC 内容的 id
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BangumiId {
+    Episode(u64),
+    Season(u64),
+    /// 媒体 id，需要先换成 season id
+    Media(u64),
+}
+
+fn extract_bangumi_id(url: &str) -> Option<BangumiId> {
+    lazy_static! {
+        static ref BANGUMI_REGEX: Regex =
+            Regex::new(r"(?i)/bangumi/(?:play|media)/(ep|ss|md)(\d+)").unwrap();
+    }
+    let caps = BANGUMI_REGEX.captures(url)?;
+    let id = caps.get(2)?.as_str().parse().ok()?;
+    match caps.get(1)?.as_str().to_ascii_lowercase().as_str() {
+        "ep" => Some(BangumiId::Episode(id)),
+        "ss" => Some(BangumiId::Season(id)),
+        _ => Some(BangumiId::Media(id)),
+    }
+}
+
+struct Season {
+    title: String,
+    cover: Option<Url>,
+    /// 发布时间取自各集
+    metadata: Metadata,
+    episodes: Vec<Episode>,
+}
+
+struct Episode {
+    ep_id: u64,
+    cid: u64,
+    bvid: String,
+    /// 集数，如「1」，电影是「正片」
+    title: String,
+    long_title: String,
+    duration: Duration,
+    pub_time: Option<u64>,
+}
+
+impl Season {
+    fn playlist(&self, season_id: u64) -> Playlist {
+        let entries = self
+            .episodes
+            .iter()
+            .map(|ep| PlaylistEntry {
+                id: format!("ep{}", ep.ep_id),
+                input: format!("https://www.bilibili.com/bangumi/play/ep{}", ep.ep_id),
+                title: ep.name(),
+                duration: Some(ep.duration),
+                upload_time: ep.pub_time,
+                kind: EntryKind::Video,
+            })
+            .collect();
+        Playlist {
+            id: format!("ss{}", season_id),
+            title: self.title.clone(),
+            entries,
+        }
+    }
+}
+
+impl Episode {
+    /// 形如「第 1 话 出发」，集数不是数字时原样保留
+    fn name(&self) -> String {
+        let number = if self.title.parse::<u32>().is_ok() {
+            format!("第 {} 话", self.title)
+        } else {
+            self.title.clone()
+        };
+        if self.long_title.is_empty() {
+            number
+        } else {
+            format!("{} {}", number, self.long_title)
+        }
+    }
 }
 
-async fn get_info(bvid: &str, client: &Client) -> anyhow::Result<(Url, Url)> {
-    let cid = get_cid(bvid, client).await?;
+async fn extract_bangumi(id: BangumiId, input: &str, client: &Client) -> anyhow::Result<Extracted> {
+    let query = match id {
+        BangumiId::Episode(ep_id) => ("ep_id", ep_id),
+        BangumiId::Season(season_id) => ("season_id", season_id),
+        BangumiId::Media(media_id) => ("season_id", get_season_id(media_id, client).await?),
+    };
+    let (season_id, season) = get_season(query, client).await?;
+    let episode = match id {
+        BangumiId::Episode(ep_id) => season
+            .episodes
+            .iter()
+            .find(|ep| ep.ep_id == ep_id)
+            .with_context(|| format!("剧集中没有 ep{}", ep_id))?,
+        // 整季交给用户挑选，只有一集（电影）时直接下载
+        _ if season.episodes.len() > 1 => {
+            return Ok(Extracted::Playlist(season.playlist(season_id)));
+        }
+        _ => season
+            .episodes
+            .first()
+            .context("这一季还没有可以播放的剧集")?,
+    };
+    let formats = get_pgc_formats(episode.ep_id, episode.cid, client).await?;
+    let mut subtitles = get_subtitles(&episode.bvid, episode.cid, client).await;
+    subtitles.push(danmaku(episode.cid));
+    let title = if season.episodes.len() > 1 {
+        format!("{} {}", season.title, episode.name())
+    } else {
+        season.title.clone()
+    };
+    let mut info = MediaInfo {
+        id: format!("ep{}", episode.ep_id),
+        title,
+        streams: Vec::new(),
+        formats,
+        thumbnails: season
+            .cover
+            .clone()
+            .map(|url| Thumbnail { url })
+            .into_iter()
+            .collect(),
+        subtitles,
+        metadata: Metadata {
+            upload_time: episode.pub_time,
+            ..season.metadata.clone()
+        },
+        headers: get_headers(input),
+        part_of: None,
+        input: String::new(),
+    };
+    info.apply_policy(&FormatPolicy::BEST);
+    Ok(Extracted::Media(info))
+}
+
+async fn get_season_id(media_id: u64, client: &Client) -> anyhow::Result<u64> {
     let body: serde_json::Value = client
-        .get("https://api.bilibili.com/x/player/playurl?qn=80&fnval=4048&fourk=1&try_look=1")
-        .query(&[("bvid", bvid), ("cid", &cid.to_string())])
+        .get("https://api.bilibili.com/pgc/review/user")
+        .query(&[("media_id", media_id)])
         .send()
         .await?
         .json()
         .await?;
-    let code = body
-        .get("code")
-        .and_then(|c| c.as_i64())
-        .ok_or_else(|| anyhow!("bilibili API 错误: 没有 code"))?;
-    if code != 0 {
-        let msg = body
-            .get("message")
-            .and_then(|m| m.as_str())
-            .unwrap_or("未知错误");
-        bail!("bilibili API 错误: code: {}, message: {}", code, msg);
+    check_code(&body)?;
+    body.pointer("/result/media/season_id")
+        .and_then(|id| id.as_u64())
+        .context("无法获取番剧信息")
+}
+
+/// 按 ep_id 或 season_id 获取整季信息，返回 season_id 和剧集列表
+async fn get_season(query: (&str, u64), client: &Client) -> anyhow::Result<(u64, Season)> {
+    let body: serde_json::Value = client
+        .get("https://api.bilibili.com/pgc/view/web/season")
+        .query(&[query])
+        .send()
+        .await?
+        .json()
+        .await?;
+    check_code(&body)?;
+    let result = body.get("result").context("无法获取番剧信息")?;
+    let season_id = result
+        .get("season_id")
+        .and_then(|id| id.as_u64())
+        .context("无法获取番剧信息")?;
+    let title = result
+        .get("title")
+        .and_then(|t| t.as_str())
+        .context("无法获取标题")?
+        .to_string();
+    let cover = result
+        .get("cover")
+        .and_then(|c| c.as_str())
+        .and_then(|c| c.parse().ok());
+    let episodes = result
+        .get("episodes")
+        .and_then(|e| e.as_array())
+        .context("无法获取剧集列表")?
+        .iter()
+        .map(|ep| {
+            Some(Episode {
+                ep_id: ep.get("id")?.as_u64()?,
+                cid: ep.get("cid")?.as_u64()?,
+                bvid: ep
+                    .get("bvid")
+                    .and_then(|b| b.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                title: ep.get("title")?.as_str()?.to_string(),
+                long_title: ep
+                    .get("long_title")
+                    .and_then(|t| t.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                // 番剧接口的时长单位是毫秒
+                duration: Duration::from_millis(
+                    ep.get("duration").and_then(|d| d.as_u64()).unwrap_or(0),
+                ),
+                pub_time: ep.get("pub_time").and_then(|p| p.as_u64()),
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .context("无法解析剧集信息")?;
+    let metadata = Metadata {
+        uploader: result
+            .pointer("/up_info/uname")
+            .and_then(|n| n.as_str())
+            .map(String::from),
+        description: result
+            .get("evaluate")
+            .and_then(|d| d.as_str())
+            .filter(|d| !d.is_empty())
+            .map(String::from),
+        upload_time: None,
+    };
+    Ok((
+        season_id,
+        Season {
+            title,
+            cover,
+            metadata,
+            episodes,
+        },
+    ))
+}
+
+async fn get_pgc_formats(ep_id: u64, cid: u64, client: &Client) -> anyhow::Result<Vec<Stream>> {
+    let body: serde_json::Value = client
+        .get("https://api.bilibili.com/pgc/player/web/playurl?qn=127&fnval=4048&fourk=1")
+        .query(&[("ep_id", ep_id), ("cid", cid)])
+        .send()
+        .await?
+        .json()
+        .await?;
+    check_code(&body)?;
+    parse_playurl(body.get("result").context("无法获取播放信息")?)
+}
+
+/// 获取 DASH 中所有的视频和音频流
+async fn get_formats(bvid: &str, cid: u64, client: &Client) -> anyhow::Result<Vec<Stream>> {
+    let mut request = client
+        .get("https://api.bilibili.com/x/player/playurl?qn=127&fnval=4048&fourk=1")
+        .query(&[("bvid", bvid), ("cid", &cid.to_string())]);
+    // 未登录时靠 try_look 拿到 1080P，登录后按账号权限返回
+    if bili_session().is_none() {
+        request = request.query(&[("try_look", "1")]);
     }
-    let dash = body
-        .get("data")
-        .and_then(|d| d.get("dash"))
-        .context("无法获取 dash 数据")?;
-    let video_url = dash
+    let body: serde_json::Value = request.send().await?.json().await?;
+    check_code(&body)?;
+    parse_playurl(body.get("data").context("无法获取播放信息")?)
+}
+
+/// 解析普通视频的 data 或番剧的 result，两者结构相同
+fn parse_playurl(data: &serde_json::Value) -> anyhow::Result<Vec<Stream>> {
+    let dash = data.get("dash").context("无法获取 dash 数据")?;
+    // accept_quality 和 accept_description 一一对应，如 80 => 1080P 高清
+    let labels: HashMap<u64, &str> = data
+        .get("accept_quality")
+        .and_then(|q| q.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|q| q.as_u64())
+        .zip(
+            data.get("accept_description")
+                .and_then(|d| d.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|d| d.as_str()),
+        )
+        .collect();
+    let mut formats = Vec::new();
+    for video in dash
         .get("video")
-        .and_then(|v| v.get(0))
-        .and_then(|v| v.get("baseUrl").or_else(|| v.get("base_url")))
-        .and_then(|v| v.as_str())
+        .and_then(|v| v.as_array())
         .context("无法获取视频")?
-        .parse()
-        .context("无法解析视频 URL")?;
-    let audio_url = dash
+    {
+        let mut stream =
+            parse_dash_stream(video, StreamKind::Video, "mp4").context("无法解析视频 URL")?;
+        stream.format.label = video
+            .get("id")
+            .and_then(|id| id.as_u64())
+            .and_then(|id| labels.get(&id))
+            .map(|label| label.to_string());
+        formats.push(stream);
+    }
+    for audio in dash
         .get("audio")
-        .and_then(|a| a.get(0))
-        .and_then(|a| a.get("baseUrl").or_else(|| a.get("base_url")))
-        .and_then(|a| a.as_str())
+        .and_then(|a| a.as_array())
         .context("无法获取音频")?
-        .parse()
-        .context("无法解析音频 URL")?;
-    Ok((video_url, audio_url))
+    {
+        let mut stream =
+            parse_dash_stream(audio, StreamKind::Audio, "m4a").context("无法解析音频 URL")?;
+        stream.format.label = match stream.format.id.as_str() {
+            "30216" => Some("64K".to_string()),
+            "30232" => Some("132K".to_string()),
+            "30280" => Some("192K".to_string()),
+            _ => None,
+        };
+        formats.push(stream);
+    }
+    // 无损和杜比音轨只在视频有、账号有权限时返回，字段为 null
+    let flac = dash.pointer("/flac/audio").filter(|a| !a.is_null());
+    let dolby = dash
+        .pointer("/dolby/audio")
+        .and_then(|a| a.as_array())
+        .into_iter()
+        .flatten();
+    for (audio, label) in flac
+        .into_iter()
+        .map(|a| (a, "Hi-Res 无损"))
+        .chain(dolby.map(|a| (a, "杜比全景声")))
+    {
+        let Some(mut stream) = parse_dash_stream(audio, StreamKind::Audio, "m4a") else {
+            warn!(label, "无法解析音频 URL");
+            continue;
+        };
+        stream.format.label = Some(label.to_string());
+        formats.push(stream);
+    }
+    Ok(formats)
+}
+
+/// 解析 dash.video / dash.audio 中的一项，接口有驼峰和下划线两种字段名
+fn parse_dash_stream(value: &serde_json::Value, kind: StreamKind, ext: &str) -> Option<Stream> {
+    let get = |camel: &str, snake: &str| value.get(camel).or_else(|| value.get(snake));
+    let url = get("baseUrl", "base_url")?.as_str()?.parse().ok()?;
+    let mirrors = get("backupUrl", "backup_url")
+        .and_then(|b| b.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|u| u.as_str()?.parse().ok())
+        .collect();
+    let format = Format {
+        id: value.get("id")?.as_u64()?.to_string(),
+        label: None,
+        codecs: value
+            .get("codecs")
+            .and_then(|c| c.as_str())
+            .map(String::from),
+        width: value
+            .get("width")
+            .and_then(|w| w.as_u64())
+            .map(|w| w as u32),
+        height: value
+            .get("height")
+            .and_then(|h| h.as_u64())
+            .map(|h| h as u32),
+        bandwidth: value.get("bandwidth").and_then(|b| b.as_u64()),
+        frame_rate: get("frameRate", "frame_rate")
+            .and_then(|f| f.as_str())
+            .and_then(|f| f.parse().ok()),
+    };
+    Some(
+        Stream::new(kind, url, ext)
+            .with_mirrors(mirrors)
+            .with_format(format),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dash_stream() {
+        let video = serde_json::json!({
+            "id": 80,
+            "baseUrl": "https://upos-sz-mirrorcos.bilivideo.com/a.m4s",
+            "backupUrl": [
+                "https://upos-sz-mirrorali.bilivideo.com/a.m4s",
+                "https://upos-sz-mirror08c.bilivideo.com/a.m4s"
+            ],
+            "codecs": "avc1.640032",
+            "width": 1920,
+            "height": 1080,
+            "frameRate": "29.970"
+        });
+        let stream = parse_dash_stream(&video, StreamKind::Video, "mp4").unwrap();
+        assert_eq!(stream.mirrors.len(), 2);
+        assert_eq!(stream.format.height, Some(1080));
+        let audio = serde_json::json!({
+            "id": 30280,
+            "base_url": "https://upos-sz-mirrorcos.bilivideo.com/b.m4s",
+            "backup_url": ["https://upos-sz-mirrorali.bilivideo.com/b.m4s"],
+            "codecs": "mp4a.40.2"
+        });
+        let stream = parse_dash_stream(&audio, StreamKind::Audio, "m4a").unwrap();
+        assert_eq!(
+            stream.mirrors[0].as_str(),
+            "https://upos-sz-mirrorali.bilivideo.com/b.m4s"
+        );
+        let no_backup = serde_json::json!({ "id": 16, "baseUrl": "https://a.com/c.m4s" });
+        let stream = parse_dash_stream(&no_backup, StreamKind::Video, "mp4").unwrap();
+        assert!(stream.mirrors.is_empty());
+    }
+
+    #[test]
+    fn test_extract_bvid() {
+        assert_eq!(
+            extract_bvid("https://www.bilibili.com/video/BV17x411w7KC/?p=2").as_deref(),
+            Some("BV17x411w7KC")
+        );
+        assert_eq!(
+            extract_bvid("https://www.bilibili.com/video/av170001").as_deref(),
+            Some("BV17x411w7KC")
+        );
+        assert_eq!(
+            extract_bvid("AV1054803170").as_deref(),
+            Some("BV1mH4y1u7UA")
+        );
+        assert_eq!(extract_bvid("https://www.bilibili.com/"), None);
+        assert_eq!(
+            extract_bvid("https://www.bilibili.com/video/?codec=av1"),
+            None
+        );
+        assert!(is_short_url("https://b23.tv/abc"));
+        assert!(!is_short_url("https://www.bilibili.com/video/BV17x411w7KC"));
+    }
+
+    #[test]
+    fn test_matches() {
+        let down = BiliDown::new();
+        assert!(down.matches("av170001"));
+        assert!(down.matches("BV17x411w7KC"));
+        assert!(down.matches("https://www.bilibili.com/video/av170001"));
+        assert!(!down.matches("https://example.com/video.mp4?codec=av1"));
+        assert!(!down.matches("看看 av170001"));
+        assert!(!down.matches("https://live.bilibili.com/22637261"));
+    }
+
+    #[test]
+    fn test_extract_bangumi_id() {
+        assert_eq!(
+            extract_bangumi_id("https://www.bilibili.com/bangumi/play/ep123?spm_id_from=333"),
+            Some(BangumiId::Episode(123))
+        );
+        assert_eq!(
+            extract_bangumi_id("https://m.bilibili.com/bangumi/play/ss456"),
+            Some(BangumiId::Season(456))
+        );
+        assert_eq!(
+            extract_bangumi_id("https://www.bilibili.com/bangumi/media/md789/"),
+            Some(BangumiId::Media(789))
+        );
+        assert_eq!(
+            extract_bangumi_id("https://www.bilibili.com/video/BV1xx411c7mD"),
+            None
+        );
+    }
 }
@@ -1,166 +1,419 @@
 use crate::{
-    JS_RT, Parser, TOKIO_RT,
-    abort::AbortOnDrop,
-    fd::{ProgressState, download_segment},
+    Extracted, Extractor, JS_RT, MediaInfo, Metadata, Stream, StreamKind, Thumbnail,
+    douyin_list::{extract_douyin_list, get_douyin_list},
+    find_url, traced,
 };
-use anyhow::Context as _;
-use gpui::{
-    AnyView, App, AppContext, Context, IntoElement, ParentElement, Render, SharedString, Styled,
-    Task, Timer, Window, div,
-};
-use gpui_component::{StyledExt, h_flex, progress::Progress, v_flex};
+use anyhow::{Context as _, bail};
+use async_trait::async_trait;
+use lazy_static::lazy_static;
 use regex::Regex;
 use reqwest::{
-    Client,
+    Client, ClientBuilder, Url,
     header::{self, HeaderMap},
 };
-use std::{
-    env,
-    sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
-    },
-    time::Duration,
-};
+use tracing::{info_span, warn};
 
 fn get_headers(referer: &str) -> HeaderMap {
     HeaderMap::from_iter( [
-        (header::REFERER, referer.parse().unwrap()),
+        (header::REFERER, referer.parse().unwrap_or(header::HeaderValue::from_static("https://www.douyin.com/"))),
         (header::ORIGIN, "https://www.douyin.com".parse().unwrap()),
         (header::USER_AGENT, "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36".parse().unwrap()),
     ])
 }
 
+/// 分享页只对手机浏览器返回带数据的页面
+pub(crate) const MOBILE_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 16_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/16.6 Mobile/15E148 Safari/604.1";
+
 #[derive(Default)]
-pub struct DouyinDown {}
+pub struct DouyinDown {
+    third_party: bool,
+}
 
 impl DouyinDown {
-    pub fn new() -> Self {
-        Self {}
+    /// `third_party` 为真时，原生解析失败会把链接发给 hellotik.app 解析，默认关闭
+    pub fn new(third_party: bool) -> Self {
+        Self { third_party }
     }
 }
 
-impl Parser for DouyinDown {
-    fn parse(
-        &self,
-        input: &str,
-        _: &mut Window,
-        cx: &mut App,
-    ) -> Task<anyhow::Result<Option<AnyView>>> {
-        let client = Client::new();
-        let client_cl = client.clone();
-        let js_rt = JS_RT.clone();
-        let headers = get_headers(input).into();
-        let input = extract_modal_id(input)
-            .map(|s| format!("https://www.douyin.com?{s}"))
-            .unwrap_or_else(|| input.to_string());
-        let fut = TOKIO_RT.spawn(async move { js_rt.parse_douyin(input, client_cl).await });
-        cx.spawn(async move |cx| {
-            let (title, video_url) = fut.await?.context("无法解析抖音视频链接")?;
-            let title = SharedString::from(title);
-            let output_dir = dirs::desktop_dir()
-                .or_else(dirs::download_dir)
-                .or_else(|| env::current_dir().ok())
-                .context("找不到下载目录")?;
-            let state = ProgressState::new();
-            let is_finished = Arc::new(AtomicBool::new(false));
-            let task_handle = {
-                let is_finished = is_finished.clone();
-                let state = state.clone();
-                let title = title.clone();
-                TOKIO_RT.spawn(async move {
-                    let _guard = scopeguard::guard((), |_| {
-                        is_finished.store(true, Ordering::Relaxed);
-                    });
-                    download_segment(
-                        video_url,
-                        &title,
-                        "mp4",
-                        &output_dir,
-                        &client,
-                        &state,
-                        4,
-                        headers,
-                    )
-                    .await?;
-                    Ok(())
-                })
-            };
-            let view = cx.new(|cx| {
-                let finished_flag = is_finished.clone();
-                cx.spawn(async move |view, cx| {
-                    loop {
-                        if finished_flag.load(Ordering::Relaxed) {
-                            break;
-                        }
-                        Timer::after(Duration::from_millis(100)).await;
-                        if view.update(cx, |_, cx| cx.notify()).is_err() {
-                            break;
-                        }
-                    }
-                })
-                .detach();
-                DouyinView {
-                    title,
-                    state,
-                    is_finished,
-                    _guard: AbortOnDrop(task_handle),
-                }
-            })?;
-            Ok(Some(view.into()))
-        })
+#[async_trait]
+impl Extractor for DouyinDown {
+    fn name(&self) -> &'static str {
+        "抖音"
     }
+
+    fn matches(&self, input: &str) -> bool {
+        lazy_static! {
+            static ref DOUYIN_REGEX: Regex =
+                Regex::new(r"(?i)\b(douyin\.com|iesdouyin\.com)\b").unwrap();
+        }
+        DOUYIN_REGEX.is_match(input)
+    }
+
+    async fn extract(&self, input: &str) -> anyhow::Result<Option<Extracted>> {
+        let input = match find_url(input) {
+            Some(url) if is_short_url(url) => resolve_short_url(url).await?,
+            Some(url) => url.to_string(),
+            None => input.to_string(),
+        };
+        let input = input.as_str();
+        let third_party = self.third_party;
+        if let Some(aweme_id) = extract_aweme_id(input) {
+            match traced(info_span!("原生解析", aweme_id), extract_native(aweme_id)).await {
+                Ok(info) => return Ok(Some(Extracted::Media(info))),
+                Err(e) if third_party => warn!(err = ?e, "原生解析失败"),
+                Err(e) => return Err(e),
+            }
+        } else if let Some(list) = extract_douyin_list(input) {
+            let client = ClientBuilder::new()
+                .default_headers(get_headers("https://www.douyin.com/"))
+                .build()
+                .context("无法创建客户端")?;
+            let span = info_span!("获取列表", ?list);
+            let playlist = traced(span, get_douyin_list(list, &client)).await?;
+            return Ok(Some(Extracted::Playlist(playlist)));
+        } else if !third_party {
+            bail!("无法识别抖音作品 id");
+        }
+        warn!(url = input, "链接将发送给第三方服务 hellotik.app 解析");
+        traced(info_span!("第三方解析"), extract_third_party(input))
+            .await
+            .map(Some)
+    }
+}
+
+/// 作品 id（aweme_id），支持 `modal_id=`、`/video/<id>`、`/note/<id>` 和 `share/video/<id>`
+fn extract_aweme_id(url: &str) -> Option<&str> {
+    lazy_static! {
+        static ref AWEME_ID_REGEX: Regex =
+            Regex::new(r"(?i)(?:\bmodal_id=|(?:douyin\.com|/share)/(?:video|note|slides)/)(\d+)")
+                .unwrap();
+    }
+    AWEME_ID_REGEX.captures(url)?.get(1).map(|m| m.as_str())
+}
+
+fn is_short_url(url: &str) -> bool {
+    lazy_static! {
+        static ref SHORT_URL_REGEX: Regex =
+            Regex::new(r"(?i)^https?://(v\.douyin\.com|v\.iesdouyin\.com)/").unwrap();
+    }
+    SHORT_URL_REGEX.is_match(url)
+}
+
+/// 跟随 v.douyin.com 短链接的跳转，返回最终的链接
+async fn resolve_short_url(url: &str) -> anyhow::Result<String> {
+    let resp = Client::builder()
+        .default_headers(get_headers("https://www.douyin.com/"))
+        .build()?
+        .get(url)
+        .header(header::USER_AGENT, MOBILE_UA)
+        .send()
+        .await
+        .context("无法打开短链接")?;
+    let resolved = resp.url().to_string();
+    if is_short_url(&resolved) {
+        bail!("短链接没有跳转: {}", url);
+    }
+    Ok(resolved)
+}
+
+/// 作品详情，来自分享页里内嵌的数据
+struct Aweme {
+    id: String,
+    desc: String,
+    author: Option<String>,
+    create_time: Option<u64>,
+    cover: Option<Url>,
+    video: Option<Url>,
+    /// 图文作品的图片，每张图片的地址都按兼容性排好
+    images: Vec<Vec<Url>>,
+    /// 背景音乐
+    music: Option<Url>,
 }
 
-pub struct DouyinView {
-    title: SharedString,
-    state: Arc<ProgressState>,
-    is_finished: Arc<AtomicBool>,
-    _guard: AbortOnDrop<anyhow::Result<()>>,
-}
-
-impl Render for DouyinView {
-    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
-        let (text, pct) = self.state.display();
-        let done = self.is_finished.load(Ordering::Relaxed);
-
-        v_flex()
-            .p_4()
-            .gap_4()
-            .child(div().child(self.title.clone()).text_2xl().font_bold())
-            .child(self.render_row("视频", text, pct))
-            .child(
-                div()
-                    .child(if done {
-                        "全部完成，请检查桌面"
-                    } else {
-                        "下载还未完成，请耐心等待，点解析按钮可以打断下载并重试"
-                    })
-                    .text_2xl()
-                    .font_bold(),
-            )
+/// 图文作品的分享页是 `/share/note/`，视频页拿不到数据时再试一次
+async fn get_aweme(aweme_id: &str, client: &Client) -> anyhow::Result<Aweme> {
+    match get_share_page(aweme_id, "video", client).await {
+        Ok(aweme) => Ok(aweme),
+        Err(e) => {
+            warn!(err = ?e, "视频分享页没有数据，改用图文分享页");
+            get_share_page(aweme_id, "note", client).await
+        }
     }
 }
 
-impl DouyinView {
-    fn render_row(&self, label: &str, text: String, pct: f32) -> impl IntoElement {
-        v_flex()
-            .gap_2()
-            .child(
-                h_flex()
-                    .justify_between()
-                    .child(div().child(label.to_string()).text_lg().font_bold())
-                    .child(text),
-            )
-            .child(Progress::new().value(pct))
+/// 分享页不需要 a_bogus 之类的签名参数，数据在 `window._ROUTER_DATA` 里
+async fn get_share_page(aweme_id: &str, kind: &str, client: &Client) -> anyhow::Result<Aweme> {
+    lazy_static! {
+        static ref ROUTER_DATA_REGEX: Regex =
+            Regex::new(r"(?s)window\._ROUTER_DATA\s*=\s*(\{.*?\})\s*</script>").unwrap();
     }
+    let html = client
+        .get(format!(
+            "https://www.iesdouyin.com/share/{}/{}/",
+            kind, aweme_id
+        ))
+        .header(header::USER_AGENT, MOBILE_UA)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    let json = ROUTER_DATA_REGEX
+        .captures(&html)
+        .and_then(|c| c.get(1))
+        .context("分享页中没有作品数据")?
+        .as_str();
+    let data: serde_json::Value = serde_json::from_str(json).context("无法解析分享页数据")?;
+    // loaderData 的键形如 `video_(id)/page` 或 `note_(id)/page`
+    let item = data
+        .get("loaderData")
+        .and_then(|l| l.as_object())
+        .into_iter()
+        .flat_map(|l| l.values())
+        .find_map(|page| page.pointer("/videoInfoRes/item_list/0"))
+        .context("作品不存在或已删除")?;
+    parse_aweme(item)
+}
+
+fn parse_aweme(item: &serde_json::Value) -> anyhow::Result<Aweme> {
+    let first_url = |value: Option<&serde_json::Value>| url_list(value?).into_iter().next();
+    // playwm 是带水印的地址，换成 play 就是无水印的
+    let video = first_url(item.pointer("/video/play_addr"))
+        .and_then(|url| Url::parse(&url.as_str().replace("/playwm/", "/play/")).ok());
+    Ok(Aweme {
+        id: item
+            .get("aweme_id")
+            .and_then(|id| id.as_str())
+            .context("无法获取作品 id")?
+            .to_string(),
+        desc: item
+            .get("desc")
+            .and_then(|d| d.as_str())
+            .unwrap_or_default()
+            .to_string(),
+        author: item
+            .pointer("/author/nickname")
+            .and_then(|n| n.as_str())
+            .map(String::from),
+        create_time: item.get("create_time").and_then(|t| t.as_u64()),
+        cover: first_url(item.pointer("/video/origin_cover"))
+            .or_else(|| first_url(item.pointer("/video/cover"))),
+        video,
+        images: item
+            .get("images")
+            .and_then(|i| i.as_array())
+            .into_iter()
+            .flatten()
+            .map(|image| {
+                let mut urls = url_list(image);
+                // webp 的兼容性不如 jpeg，把 jpeg 排在前面
+                urls.sort_by_key(|url| url.path().ends_with(".webp"));
+                urls
+            })
+            .filter(|urls| !urls.is_empty())
+            .collect(),
+        music: first_url(item.pointer("/music/play_url")),
+    })
+}
+
+fn url_list(value: &serde_json::Value) -> Vec<Url> {
+    value
+        .get("url_list")
+        .and_then(|l| l.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|u| Url::parse(u.as_str()?).ok())
+        .collect()
+}
+
+/// 图文作品：每张图片一个流，背景音乐作为音频流，`images` 中每项都不能为空
+fn gallery_streams(images: Vec<Vec<Url>>, music: Option<Url>) -> Vec<Stream> {
+    let mut streams: Vec<_> = images
+        .into_iter()
+        .map(|mut urls| {
+            let url = urls.remove(0);
+            let ext = url
+                .path()
+                .rsplit_once('.')
+                .map(|(_, ext)| ext.to_ascii_lowercase())
+                .filter(|ext| ["jpeg", "jpg", "png", "webp"].contains(&ext.as_str()))
+                .unwrap_or_else(|| "jpg".to_string());
+            Stream::new(StreamKind::Image, url, ext).with_mirrors(urls)
+        })
+        .collect();
+    streams.extend(music.map(|url| Stream::new(StreamKind::Audio, url, "mp3")));
+    streams
+}
+
+async fn extract_native(aweme_id: &str) -> anyhow::Result<MediaInfo> {
+    let client = ClientBuilder::new()
+        .default_headers(get_headers("https://www.douyin.com/"))
+        .build()
+        .context("无法创建客户端")?;
+    let aweme = get_aweme(aweme_id, &client).await?;
+    let streams = if aweme.images.is_empty() {
+        let video = aweme.video.context("作品没有视频")?;
+        vec![Stream::new(StreamKind::Muxed, video, "mp4")]
+    } else {
+        gallery_streams(aweme.images, aweme.music)
+    };
+    let title = if aweme.desc.trim().is_empty() {
+        format!("抖音 {}", aweme.id)
+    } else {
+        aweme.desc.clone()
+    };
+    Ok(MediaInfo {
+        id: aweme.id,
+        title,
+        streams,
+        formats: Vec::new(),
+        thumbnails: aweme
+            .cover
+            .map(|url| Thumbnail { url })
+            .into_iter()
+            .collect(),
+        subtitles: Vec::new(),
+        metadata: Metadata {
+            uploader: aweme.author,
+            description: Some(aweme.desc).filter(|d| !d.trim().is_empty()),
+            upload_time: aweme.create_time,
+        },
+        headers: get_headers("https://www.douyin.com/"),
+        part_of: None,
+        input: String::new(),
+    })
 }
 
-fn extract_modal_id(url: &str) -> Option<&str> {
-    lazy_static::lazy_static! {
-        static ref MODAL_ID_REGEX: Regex = Regex::new(r"\bmodal_id=\d+?\b").unwrap();
+/// 通过 hellotik.app 解析，只作为原生解析失败时的备用
+async fn extract_third_party(input: &str) -> anyhow::Result<Extracted> {
+    let headers = get_headers(input);
+    let aweme_id = extract_aweme_id(input);
+    let url = aweme_id
+        .map(|id| format!("https://www.douyin.com?modal_id={id}"))
+        .unwrap_or_else(|| input.to_string());
+    let output = JS_RT
+        .parse_douyin(url, Client::new())
+        .await
+        .context("无法解析抖音视频链接")?;
+    Ok(Extracted::Media(MediaInfo {
+        id: aweme_id.unwrap_or_default().to_string(),
+        title: output.title,
+        streams: vec![Stream::new(StreamKind::Muxed, output.url, "mp4")],
+        formats: Vec::new(),
+        thumbnails: output
+            .cover
+            .map(|url| Thumbnail { url })
+            .into_iter()
+            .collect(),
+        subtitles: Vec::new(),
+        metadata: Metadata::default(),
+        headers,
+        part_of: None,
+        input: String::new(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_aweme_id() {
+        assert_eq!(
+            extract_aweme_id("https://www.douyin.com/discover?modal_id=7300000000000000000"),
+            Some("7300000000000000000")
+        );
+        assert_eq!(
+            extract_aweme_id(
+                "https://www.douyin.com/video/7300000000000000000?previous_page=app_code_link"
+            ),
+            Some("7300000000000000000")
+        );
+        assert_eq!(
+            extract_aweme_id("https://www.douyin.com/note/7300000000000000001"),
+            Some("7300000000000000001")
+        );
+        assert_eq!(
+            extract_aweme_id(
+                "https://www.iesdouyin.com/share/video/7300000000000000002/?region=CN"
+            ),
+            Some("7300000000000000002")
+        );
+        assert_eq!(
+            extract_aweme_id(
+                "https://www.douyin.com/user/MS4wLjABAAAA?modal_id=7300000000000000003"
+            ),
+            Some("7300000000000000003")
+        );
+        assert_eq!(extract_aweme_id("https://www.douyin.com/"), None);
+        assert_eq!(
+            extract_aweme_id("https://www.douyin.com/user/MS4wLjABAAAA"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_share_text() {
+        let text = "7.99 复制打开抖音，看看【xxx的作品】春天来了 # 风景 https://v.douyin.com/AbCdEf/ 02/03 a@B.go :5pm";
+        let url = find_url(text).unwrap();
+        assert_eq!(url, "https://v.douyin.com/AbCdEf/");
+        assert!(is_short_url(url));
+        assert!(!is_short_url(
+            "https://www.douyin.com/video/7300000000000000000"
+        ));
+    }
+
+    #[test]
+    fn test_parse_aweme() {
+        let item = serde_json::json!({
+            "aweme_id": "7300000000000000000",
+            "desc": "标题 #话题",
+            "create_time": 1700000000,
+            "author": { "nickname": "作者" },
+            "video": {
+                "play_addr": {
+                    "uri": "v0200fg10000",
+                    "url_list": ["https://aweme.snssdk.com/aweme/v1/playwm/?video_id=v0200fg10000&ratio=720p"]
+                },
+                "cover": { "url_list": ["https://p3.douyinpic.com/cover.jpeg"] }
+            }
+        });
+        let aweme = parse_aweme(&item).unwrap();
+        assert_eq!(aweme.id, "7300000000000000000");
+        assert_eq!(aweme.author.as_deref(), Some("作者"));
+        assert_eq!(aweme.create_time, Some(1700000000));
+        assert_eq!(
+            aweme.video.unwrap().as_str(),
+            "https://aweme.snssdk.com/aweme/v1/play/?video_id=v0200fg10000&ratio=720p"
+        );
+        assert_eq!(
+            aweme.cover.unwrap().as_str(),
+            "https://p3.douyinpic.com/cover.jpeg"
+        );
+        assert!(aweme.images.is_empty());
+    }
+
+    #[test]
+    fn test_gallery_streams() {
+        let item = serde_json::json!({
+            "aweme_id": "7300000000000000001",
+            "images": [
+                { "url_list": [
+                    "https://p3.douyinpic.com/a~tplv-dy-aweme-images:q75.webp?x=1",
+                    "https://p9.douyinpic.com/a~tplv-dy-aweme-images:q75.jpeg?x=1"
+                ] },
+                { "url_list": ["https://p3.douyinpic.com/b~noop.image"] }
+            ],
+            "music": { "play_url": { "url_list": ["https://sf3.douyinvod.com/music.mp3"] } }
+        });
+        let aweme = parse_aweme(&item).unwrap();
+        let streams = gallery_streams(aweme.images, aweme.music);
+        assert_eq!(streams.len(), 3);
+        assert_eq!(streams[0].kind, StreamKind::Image);
+        assert_eq!(streams[0].ext, "jpeg");
+        assert_eq!(streams[0].mirrors.len(), 1);
+        assert_eq!(streams[1].ext, "jpg");
+        assert_eq!(streams[2].kind, StreamKind::Audio);
     }
-    MODAL_ID_REGEX
-        .captures(url)
-        .and_then(|c| c.get(0).map(|m| m.as_str()))
 }
@@ -0,0 +1,309 @@
+use crate::{EntryKind, Playlist, PlaylistEntry, douyin::MOBILE_UA};
+use anyhow::{Context as _, bail};
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::{Client, header};
+use std::time::Duration;
+use tracing::info;
+
+/// 用户主页、合集和音乐原声页这几种可以批量下载的列表
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DouyinList {
+    /// sec_uid
+    User(String),
+    Mix(String),
+    Music(String),
+}
+
+pub(crate) fn extract_douyin_list(url: &str) -> Option<DouyinList> {
+    lazy_static! {
+        static ref USER_REGEX: Regex =
+            Regex::new(r"(?i)(?:douyin\.com/user|iesdouyin\.com/share/user)/([\w\-]+)").unwrap();
+        static ref MIX_REGEX: Regex =
+            Regex::new(r"(?i)(?:douyin\.com/collection|/mix/detail)/(\d+)").unwrap();
+        static ref MUSIC_REGEX: Regex =
+            Regex::new(r"(?i)(?:douyin\.com|iesdouyin\.com/share)/music/(\d+)").unwrap();
+    }
+    let id = |re: &Regex| Some(re.captures(url)?.get(1)?.as_str().to_string());
+    if let Some(id) = id(&MIX_REGEX) {
+        return Some(DouyinList::Mix(id));
+    }
+    if let Some(id) = id(&MUSIC_REGEX) {
+        return Some(DouyinList::Music(id));
+    }
+    // 自己的主页是 /user/self，没有 sec_uid
+    id(&USER_REGEX)
+        .filter(|id| id != "self")
+        .map(DouyinList::User)
+}
+
+/// 翻页获取整个列表，每一项是一个视频或图文
+pub(crate) async fn get_douyin_list(list: DouyinList, client: &Client) -> anyhow::Result<Playlist> {
+    let mut title = String::new();
+    let mut entries = Vec::new();
+    let mut cursor = 0;
+    loop {
+        let page = get_page(&list, cursor, client).await?;
+        if title.is_empty()
+            && let Some(item) = page.items.first()
+        {
+            title = list_title(&list, item);
+        }
+        entries.extend(page.items.iter().filter_map(aweme_entry));
+        info!(cursor, count = entries.len(), "已获取列表");
+        if !page.has_more || page.items.is_empty() || page.cursor == cursor {
+            break;
+        }
+        cursor = page.cursor;
+        // 翻页太快容易触发风控
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    if entries.is_empty() {
+        bail!("列表为空，或者抖音拒绝了请求");
+    }
+    let id = match &list {
+        DouyinList::User(sec_uid) => format!("user{}", sec_uid),
+        DouyinList::Mix(id) => format!("mix{}", id),
+        DouyinList::Music(id) => format!("music{}", id),
+    };
+    Ok(Playlist { id, title, entries })
+}
+
+fn list_title(list: &DouyinList, item: &serde_json::Value) -> String {
+    let str_at = |pointer: &str| item.pointer(pointer).and_then(|v| v.as_str());
+    match list {
+        DouyinList::User(_) => format!(
+            "{} 的作品",
+            str_at("/author/nickname").unwrap_or("抖音用户")
+        ),
+        DouyinList::Mix(id) => str_at("/mix_info/mix_name")
+            .map(String::from)
+            .unwrap_or_else(|| format!("合集 {}", id)),
+        DouyinList::Music(id) => match str_at("/music/title") {
+            Some(title) => format!("使用「{}」的作品", title),
+            None => format!("音乐 {}", id),
+        },
+    }
+}
+
+fn aweme_entry(item: &serde_json::Value) -> Option<PlaylistEntry> {
+    let id = item.get("aweme_id")?.as_str()?;
+    let desc = item
+        .get("desc")
+        .and_then(|d| d.as_str())
+        .unwrap_or_default();
+    let is_gallery = item
+        .get("images")
+        .and_then(|i| i.as_array())
+        .is_some_and(|i| !i.is_empty());
+    Some(PlaylistEntry {
+        id: id.to_string(),
+        input: format!("https://www.douyin.com/discover?modal_id={}", id),
+        title: if desc.trim().is_empty() {
+            format!("抖音 {}", id)
+        } else {
+            desc.to_string()
+        },
+        // 接口给的是毫秒
+        duration: item
+            .pointer("/video/duration")
+            .and_then(|d| d.as_u64())
+            .filter(|_| !is_gallery)
+            .filter(|d| *d > 0)
+            .map(Duration::from_millis),
+        upload_time: item.get("create_time").and_then(|t| t.as_u64()),
+        kind: if is_gallery {
+            EntryKind::Gallery
+        } else {
+            EntryKind::Video
+        },
+    })
+}
+
+/// 分享页用的 iesdouyin 接口，不需要 a_bogus 签名，也不需要 ttwid
+fn page_api(list: &DouyinList) -> (&'static str, &'static str, (&'static str, String)) {
+    match list {
+        DouyinList::User(sec_uid) => (
+            "https://www.iesdouyin.com/web/api/v2/aweme/post/",
+            "max_cursor",
+            ("sec_uid", sec_uid.clone()),
+        ),
+        DouyinList::Mix(id) => (
+            "https://www.iesdouyin.com/web/api/mix/item/list/",
+            "cursor",
+            ("mix_id", id.clone()),
+        ),
+        DouyinList::Music(id) => (
+            "https://www.iesdouyin.com/web/api/v2/music/list/aweme/",
+            "cursor",
+            ("music_id", id.clone()),
+        ),
+    }
+}
+
+/// 一页作品，`has_more` 为假时没有下一页
+struct Page {
+    items: Vec<serde_json::Value>,
+    has_more: bool,
+    cursor: u64,
+}
+
+async fn get_page(list: &DouyinList, cursor: u64, client: &Client) -> anyhow::Result<Page> {
+    let (url, cursor_key, id) = page_api(list);
+    let text = client
+        .get(url)
+        .query(&[
+            id,
+            (cursor_key, cursor.to_string()),
+            ("count", "21".to_string()),
+        ])
+        .header(header::USER_AGENT, MOBILE_UA)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    // 被风控时返回 200 和空内容
+    if text.trim().is_empty() {
+        bail!("抖音接口没有返回数据，可能触发了风控，请稍后再试");
+    }
+    let body: serde_json::Value = serde_json::from_str(&text).context("无法解析列表数据")?;
+    parse_page(&body, cursor_key, cursor)
+}
+
+fn parse_page(body: &serde_json::Value, cursor_key: &str, cursor: u64) -> anyhow::Result<Page> {
+    if let Some(code) = body.get("status_code").and_then(|c| c.as_i64())
+        && code != 0
+    {
+        let msg = body
+            .get("status_msg")
+            .and_then(|m| m.as_str())
+            .unwrap_or_default();
+        bail!("抖音接口返回错误 {}: {}", code, msg);
+    }
+    let items = body
+        .get("aweme_list")
+        .and_then(|l| l.as_array())
+        .cloned()
+        .unwrap_or_default();
+    // has_more 有时是数字有时是布尔值
+    let has_more = match body.get("has_more") {
+        Some(serde_json::Value::Bool(b)) => *b,
+        Some(v) => v.as_u64().unwrap_or(0) != 0,
+        None => false,
+    };
+    Ok(Page {
+        items,
+        has_more,
+        cursor: body
+            .get(cursor_key)
+            .and_then(|c| c.as_u64())
+            .unwrap_or(cursor),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_douyin_list() {
+        assert_eq!(
+            extract_douyin_list(
+                "https://www.douyin.com/user/MS4wLjABAAAA-abc_123?from_tab_name=main"
+            ),
+            Some(DouyinList::User("MS4wLjABAAAA-abc_123".into()))
+        );
+        assert_eq!(
+            extract_douyin_list("https://www.douyin.com/user/self"),
+            None
+        );
+        assert_eq!(
+            extract_douyin_list("https://www.douyin.com/collection/7100000000000000000"),
+            Some(DouyinList::Mix("7100000000000000000".into()))
+        );
+        assert_eq!(
+            extract_douyin_list("https://www.iesdouyin.com/share/mix/detail/7100000000000000000/"),
+            Some(DouyinList::Mix("7100000000000000000".into()))
+        );
+        assert_eq!(
+            extract_douyin_list("https://www.douyin.com/music/6900000000000000000"),
+            Some(DouyinList::Music("6900000000000000000".into()))
+        );
+        assert_eq!(
+            extract_douyin_list("https://www.douyin.com/discover?modal_id=7300000000000000000"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_page() {
+        // 用户主页接口的一页，只保留用到的字段
+        let body = serde_json::json!({
+            "status_code": 0,
+            "aweme_list": [
+                {
+                    "aweme_id": "7300000000000000002",
+                    "desc": "第二条",
+                    "create_time": 1700000100,
+                    "author": { "nickname": "作者" },
+                    "video": { "duration": 15300 }
+                },
+                {
+                    "aweme_id": "7300000000000000001",
+                    "desc": "第一条",
+                    "create_time": 1700000000,
+                    "images": [{ "url_list": ["https://p3.douyinpic.com/a.jpeg"] }],
+                    "video": { "duration": 0 }
+                }
+            ],
+            "max_cursor": 1700000000000u64,
+            "min_cursor": 1700000100000u64,
+            "has_more": true,
+            "extra": { "now": 1700000200000u64 }
+        });
+        let page = parse_page(&body, "max_cursor", 0).unwrap();
+        assert!(page.has_more);
+        assert_eq!(page.cursor, 1700000000000);
+        let entries: Vec<_> = page.items.iter().filter_map(aweme_entry).collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].kind, EntryKind::Gallery);
+        assert_eq!(
+            list_title(&DouyinList::User("x".into()), &page.items[0]),
+            "作者 的作品"
+        );
+
+        let body = serde_json::json!({ "aweme_list": [], "has_more": 0, "cursor": 0 });
+        let page = parse_page(&body, "cursor", 20).unwrap();
+        assert!(!page.has_more);
+        assert_eq!(page.cursor, 0);
+
+        let body = serde_json::json!({ "status_code": 2053, "status_msg": "参数错误" });
+        assert!(parse_page(&body, "cursor", 0).is_err());
+    }
+
+    #[test]
+    fn test_aweme_entry() {
+        let item = serde_json::json!({
+            "aweme_id": "7300000000000000001",
+            "desc": "",
+            "create_time": 1700000000,
+            "images": [{ "url_list": [] }],
+            "video": { "duration": 0 }
+        });
+        let entry = aweme_entry(&item).unwrap();
+        assert_eq!(entry.title, "抖音 7300000000000000001");
+        assert_eq!(entry.kind, EntryKind::Gallery);
+        assert_eq!(entry.duration, None);
+
+        let item = serde_json::json!({
+            "aweme_id": "7300000000000000002",
+            "desc": "视频",
+            "images": null,
+            "video": { "duration": 15300 }
+        });
+        let entry = aweme_entry(&item).unwrap();
+        assert_eq!(entry.kind, EntryKind::Video);
+        assert_eq!(entry.duration, Some(Duration::from_millis(15300)));
+    }
+}
@@ -0,0 +1,584 @@
+use crate::{
+    AudioCodec, MediaInfo, Metadata, StreamKind, Thumbnail,
+    archive::mark_finished,
+    container::{ext_from_content_type, sniff_ext},
+    fd::{ProgressState, download_segment},
+    ffmpeg::{encoders, ffmpeg},
+    format_date, format_duration, format_size,
+    gallery::download_gallery,
+    live::record_live,
+    sanitize::{self, sanitize},
+    subtitle::{SubtitleFile, download_subtitles},
+};
+use anyhow::{Context, bail};
+use fast_down::utils::gen_unique_path;
+use reqwest::{Client, ClientBuilder, header};
+use std::{
+    collections::HashSet,
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use tokio::sync::Notify;
+use tracing::{Instrument, info_span, warn};
+
+#[derive(Debug, Clone)]
+pub struct DownloadOptions {
+    pub output_dir: PathBuf,
+    pub threads: usize,
+    /// 把字幕、弹幕作为软字幕封装进视频，此时输出 mkv
+    pub embed_subtitles: bool,
+    /// 直播录制每段的最长时长，`None` 表示不按时长分段
+    pub segment_duration: Option<Duration>,
+    /// 直播录制每段的最大字节数，`None` 表示不按大小分段
+    pub segment_size: Option<u64>,
+    /// 仅音频模式的输出格式，`None` 表示正常下载视频
+    pub audio_format: Option<AudioFormat>,
+    /// 图文作品额外合成一个幻灯片视频
+    pub slideshow: bool,
+}
+
+impl DownloadOptions {
+    pub const DEFAULT_THREADS: usize = 16;
+    pub const DEFAULT_SEGMENT_DURATION: Duration = Duration::from_secs(3600);
+
+    pub fn new() -> anyhow::Result<Self> {
+        let output_dir = dirs::desktop_dir()
+            .or_else(dirs::download_dir)
+            .or_else(|| env::current_dir().ok())
+            .context("找不到下载目录")?;
+        Ok(Self {
+            output_dir,
+            threads: Self::DEFAULT_THREADS,
+            embed_subtitles: false,
+            segment_duration: Some(Self::DEFAULT_SEGMENT_DURATION),
+            segment_size: None,
+            audio_format: None,
+            slideshow: false,
+        })
+    }
+}
+
+/// 仅音频模式的输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    M4a,
+    Mp3,
+    Opus,
+    Flac,
+}
+
+impl AudioFormat {
+    pub const ALL: [Self; 4] = [Self::M4a, Self::Mp3, Self::Opus, Self::Flac];
+
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "m4a" | "aac" => Ok(Self::M4a),
+            "mp3" => Ok(Self::Mp3),
+            "opus" => Ok(Self::Opus),
+            "flac" => Ok(Self::Flac),
+            _ => bail!("不支持的音频格式: {s}"),
+        }
+    }
+
+    pub fn ext(&self) -> &'static str {
+        match self {
+            Self::M4a => "m4a",
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::Flac => "flac",
+        }
+    }
+
+    /// 源音轨能直接放进目标格式时不转码，否则按优先级从 `encoders` 里挑编码器。
+    /// 精简构建的 ffmpeg 可能没有 libmp3lame、libopus
+    fn codec_args(
+        &self,
+        source: Option<AudioCodec>,
+        encoders: &HashSet<String>,
+    ) -> anyhow::Result<&'static [&'static str]> {
+        const COPY: &[&str] = &["-c:a", "copy"];
+        let candidates: &[(&str, &'static [&'static str])] = match (self, source) {
+            (Self::M4a, Some(AudioCodec::Flac)) => &[("aac", &["-c:a", "aac", "-b:a", "320k"])],
+            (Self::M4a, _) => return Ok(COPY),
+            (Self::Mp3, _) => &[
+                ("libmp3lame", &["-c:a", "libmp3lame", "-q:a", "0"]),
+                ("libshine", &["-c:a", "libshine", "-b:a", "320k"]),
+                ("mp3_mf", &["-c:a", "mp3_mf", "-b:a", "320k"]),
+            ],
+            (Self::Opus, _) => &[
+                ("libopus", &["-c:a", "libopus", "-b:a", "192k"]),
+                // 原生 opus 编码器还是实验性的
+                ("opus", &["-c:a", "opus", "-b:a", "192k", "-strict", "-2"]),
+            ],
+            (Self::Flac, Some(AudioCodec::Flac)) => return Ok(COPY),
+            (Self::Flac, _) => &[("flac", &["-c:a", "flac"])],
+        };
+        candidates
+            .iter()
+            .find(|(name, _)| encoders.contains(*name))
+            .map(|(_, args)| *args)
+            .with_context(|| format!("ffmpeg 不支持 {} 编码，请换一种音频格式", self.ext()))
+    }
+
+    /// ogg 封装的 opus 写不进封面
+    fn supports_cover(&self) -> bool {
+        !matches!(self, Self::Opus)
+    }
+}
+
+/// 一个 [`MediaInfo`] 的下载进度，每个流一个 [`ProgressState`]，外加合并进度
+pub struct DownloadState {
+    pub streams: Vec<Arc<ProgressState>>,
+    pub frame: AtomicU64,
+    /// 合并速度 * 1000
+    pub merge_speed: AtomicU64,
+    pub is_finished: AtomicBool,
+    /// 直播已录制的毫秒数
+    pub recorded_ms: AtomicU64,
+    pub recorded_bytes: AtomicU64,
+    /// 直播码率 kbit/s * 1000
+    pub bitrate: AtomicU64,
+    /// 直播已录制的段数
+    pub segments: AtomicU64,
+    stop_requested: AtomicBool,
+    stop_notify: Notify,
+}
+
+impl DownloadState {
+    pub fn new(info: &MediaInfo) -> Arc<Self> {
+        Arc::new(Self {
+            streams: info.streams.iter().map(|_| ProgressState::new()).collect(),
+            frame: AtomicU64::new(0),
+            merge_speed: AtomicU64::new(0),
+            is_finished: AtomicBool::new(false),
+            recorded_ms: AtomicU64::new(0),
+            recorded_bytes: AtomicU64::new(0),
+            bitrate: AtomicU64::new(0),
+            segments: AtomicU64::new(0),
+            stop_requested: AtomicBool::new(false),
+            stop_notify: Notify::new(),
+        })
+    }
+
+    /// 结束直播录制，和取消不同，已录制的文件算作完成
+    pub fn request_stop(&self) {
+        self.stop_requested.store(true, Ordering::Release);
+        self.stop_notify.notify_waiters();
+    }
+
+    pub fn is_stop_requested(&self) -> bool {
+        self.stop_requested.load(Ordering::Acquire)
+    }
+
+    /// 等到 [`Self::request_stop`] 被调用
+    pub async fn stopped(&self) {
+        // 先注册再检查标志，避免错过检查之后、等待之前发出的通知
+        let notified = self.stop_notify.notified();
+        if self.is_stop_requested() {
+            return;
+        }
+        notified.await;
+    }
+
+    pub fn merge_display(&self) -> String {
+        let frame = self.frame.load(Ordering::Relaxed);
+        let merge_speed = self.merge_speed.load(Ordering::Relaxed) as f64 / 1000.;
+        format!("frame: {} | speed: {:.2}x", frame, merge_speed)
+    }
+
+    pub fn live_display(&self) -> String {
+        let recorded = Duration::from_millis(self.recorded_ms.load(Ordering::Relaxed));
+        let bytes = self.recorded_bytes.load(Ordering::Relaxed) as f64;
+        let bitrate = self.bitrate.load(Ordering::Relaxed) as f64 / 1000.;
+        let segments = self.segments.load(Ordering::Relaxed);
+        format!(
+            "{} | {} | {:.0} kbps | 第 {} 段",
+            format_duration(recorded),
+            format_size(bytes),
+            bitrate,
+            segments.max(1)
+        )
+    }
+}
+
+/// 下载 [`MediaInfo`] 中的所有流，用 ffmpeg 合并并写入封面和标签，返回最终文件路径
+pub async fn download(
+    info: MediaInfo,
+    options: DownloadOptions,
+    state: Arc<DownloadState>,
+) -> anyhow::Result<PathBuf> {
+    let _guard = scopeguard::guard((), |_| {
+        state.is_finished.store(true, Ordering::Relaxed);
+    });
+    if info.is_live() {
+        return record_live(&info, &options, &state).await;
+    }
+    if info.is_gallery() {
+        return download_gallery(&info, &options, &state).await;
+    }
+    let client = ClientBuilder::new()
+        .default_headers(info.headers.clone())
+        .build()
+        .context("无法创建客户端")?;
+    let headers = Arc::new(info.headers.clone());
+    // 弹幕按视频分辨率排版，没有视频流时按 1080P
+    let resolution = info
+        .streams
+        .iter()
+        .filter(|s| s.kind != StreamKind::Audio)
+        .find_map(|s| Some((s.format.width?, s.format.height?)))
+        .unwrap_or((1920, 1080));
+    let video_count = info
+        .streams
+        .iter()
+        .filter(|s| s.kind != StreamKind::Audio)
+        .count();
+    let has_flac = info
+        .streams
+        .iter()
+        .any(|s| s.format.audio_codec() == Some(AudioCodec::Flac));
+    let audio_index = info
+        .streams
+        .iter()
+        .position(|s| s.kind == StreamKind::Audio)
+        .unwrap_or(0);
+    let audio_codec = info
+        .streams
+        .get(audio_index)
+        .and_then(|s| s.format.audio_codec());
+    // 先确认 ffmpeg 带了需要的编码器，免得下载完才失败
+    let audio_args = match options.audio_format {
+        Some(format) => {
+            let encoders = encoders().await.context("无法查询 ffmpeg 支持的编码器")?;
+            Some((format, format.codec_args(audio_codec, encoders)?))
+        }
+        None => None,
+    };
+    let mut paths = Vec::with_capacity(info.streams.len());
+    for (stream, progress) in info.streams.iter().zip(&state.streams) {
+        let path = download_segment(
+            &stream.urls(),
+            &info.title,
+            &stream.ext,
+            &options.output_dir,
+            &client,
+            progress,
+            options.threads,
+            headers.clone(),
+        )
+        .await?;
+        paths.push(path);
+    }
+    if paths.is_empty() {
+        bail!("没有可下载的资源");
+    }
+    // 写不进封面的音频格式就不用下载封面了
+    let wants_cover = options.audio_format.is_none_or(|f| f.supports_cover());
+    let cover = match info.thumbnails.first().filter(|_| wants_cover) {
+        Some(thumbnail) => download_cover(thumbnail, &info.title, &options.output_dir, &client)
+            .await
+            .inspect_err(|e| warn!(err = ?e, "封面下载失败"))
+            .ok(),
+        None => None,
+    };
+    if let Some((format, codec_args)) = audio_args {
+        let audio_path = save_audio(
+            &paths[audio_index.min(paths.len() - 1)],
+            cover.as_deref(),
+            format,
+            codec_args,
+            &info.title,
+            &info.metadata,
+            &options.output_dir,
+            &state,
+        )
+        .await?;
+        for path in paths.iter().chain(&cover) {
+            let _ = tokio::fs::remove_file(path).await;
+        }
+        mark_finished(&info).await;
+        return Ok(audio_path);
+    }
+    let subtitles = download_subtitles(
+        &info.subtitles,
+        &info.title,
+        &options.output_dir,
+        &client,
+        resolution,
+    )
+    .await;
+    let subtitles = if options.embed_subtitles {
+        subtitles
+    } else {
+        Vec::new()
+    };
+    // mp4 放不下 ASS 字幕，FLAC 在旧版播放器里也不认，这两种情况改用 mkv
+    let ext = if subtitles.is_empty() && !has_flac {
+        "mp4"
+    } else {
+        "mkv"
+    };
+    let single = paths.len() == 1;
+    if single && subtitles.is_empty() && cover.is_none() {
+        mark_finished(&info).await;
+        return Ok(paths.remove(0));
+    }
+    // 只有一个流时只是封装字幕和封面，最后沿用不带「合并」的文件名
+    let merge_filename = sanitize(format!(
+        "{}{}.{}",
+        sanitize::truncate_to_bytes(&info.title, 230),
+        if single { "" } else { "-合并" },
+        ext
+    ));
+    let merge_path = gen_unique_path(soft_canonicalize::soft_canonicalize(
+        options.output_dir.join(&merge_filename),
+    )?)
+    .await?;
+    let mut args: Vec<OsString> = Vec::new();
+    let inputs: Vec<_> = paths
+        .iter()
+        .chain(subtitles.iter().map(|s| &s.path))
+        .chain(&cover)
+        .collect();
+    for path in &inputs {
+        args.push("-i".into());
+        args.push(path.into());
+    }
+    for i in 0..inputs.len() {
+        args.push("-map".into());
+        args.push(i.to_string().into());
+    }
+    args.extend(subtitle_metadata(&subtitles));
+    args.extend(media_metadata(&info.title, &info.metadata));
+    args.extend(["-c".into(), "copy".into()]);
+    // 封面是最后一个视频流，转成 mjpeg 作为附图，mp4 和 mkv 都支持
+    if cover.is_some() {
+        args.extend([
+            format!("-c:v:{}", video_count).into(),
+            "mjpeg".into(),
+            format!("-disposition:v:{}", video_count).into(),
+            "attached_pic".into(),
+        ]);
+    }
+    args.push("-y".into());
+    args.push(merge_path.clone().into());
+    let span = info_span!("合并音视频", subtitles = subtitles.len());
+    ffmpeg(args, |info| {
+        state.frame.store(info.frame, Ordering::Relaxed);
+        state
+            .merge_speed
+            .store((info.speed * 1000.) as u64, Ordering::Relaxed);
+    })
+    .instrument(span)
+    .await?;
+    for path in paths.iter().chain(&cover) {
+        let _ = tokio::fs::remove_file(path).await;
+    }
+    // 合并时原文件还占着这个名字，删掉之后再改回来
+    let output = if single {
+        let output = gen_unique_path(soft_canonicalize::soft_canonicalize(
+            options.output_dir.join(&merge_filename),
+        )?)
+        .await?;
+        tokio::fs::rename(&merge_path, &output)
+            .await
+            .context("无法重命名输出文件")?;
+        output
+    } else {
+        merge_path
+    };
+    mark_finished(&info).await;
+    Ok(output)
+}
+
+/// 仅音频模式：取出音轨，按需转码，写入封面和标签
+#[allow(clippy::too_many_arguments)]
+async fn save_audio(
+    input: &Path,
+    cover: Option<&Path>,
+    format: AudioFormat,
+    codec_args: &[&str],
+    title: &str,
+    metadata: &Metadata,
+    dir: &Path,
+    state: &DownloadState,
+) -> anyhow::Result<PathBuf> {
+    let filename = sanitize(format!(
+        "{}.{}",
+        sanitize::truncate_to_bytes(title, 230),
+        format.ext()
+    ));
+    let output = gen_unique_path(soft_canonicalize::soft_canonicalize(dir.join(filename))?).await?;
+    let mut args: Vec<OsString> = vec!["-i".into(), input.into()];
+    if let Some(cover) = cover {
+        args.extend(["-i".into(), cover.into()]);
+    }
+    // 抖音是音视频合一的 mp4，也只取第一条音轨
+    args.extend(["-map".into(), "0:a:0".into()]);
+    if cover.is_some() {
+        args.extend([
+            "-map".into(),
+            "1:v:0".into(),
+            "-c:v".into(),
+            "mjpeg".into(),
+            "-disposition:v".into(),
+            "attached_pic".into(),
+        ]);
+    }
+    args.extend(media_metadata(title, metadata));
+    args.extend(codec_args.iter().map(OsString::from));
+    if format == AudioFormat::Mp3 {
+        // 不少播放器只认 ID3v2.3 的封面
+        args.extend(["-id3v2_version".into(), "3".into()]);
+    }
+    args.push("-y".into());
+    args.push(output.clone().into());
+    let span = info_span!("提取音频", format = format.ext());
+    ffmpeg(args, |info| {
+        state.frame.store(info.frame, Ordering::Relaxed);
+        state
+            .merge_speed
+            .store((info.speed * 1000.) as u64, Ordering::Relaxed);
+    })
+    .instrument(span)
+    .await?;
+    Ok(output)
+}
+
+/// 下载封面，保存在视频旁边
+async fn download_cover(
+    thumbnail: &Thumbnail,
+    title: &str,
+    dir: &Path,
+    client: &Client,
+) -> anyhow::Result<PathBuf> {
+    let resp = client
+        .get(thumbnail.url.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+    let content_type = resp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(ext_from_content_type);
+    let bytes = resp.bytes().await?;
+    // 文件头最可靠，其次是 Content-Type，最后才看 URL
+    let ext = sniff_ext(&bytes)
+        .or(content_type)
+        .map(String::from)
+        .or_else(|| {
+            thumbnail
+                .url
+                .path()
+                .rsplit_once('.')
+                .map(|(_, ext)| ext.to_ascii_lowercase())
+        })
+        .filter(|ext| ["jpg", "jpeg", "png", "webp"].contains(&ext.as_str()))
+        .unwrap_or_else(|| "jpg".to_string());
+    let path = gen_unique_path(soft_canonicalize::soft_canonicalize(
+        dir.join(sanitize(format!("{}.cover.{}", title, ext))),
+    )?)
+    .await?;
+    tokio::fs::write(&path, bytes)
+        .await
+        .context("无法保存封面")?;
+    Ok(path)
+}
+
+/// 写入标题、作者、简介和发布日期
+pub(crate) fn media_metadata(title: &str, metadata: &Metadata) -> Vec<OsString> {
+    let mut tags = vec![("title", title.to_string())];
+    if let Some(uploader) = &metadata.uploader {
+        tags.push(("artist", uploader.clone()));
+    }
+    if let Some(description) = &metadata.description {
+        tags.push(("comment", description.clone()));
+        tags.push(("description", description.clone()));
+    }
+    if let Some(time) = metadata.upload_time {
+        tags.push(("date", format_date(time)));
+    }
+    tags.into_iter()
+        .flat_map(|(key, value)| ["-metadata".into(), format!("{}={}", key, value).into()])
+        .collect()
+}
+
+/// 给每条字幕流写上语言和标题
+fn subtitle_metadata(subtitles: &[SubtitleFile]) -> Vec<OsString> {
+    let mut args = Vec::new();
+    for (i, subtitle) in subtitles.iter().enumerate() {
+        args.push(format!("-metadata:s:s:{}", i).into());
+        args.push(format!("language={}", iso639_2(&subtitle.lang)).into());
+        args.push(format!("-metadata:s:s:{}", i).into());
+        args.push(format!("title={}", subtitle.name).into());
+    }
+    args
+}
+
+/// 把 zh-CN、ai-en 这样的语言代码转成 mkv 使用的 ISO 639-2 代码
+fn iso639_2(lang: &str) -> &'static str {
+    let lang = lang.strip_prefix("ai-").unwrap_or(lang);
+    match lang.split(['-', '_']).next().unwrap_or_default() {
+        "zh" => "chi",
+        "en" => "eng",
+        "ja" => "jpn",
+        "ko" => "kor",
+        "es" => "spa",
+        "fr" => "fre",
+        "de" => "ger",
+        "ru" => "rus",
+        "ar" => "ara",
+        "pt" => "por",
+        _ => "und",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audio_format() {
+        assert_eq!(AudioFormat::parse("MP3").unwrap(), AudioFormat::Mp3);
+        assert_eq!(AudioFormat::parse("aac").unwrap(), AudioFormat::M4a);
+        assert!(AudioFormat::parse("wav").is_err());
+        let encoders: HashSet<String> = ["aac", "flac", "opus"].map(String::from).into();
+        assert_eq!(
+            AudioFormat::M4a
+                .codec_args(Some(AudioCodec::Aac), &encoders)
+                .unwrap(),
+            ["-c:a", "copy"]
+        );
+        assert_eq!(
+            AudioFormat::Flac
+                .codec_args(Some(AudioCodec::Flac), &encoders)
+                .unwrap(),
+            ["-c:a", "copy"]
+        );
+        assert_eq!(
+            AudioFormat::Flac.codec_args(None, &encoders).unwrap(),
+            ["-c:a", "flac"]
+        );
+        // 没有 libopus 时退回原生编码器，mp3 没有原生编码器
+        assert_eq!(
+            AudioFormat::Opus.codec_args(None, &encoders).unwrap()[1],
+            "opus"
+        );
+        assert!(AudioFormat::Mp3.codec_args(None, &encoders).is_err());
+    }
+
+    #[test]
+    fn test_iso639_2() {
+        assert_eq!(iso639_2("zh-Hans"), "chi");
+        assert_eq!(iso639_2("ai-en"), "eng");
+        assert_eq!(iso639_2("ja"), "jpn");
+        assert_eq!(iso639_2("danmaku"), "und");
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    MediaInfo, StreamKind,
+    archive::mark_finished,
+    download::{DownloadOptions, DownloadState, media_metadata},
+    fd::download_segment,
+    ffmpeg::ffmpeg,
+    sanitize::{self, sanitize},
+};
+use anyhow::{Context, bail};
+use fast_down::utils::gen_unique_path;
+use futures::{StreamExt, TryStreamExt};
+use reqwest::ClientBuilder;
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::{Arc, atomic::Ordering},
+};
+use tracing::{Instrument, info_span};
+
+/// 幻灯片里每张图片停留的秒数
+const SLIDE_SECONDS: u32 = 3;
+/// 同时下载的图片数
+const IMAGE_CONCURRENCY: usize = 4;
+
+/// 下载图文作品：所有图片和背景音乐放进以标题命名的文件夹，
+/// 需要时再合成幻灯片视频。返回视频路径，没有合成时返回文件夹
+pub async fn download_gallery(
+    info: &MediaInfo,
+    options: &DownloadOptions,
+    state: &DownloadState,
+) -> anyhow::Result<PathBuf> {
+    let client = ClientBuilder::new()
+        .default_headers(info.headers.clone())
+        .build()
+        .context("无法创建客户端")?;
+    let headers = Arc::new(info.headers.clone());
+    let folder = gen_unique_path(soft_canonicalize::soft_canonicalize(
+        options
+            .output_dir
+            .join(sanitize(sanitize::truncate_to_bytes(&info.title, 230))),
+    )?)
+    .await?;
+    tokio::fs::create_dir_all(&folder)
+        .await
+        .context("无法创建图文文件夹")?;
+    let image_count = info
+        .streams
+        .iter()
+        .filter(|s| s.kind == StreamKind::Image)
+        .count();
+    let width = image_count.to_string().len().max(2);
+    let mut index = 0;
+    let jobs = info
+        .streams
+        .iter()
+        .zip(&state.streams)
+        .map(|(stream, progress)| {
+            let name = match stream.kind {
+                StreamKind::Image => {
+                    index += 1;
+                    format!("{:0width$}", index)
+                }
+                _ => "背景音乐".to_string(),
+            };
+            let (folder, client, headers) = (&folder, &client, headers.clone());
+            async move {
+                let path = download_segment(
+                    &stream.urls(),
+                    &name,
+                    &stream.ext,
+                    folder,
+                    client,
+                    progress,
+                    // 图片很小，多线程没有意义
+                    1,
+                    headers,
+                )
+                .await?;
+                anyhow::Ok((stream.kind, path))
+            }
+        });
+    // 按顺序收集结果，并发数有限，免得一次发出几十个请求
+    let paths: Vec<_> = futures::stream::iter(jobs)
+        .buffered(IMAGE_CONCURRENCY)
+        .try_collect()
+        .await?;
+    let mut images = Vec::with_capacity(image_count);
+    let mut audio = None;
+    for (kind, path) in paths {
+        match kind {
+            StreamKind::Image => images.push(path),
+            _ => audio = Some(path),
+        }
+    }
+    if images.is_empty() {
+        bail!("图文作品中没有图片");
+    }
+    if !options.slideshow {
+        mark_finished(info).await;
+        return Ok(folder);
+    }
+    let filename = sanitize(format!(
+        "{}.mp4",
+        sanitize::truncate_to_bytes(&info.title, 230)
+    ));
+    let output = gen_unique_path(folder.join(filename)).await?;
+    let mut args = slideshow_args(&images, audio.as_deref());
+    args.extend(media_metadata(&info.title, &info.metadata));
+    args.push("-y".into());
+    args.push(output.clone().into());
+    let span = info_span!("合成幻灯片", images = images.len());
+    ffmpeg(args, |progress| {
+        state.frame.store(progress.frame, Ordering::Relaxed);
+        state
+            .merge_speed
+            .store((progress.speed * 1000.) as u64, Ordering::Relaxed);
+    })
+    .instrument(span)
+    .await?;
+    mark_finished(info).await;
+    Ok(output)
+}
+
+/// 每张图片缩放到竖屏 1080x1920 并补黑边后依次拼接，
+/// 背景音乐比画面短时循环播放，视频长度以图片为准
+fn slideshow_args(images: &[PathBuf], audio: Option<&Path>) -> Vec<OsString> {
+    let mut args: Vec<OsString> = Vec::new();
+    for image in images {
+        args.extend([
+            "-loop".into(),
+            "1".into(),
+            "-framerate".into(),
+            "30".into(),
+            "-t".into(),
+            SLIDE_SECONDS.to_string().into(),
+            "-i".into(),
+            image.into(),
+        ]);
+    }
+    if let Some(audio) = audio {
+        args.extend([
+            "-stream_loop".into(),
+            "-1".into(),
+            "-i".into(),
+            audio.into(),
+        ]);
+    }
+    let mut filter = String::new();
+    for i in 0..images.len() {
+        filter.push_str(&format!(
+            "[{i}:v]scale=1080:1920:force_original_aspect_ratio=decrease,\
+             pad=1080:1920:(ow-iw)/2:(oh-ih)/2,setsar=1,format=yuv420p[v{i}];"
+        ));
+    }
+    for i in 0..images.len() {
+        filter.push_str(&format!("[v{i}]"));
+    }
+    filter.push_str(&format!("concat=n={}:v=1:a=0[v]", images.len()));
+    args.extend([
+        "-filter_complex".into(),
+        filter.into(),
+        "-map".into(),
+        "[v]".into(),
+    ]);
+    if audio.is_some() {
+        args.extend([
+            "-map".into(),
+            format!("{}:a:0", images.len()).into(),
+            "-c:a".into(),
+            "aac".into(),
+            "-b:a".into(),
+            "192k".into(),
+        ]);
+    }
+    let total = SLIDE_SECONDS as usize * images.len();
+    args.extend([
+        "-c:v".into(),
+        "libx264".into(),
+        "-r".into(),
+        "30".into(),
+        "-t".into(),
+        total.to_string().into(),
+    ]);
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slideshow_args() {
+        let images = [PathBuf::from("01.jpg"), PathBuf::from("02.webp")];
+        let args = slideshow_args(&images, Some(Path::new("背景音乐.mp3")));
+        let args: Vec<_> = args.iter().map(|a| a.to_str().unwrap()).collect();
+        assert_eq!(args.iter().filter(|a| **a == "-i").count(), 3);
+        let filter = args[args.iter().position(|a| *a == "-filter_complex").unwrap() + 1];
+        assert!(filter.ends_with("[v0][v1]concat=n=2:v=1:a=0[v]"));
+        assert!(args.windows(2).any(|w| w == ["-map", "2:a:0"]));
+        assert!(args.ends_with(&["-t", "6"]));
+
+        let args = slideshow_args(&images[..1], None);
+        assert!(!args.iter().any(|a| a == "-stream_loop"));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    MediaInfo,
+    bili_live::{extract_room_id, get_live_stream},
+    download::{DownloadOptions, DownloadState},
+    ffmpeg::ffmpeg,
+    sanitize::{self, sanitize},
+};
+use anyhow::{Context, bail};
+use fast_down::utils::gen_unique_path;
+use reqwest::{ClientBuilder, header};
+use std::{
+    ffi::OsString,
+    path::PathBuf,
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+use tracing::{Instrument, info, info_span, warn};
+
+/// 连续重连失败这么多次后放弃
+const MAX_RETRIES: u32 = 10;
+const RETRY_DELAY: Duration = Duration::from_secs(3);
+
+/// 录制直播直到下播或用户停止，按时长、大小分段，断流后自动重连，返回第一段的路径
+pub async fn record_live(
+    info: &MediaInfo,
+    options: &DownloadOptions,
+    state: &DownloadState,
+) -> anyhow::Result<PathBuf> {
+    let room_url = info.streams.first().context("没有直播流")?.url.as_str();
+    let room_id = extract_room_id(room_url).context("无效的直播间地址")?;
+    let client = ClientBuilder::new()
+        .default_headers(info.headers.clone())
+        .build()
+        .context("无法创建客户端")?;
+    // 直播流地址自带签名，cookie 不能放进命令行，否则别的进程能看到 SESSDATA
+    let headers: String = info
+        .headers
+        .iter()
+        .filter(|(k, _)| **k != header::COOKIE)
+        .filter_map(|(k, v)| Some(format!("{}: {}\r\n", k, v.to_str().ok()?)))
+        .collect();
+    let mut paths = Vec::new();
+    let mut retries = 0;
+    loop {
+        if state.is_stop_requested() {
+            break;
+        }
+        let stream = match get_live_stream(room_id, &client).await {
+            Ok(Some(stream)) => stream,
+            Ok(None) => {
+                info!("直播已结束");
+                break;
+            }
+            Err(e) if retries < MAX_RETRIES => {
+                retries += 1;
+                warn!(err = ?e, retries, "获取直播流失败，稍后重试");
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let segment = state.segments.fetch_add(1, Ordering::Relaxed) + 1;
+        let filename = sanitize(format!(
+            "{}-{:03}.{}",
+            sanitize::truncate_to_bytes(&info.title, 230),
+            segment,
+            stream.ext
+        ));
+        let path = gen_unique_path(soft_canonicalize::soft_canonicalize(
+            options.output_dir.join(filename),
+        )?)
+        .await?;
+        let mut args: Vec<OsString> = vec![
+            // 网络卡住 15 秒没有数据就断开重连
+            "-rw_timeout".into(),
+            "15000000".into(),
+            "-headers".into(),
+            headers.clone().into(),
+            "-i".into(),
+            stream.url.as_str().into(),
+            "-map".into(),
+            "0".into(),
+            "-c".into(),
+            "copy".into(),
+        ];
+        if let Some(duration) = options.segment_duration {
+            args.extend(["-t".into(), duration.as_secs().to_string().into()]);
+        }
+        if let Some(size) = options.segment_size {
+            args.extend(["-fs".into(), size.to_string().into()]);
+        }
+        args.push("-y".into());
+        args.push(path.clone().into());
+        // 已录制的部分作为基数，进度只统计当前这一段
+        let base_ms = state.recorded_ms.load(Ordering::Relaxed);
+        let base_bytes = state.recorded_bytes.load(Ordering::Relaxed);
+        let started = Instant::now();
+        let span = info_span!("录制直播", segment, ext = stream.ext);
+        let recording = ffmpeg(args, |progress| {
+            state.recorded_ms.store(
+                base_ms + progress.out_time.as_millis() as u64,
+                Ordering::Relaxed,
+            );
+            state
+                .recorded_bytes
+                .store(base_bytes + progress.total_size, Ordering::Relaxed);
+            state
+                .bitrate
+                .store((progress.bitrate * 1000.) as u64, Ordering::Relaxed);
+        })
+        .instrument(span);
+        // 停止时直接结束 ffmpeg，flv 和 ts 即使被打断也能正常播放
+        let result = tokio::select! {
+            result = recording => Some(result),
+            _ = state.stopped() => None,
+        };
+        let size = tokio::fs::metadata(&path)
+            .await
+            .map(|m| m.len())
+            .unwrap_or(0);
+        if size > 0 {
+            paths.push(path);
+        } else {
+            let _ = tokio::fs::remove_file(&path).await;
+            state.segments.fetch_sub(1, Ordering::Relaxed);
+        }
+        match result {
+            None => break,
+            // 录满一段后接着录下一段
+            Some(Ok(())) => retries = 0,
+            Some(Err(e)) => {
+                // 录了一段时间才断开的算作断流，重新计数
+                if started.elapsed() > Duration::from_secs(60) {
+                    retries = 0;
+                }
+                if retries >= MAX_RETRIES {
+                    return Err(e).context("直播录制失败");
+                }
+                retries += 1;
+                warn!(err = ?e, retries, "直播断流，稍后重连");
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+        }
+    }
+    match paths.into_iter().next() {
+        Some(path) => Ok(path),
+        None => bail!("没有录制到任何内容"),
+    }
+}
@@ -0,0 +1,447 @@
+use crate::{AudioCodec, FormatPolicy, VideoCodec, archive::is_downloaded};
+use anyhow::{Context, bail};
+use reqwest::{Url, header::HeaderMap};
+use std::time::Duration;
+
+/// 提取结果：单个媒体，或者需要用户挑选的列表（分 P、合集等）
+#[derive(Debug, Clone)]
+pub enum Extracted {
+    Media(MediaInfo),
+    Playlist(Playlist),
+}
+
+#[derive(Debug, Clone)]
+pub struct Playlist {
+    pub id: String,
+    pub title: String,
+    pub entries: Vec<PlaylistEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistEntry {
+    /// 和解析出来的 [`MediaInfo::id`] 相同，用于判断是否已经下载过
+    pub id: String,
+    /// 交回提取器再次解析用的输入
+    pub input: String,
+    pub title: String,
+    pub duration: Option<Duration>,
+    /// 发布时间，unix 时间戳（秒）
+    pub upload_time: Option<u64>,
+    pub kind: EntryKind,
+}
+
+/// 列表项是视频还是图文
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EntryKind {
+    #[default]
+    Video,
+    Gallery,
+}
+
+impl EntryKind {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "video" | "视频" => Ok(Self::Video),
+            "image" | "note" | "gallery" | "图文" => Ok(Self::Gallery),
+            _ => bail!("不支持的作品类型: {s}"),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Video => "视频",
+            Self::Gallery => "图文",
+        }
+    }
+}
+
+/// 批量下载时按发布时间、数量和下载记录筛选列表
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlaylistFilter {
+    /// 只要这个时间及之后发布的，unix 时间戳（秒）
+    pub since: Option<u64>,
+    /// 只要这个时间之前发布的，unix 时间戳（秒）
+    pub until: Option<u64>,
+    /// 最多选多少项，按列表顺序
+    pub max_count: Option<usize>,
+    pub skip_downloaded: bool,
+    /// 只要视频或只要图文，`None` 表示都要
+    pub kind: Option<EntryKind>,
+}
+
+impl PlaylistFilter {
+    /// 没有发布时间的项不按时间筛选
+    pub fn matches(&self, entry: &PlaylistEntry) -> bool {
+        if let Some(time) = entry.upload_time
+            && (self.since.is_some_and(|since| time < since)
+                || self.until.is_some_and(|until| time >= until))
+        {
+            return false;
+        }
+        if self.kind.is_some_and(|kind| kind != entry.kind) {
+            return false;
+        }
+        !(self.skip_downloaded && is_downloaded(&entry.id))
+    }
+
+    /// 返回每一项是否被选中
+    pub fn select(&self, entries: &[PlaylistEntry]) -> Vec<bool> {
+        let mut remaining = self.max_count.unwrap_or(usize::MAX);
+        entries
+            .iter()
+            .map(|entry| {
+                let selected = remaining > 0 && self.matches(entry);
+                if selected {
+                    remaining -= 1;
+                }
+                selected
+            })
+            .collect()
+    }
+}
+
+/// 提取器的解析结果，不依赖任何 UI，可以直接交给下载层使用
+#[derive(Debug, Clone)]
+pub struct MediaInfo {
+    pub id: String,
+    pub title: String,
+    /// 要下载的流
+    pub streams: Vec<Stream>,
+    /// 所有可选的流，站点不提供选择时为空
+    pub formats: Vec<Stream>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub subtitles: Vec<Subtitle>,
+    pub metadata: Metadata,
+    /// 下载这些资源时需要带上的请求头（Referer、User-Agent 等）
+    pub headers: HeaderMap,
+    /// 多 P 视频中的一 P，所有分 P 都下载完后整个视频也记为已下载
+    pub part_of: Option<PartOf>,
+    /// 解析时的输入，由 [`AllDown`](crate::AllDown) 填写，重试时用来重新解析
+    pub input: String,
+}
+
+/// 所属的多 P 视频，`id` 和列表里的 [`PlaylistEntry::id`] 相同
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartOf {
+    pub id: String,
+    /// 所有分 P 的 [`MediaInfo::id`]
+    pub parts: Vec<String>,
+}
+
+impl MediaInfo {
+    /// 按策略从 `formats` 中重新挑选要下载的流
+    pub fn apply_policy(&mut self, policy: &FormatPolicy) {
+        if !self.formats.is_empty() {
+            let indices = policy.select(&self.formats);
+            self.select_formats(&indices);
+        }
+    }
+
+    /// 直播没有结束时间，交给录制而不是下载
+    pub fn is_live(&self) -> bool {
+        self.streams.iter().any(|s| s.kind == StreamKind::Live)
+    }
+
+    /// 图文作品：一组图片，可能带背景音乐
+    pub fn is_gallery(&self) -> bool {
+        self.streams.iter().any(|s| s.kind == StreamKind::Image)
+    }
+
+    /// 仅音频模式：有单独的音频流时丢掉视频流，音视频合一时保留，交给 ffmpeg 提取
+    pub fn keep_audio_only(&mut self) {
+        if self.streams.iter().any(|s| s.kind == StreamKind::Audio) {
+            self.streams.retain(|s| s.kind == StreamKind::Audio);
+        }
+    }
+
+    /// 换上重新解析得到的地址，保留原来挑选的流。
+    /// CDN 地址带签名，过一段时间就会失效，重试前需要刷新
+    pub fn refresh_urls(&mut self, fresh: MediaInfo) -> anyhow::Result<()> {
+        let candidates = if fresh.formats.is_empty() {
+            &fresh.streams
+        } else {
+            &fresh.formats
+        };
+        let mut used = vec![false; candidates.len()];
+        for stream in &mut self.streams {
+            let i = (0..candidates.len())
+                .find(|&i| {
+                    let c = &candidates[i];
+                    !used[i]
+                        && c.kind == stream.kind
+                        && c.format.id == stream.format.id
+                        && c.format.codecs == stream.format.codecs
+                })
+                .with_context(|| format!("重新解析后找不到原来的{}流", stream.kind.label()))?;
+            used[i] = true;
+            stream.url = candidates[i].url.clone();
+            stream.mirrors = candidates[i].mirrors.clone();
+        }
+        self.formats = fresh.formats;
+        self.thumbnails = fresh.thumbnails;
+        self.subtitles = fresh.subtitles;
+        self.headers = fresh.headers;
+        Ok(())
+    }
+
+    /// 用 `formats` 中指定下标的流替换要下载的流
+    pub fn select_formats(&mut self, indices: &[usize]) {
+        self.streams = indices
+            .iter()
+            .filter_map(|&i| self.formats.get(i).cloned())
+            .collect();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamKind {
+    /// 只有画面
+    Video,
+    /// 只有声音
+    Audio,
+    /// 音视频已经合在一起
+    Muxed,
+    /// 直播，url 是直播间地址，录制时才解析真实的流地址
+    Live,
+    /// 图文作品中的一张图片
+    Image,
+}
+
+impl StreamKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StreamKind::Video => "视频",
+            StreamKind::Audio => "音频",
+            StreamKind::Muxed => "视频",
+            StreamKind::Live => "直播",
+            StreamKind::Image => "图片",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Stream {
+    pub kind: StreamKind,
+    pub url: Url,
+    /// 同一个文件的备用地址（其他 CDN 节点），主地址出错时切换
+    pub mirrors: Vec<Url>,
+    pub ext: String,
+    pub format: Format,
+}
+
+impl Stream {
+    pub fn new(kind: StreamKind, url: Url, ext: impl Into<String>) -> Self {
+        Self {
+            kind,
+            url,
+            mirrors: Vec::new(),
+            ext: ext.into(),
+            format: Format::default(),
+        }
+    }
+
+    pub fn with_mirrors(mut self, mirrors: Vec<Url>) -> Self {
+        self.mirrors = mirrors;
+        self
+    }
+
+    /// 主地址在前，备用地址在后
+    pub fn urls(&self) -> Vec<Url> {
+        let mut urls = vec![self.url.clone()];
+        urls.extend(self.mirrors.iter().filter(|u| **u != self.url).cloned());
+        urls
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// 流的清晰度、编码等信息，站点没给的字段为 `None`
+#[derive(Debug, Clone, Default)]
+pub struct Format {
+    /// 站点内部的格式 id，如 B 站的 qn
+    pub id: String,
+    /// 给人看的清晰度名，如「1080P 高清」
+    pub label: Option<String>,
+    pub codecs: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// 比特/秒
+    pub bandwidth: Option<u64>,
+    pub frame_rate: Option<f64>,
+}
+
+impl Format {
+    pub fn video_codec(&self) -> Option<VideoCodec> {
+        self.codecs.as_deref().and_then(VideoCodec::from_codecs)
+    }
+
+    pub fn audio_codec(&self) -> Option<AudioCodec> {
+        self.codecs.as_deref().and_then(AudioCodec::from_codecs)
+    }
+
+    /// 形如「1080P 高清 | 1920x1080 | AVC | 30fps | 2512 kbps」
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        parts.extend(self.label.clone());
+        if let (Some(w), Some(h)) = (self.width, self.height) {
+            parts.push(format!("{}x{}", w, h));
+        }
+        match (self.video_codec(), &self.codecs) {
+            (Some(codec), _) => parts.push(codec.label().to_string()),
+            (None, Some(codecs)) => parts.push(codecs.clone()),
+            (None, None) => {}
+        }
+        if let Some(fps) = self.frame_rate {
+            parts.push(format!("{:.0}fps", fps));
+        }
+        if let Some(bandwidth) = self.bandwidth {
+            parts.push(format!("{} kbps", bandwidth / 1000));
+        }
+        if parts.is_empty() {
+            parts.push(self.id.clone());
+        }
+        parts.join(" | ")
+    }
+}
+
+/// 合并时写入文件的标签，站点没给的字段为 `None`
+#[derive(Debug, Clone, Default)]
+pub struct Metadata {
+    pub uploader: Option<String>,
+    pub description: Option<String>,
+    /// 发布时间，unix 时间戳（秒）
+    pub upload_time: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Thumbnail {
+    pub url: Url,
+}
+
+#[derive(Debug, Clone)]
+pub struct Subtitle {
+    /// 语言代码，例如 zh-CN
+    pub lang: String,
+    /// 给人看的名字，例如「中文（自动生成）」
+    pub name: String,
+    pub url: Url,
+    pub ext: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, upload_time: Option<u64>) -> PlaylistEntry {
+        PlaylistEntry {
+            id: id.to_string(),
+            input: id.to_string(),
+            title: id.to_string(),
+            duration: None,
+            upload_time,
+            kind: EntryKind::Video,
+        }
+    }
+
+    #[test]
+    fn test_stream_urls() {
+        let url = |s: &str| Url::parse(s).unwrap();
+        let stream = Stream::new(StreamKind::Video, url("https://a.com/v.m4s"), "mp4")
+            .with_mirrors(vec![
+                url("https://b.com/v.m4s"),
+                url("https://a.com/v.m4s"),
+                url("https://c.com/v.m4s"),
+            ]);
+        assert_eq!(
+            stream.urls(),
+            vec![
+                url("https://a.com/v.m4s"),
+                url("https://b.com/v.m4s"),
+                url("https://c.com/v.m4s"),
+            ]
+        );
+        let stream = Stream::new(StreamKind::Audio, url("https://a.com/a.m4s"), "m4a");
+        assert_eq!(stream.urls(), vec![url("https://a.com/a.m4s")]);
+    }
+
+    #[test]
+    fn test_playlist_filter() {
+        let entries = vec![
+            entry("a", Some(300)),
+            entry("b", Some(200)),
+            entry("c", None),
+            entry("d", Some(100)),
+        ];
+        let filter = PlaylistFilter {
+            since: Some(150),
+            until: Some(300),
+            ..Default::default()
+        };
+        assert_eq!(filter.select(&entries), vec![false, true, true, false]);
+        let filter = PlaylistFilter {
+            max_count: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(filter.select(&entries), vec![true, true, false, false]);
+
+        let mut entries = entries;
+        entries[1].kind = EntryKind::Gallery;
+        let filter = PlaylistFilter {
+            kind: Some(EntryKind::Gallery),
+            ..Default::default()
+        };
+        assert_eq!(filter.select(&entries), vec![false, true, false, false]);
+        let filter = PlaylistFilter {
+            kind: Some(EntryKind::Video),
+            max_count: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(filter.select(&entries), vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn test_refresh_urls() {
+        let stream = |kind, id: &str, url: &str| {
+            Stream::new(kind, Url::parse(url).unwrap(), "mp4").with_format(Format {
+                id: id.to_string(),
+                ..Default::default()
+            })
+        };
+        let info = |formats: Vec<Stream>| MediaInfo {
+            id: "BV17x411w7KC".into(),
+            title: "标题".into(),
+            streams: Vec::new(),
+            formats,
+            thumbnails: Vec::new(),
+            subtitles: Vec::new(),
+            metadata: Metadata::default(),
+            headers: HeaderMap::new(),
+            part_of: None,
+            input: "BV17x411w7KC".into(),
+        };
+        let mut old = info(vec![
+            stream(StreamKind::Video, "80", "https://a.com/old-80.m4s"),
+            stream(StreamKind::Video, "64", "https://a.com/old-64.m4s"),
+            stream(StreamKind::Audio, "30280", "https://a.com/old-a.m4s"),
+        ]);
+        old.select_formats(&[1, 2]);
+        let fresh = info(vec![
+            stream(StreamKind::Video, "80", "https://a.com/new-80.m4s"),
+            stream(StreamKind::Video, "64", "https://a.com/new-64.m4s"),
+            stream(StreamKind::Audio, "30280", "https://a.com/new-a.m4s"),
+        ]);
+        old.refresh_urls(fresh.clone()).unwrap();
+        let urls: Vec<_> = old.streams.iter().map(|s| s.url.as_str()).collect();
+        assert_eq!(
+            urls,
+            ["https://a.com/new-64.m4s", "https://a.com/new-a.m4s"]
+        );
+
+        let mut fresh = fresh;
+        fresh.formats.remove(1);
+        assert!(old.refresh_urls(fresh).is_err());
+    }
+}
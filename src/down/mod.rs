@@ -1,59 +1,236 @@
-use crate::{bilibili::BiliDown, douyin::DouyinDown};
-use gpui::{AnyView, Task, Window};
-use tracing::error;
+use crate::{bili_live::BiliLive, bilibili::BiliDown, douyin::DouyinDown};
+use anyhow::bail;
+use async_trait::async_trait;
+use std::fmt;
+use tracing::{Instrument, Span, error, info_span};
+use tracing_error::SpanTrace;
 
+pub mod archive;
+pub mod bili_list;
+pub mod bili_live;
+pub mod bili_login;
 pub mod bilibili;
 pub mod douyin;
+pub mod douyin_list;
+pub mod download;
+pub mod gallery;
+pub mod live;
+pub mod media;
+pub mod policy;
+pub mod queue;
+pub mod subtitle;
 
-pub trait Parser: Send + Sync {
-    fn parse(
-        &self,
-        input: &str,
-        window: &mut Window,
-        cx: &mut gpui::App,
-    ) -> Task<anyhow::Result<Option<AnyView>>>;
+pub use media::*;
+pub use policy::*;
+
+/// 站点提取器，只负责把输入解析成 [`MediaInfo`]，不涉及下载和界面
+#[async_trait]
+pub trait Extractor: Send + Sync {
+    /// 站点名，用于错误提示
+    fn name(&self) -> &'static str;
+
+    /// 输入是否归这个提取器处理，输入可能是完整 URL，也可能是 BV 号这类裸 id
+    fn matches(&self, input: &str) -> bool;
+
+    async fn extract(&self, input: &str) -> anyhow::Result<Option<Extracted>>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ExtractError {
+    #[error("不支持的链接: {0}")]
+    Unsupported(String),
+    #[error("没有解析到可下载的内容")]
+    Empty,
+    #[error("{site} 解析失败: {reason}")]
+    Failed {
+        site: &'static str,
+        reason: String,
+        /// 完整的错误链和 span trace，给用户展开查看
+        detail: String,
+    },
+}
+
+impl ExtractError {
+    /// `err` 经过 `traced` 时用出错处的 span trace，否则用当前的
+    pub fn failed(site: &'static str, err: &anyhow::Error) -> Self {
+        let span_trace = match err.chain().find_map(|e| e.downcast_ref::<Traced>()) {
+            Some(traced) => traced.span_trace.clone(),
+            None => SpanTrace::capture(),
+        };
+        Self::Failed {
+            site,
+            reason: format!("{err:#}"),
+            detail: format!("{err:?}\n\n{span_trace}"),
+        }
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        match self {
+            Self::Failed { detail, .. } => Some(detail),
+            _ => None,
+        }
+    }
+}
+
+/// 带着出错处 span trace 的错误，显示和错误链都和原错误一样
+#[derive(Debug)]
+struct Traced {
+    error: anyhow::Error,
+    span_trace: SpanTrace,
+}
+
+impl fmt::Display for Traced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for Traced {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// 在 `span` 里执行一步解析，出错时在 span 内捕获 span trace 并随错误带出。
+/// 嵌套使用时保留最里层的
+pub(crate) async fn traced<T>(
+    span: Span,
+    fut: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    fut.instrument(span.clone()).await.map_err(|error| {
+        if error.chain().any(|e| e.is::<Traced>()) {
+            return error;
+        }
+        let span_trace = span.in_scope(SpanTrace::capture);
+        Traced { error, span_trace }.into()
+    })
 }
 
 pub struct AllDown {
-    downs: Vec<Box<dyn Parser>>,
+    downs: Vec<Box<dyn Extractor>>,
 }
 
 impl AllDown {
-    pub fn new(downs: Vec<Box<dyn Parser>>) -> Self {
+    pub fn new(downs: Vec<Box<dyn Extractor>>) -> Self {
         Self { downs }
     }
+
+    /// 支持的所有站点，`douyin_third_party` 见 [`DouyinDown::new`]
+    pub fn all_sites(douyin_third_party: bool) -> Self {
+        Self::new(vec![
+            Box::new(BiliLive::new()),
+            Box::new(BiliDown::new()),
+            Box::new(DouyinDown::new(douyin_third_party)),
+        ])
+    }
+
+    /// 解析列表中的单项，收藏夹、合集里的多 P 视频会再展开一层
+    pub async fn extract_media(&self, input: &str) -> anyhow::Result<Vec<MediaInfo>> {
+        let playlist = match self.extract(input).await? {
+            Some(Extracted::Media(info)) => return Ok(vec![info]),
+            Some(Extracted::Playlist(playlist)) => playlist,
+            None => return Err(ExtractError::Empty.into()),
+        };
+        let mut infos = Vec::with_capacity(playlist.entries.len());
+        for entry in playlist.entries {
+            match self.extract(&entry.input).await? {
+                Some(Extracted::Media(info)) => infos.push(info),
+                Some(Extracted::Playlist(_)) => bail!("列表中不能再嵌套列表: {}", entry.input),
+                None => return Err(ExtractError::Empty.into()),
+            }
+        }
+        Ok(infos)
+    }
 }
 
-impl Parser for AllDown {
-    fn parse(
-        &self,
-        input: &str,
-        window: &mut Window,
-        cx: &mut gpui::App,
-    ) -> Task<anyhow::Result<Option<AnyView>>> {
-        let mut tasks = Vec::with_capacity(self.downs.len());
-        for down in &self.downs {
-            tasks.push(down.parse(input, window, cx));
+#[async_trait]
+impl Extractor for AllDown {
+    fn name(&self) -> &'static str {
+        "全部"
+    }
+
+    fn matches(&self, input: &str) -> bool {
+        self.downs.iter().any(|down| down.matches(input))
+    }
+
+    async fn extract(&self, input: &str) -> anyhow::Result<Option<Extracted>> {
+        let input = input.trim();
+        let mut matched = self
+            .downs
+            .iter()
+            .filter(|down| down.matches(input))
+            .peekable();
+        if matched.peek().is_none() {
+            return Err(ExtractError::Unsupported(input.to_string()).into());
         }
-        cx.spawn(async move |_| {
-            for task in tasks {
-                match task.await {
-                    Ok(Some(view)) => return Ok(Some(view)),
-                    Ok(None) => {}
-                    Err(e) => error!(err = ?e, "Error parsing input"),
+        let mut last_err = None;
+        for down in matched {
+            let span = info_span!("extract", site = down.name());
+            match traced(span, down.extract(input)).await {
+                Ok(Some(Extracted::Media(mut info))) => {
+                    info.input = input.to_string();
+                    return Ok(Some(Extracted::Media(info)));
+                }
+                Ok(Some(extracted)) => return Ok(Some(extracted)),
+                Ok(None) => {}
+                Err(e) => {
+                    error!(err = ?e, "Error parsing input");
+                    last_err = Some(ExtractError::failed(down.name(), &e));
                 }
             }
-            Ok(None)
-        })
+        }
+        Err(last_err.unwrap_or(ExtractError::Empty).into())
     }
 }
 
-lazy_static::lazy_static! {
-    pub static ref ALL_DOWN: AllDown = {
-        let downs: Vec<Box<dyn Parser>> = vec![
-            Box::new(BiliDown::new()),
-            Box::new(DouyinDown::new()),
-        ];
-        AllDown::new(downs)
-    };
+/// 从分享文案里找出第一个链接，如「【标题】 https://b23.tv/abc」
+pub fn find_url(text: &str) -> Option<&str> {
+    lazy_static::lazy_static! {
+        static ref URL_REGEX: regex::Regex =
+            regex::Regex::new(r"(?i)https?://[A-Za-z0-9\-._~:/?#\[\]@!$&*+,;=%]+").unwrap();
+    }
+    URL_REGEX
+        .find(text)
+        .map(|m| m.as_str().trim_end_matches(['.', ',', '!', '?']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_url() {
+        assert_eq!(
+            find_url("【标题】 https://b23.tv/abc 快来看"),
+            Some("https://b23.tv/abc")
+        );
+        assert_eq!(
+            find_url("看看这个https://www.bilibili.com/video/BV17x411w7KC?p=2，不错"),
+            Some("https://www.bilibili.com/video/BV17x411w7KC?p=2")
+        );
+        assert_eq!(find_url("BV17x411w7KC"), None);
+    }
+
+    #[test]
+    fn test_traced() {
+        use anyhow::Context;
+        let err = crate::TOKIO_RT
+            .block_on(async {
+                let inner = traced(info_span!("内层"), async {
+                    Err::<(), _>(anyhow::anyhow!("根因"))
+                });
+                traced(info_span!("外层"), async {
+                    inner.await.context("外层出错")
+                })
+                .await
+            })
+            .unwrap_err();
+        // 错误链不变，只保留最里层捕获的 span trace
+        assert_eq!(format!("{err:#}"), "外层出错: 根因");
+        assert_eq!(err.chain().filter(|e| e.is::<Traced>()).count(), 1);
+        let ExtractError::Failed { reason, .. } = ExtractError::failed("测试", &err) else {
+            unreachable!()
+        };
+        assert_eq!(reason, "外层出错: 根因");
+    }
 }
@@ -0,0 +1,317 @@
+use crate::{Stream, StreamKind};
+use anyhow::bail;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoCodec {
+    Avc,
+    Hevc,
+    Av1,
+}
+
+impl VideoCodec {
+    /// 从 `avc1.640032`、`hev1.1.6.L150.90`、`av01.0.08M.08` 这样的 codecs 字符串识别编码
+    pub fn from_codecs(codecs: &str) -> Option<Self> {
+        let codecs = codecs.to_ascii_lowercase();
+        if codecs.starts_with("avc") {
+            Some(Self::Avc)
+        } else if codecs.starts_with("hev") || codecs.starts_with("hvc") {
+            Some(Self::Hevc)
+        } else if codecs.starts_with("av01") {
+            Some(Self::Av1)
+        } else {
+            None
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Avc => "AVC",
+            Self::Hevc => "HEVC",
+            Self::Av1 => "AV1",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioCodec {
+    Aac,
+    /// B 站的 Hi-Res 无损
+    Flac,
+    /// 杜比全景声，E-AC-3
+    Dolby,
+}
+
+impl AudioCodec {
+    /// 从 `mp4a.40.2`、`fLaC`、`ec-3` 这样的 codecs 字符串识别编码
+    pub fn from_codecs(codecs: &str) -> Option<Self> {
+        let codecs = codecs.to_ascii_lowercase();
+        if codecs.starts_with("mp4a") {
+            Some(Self::Aac)
+        } else if codecs.starts_with("flac") {
+            Some(Self::Flac)
+        } else if codecs.starts_with("ec-3") || codecs.starts_with("ac-3") {
+            Some(Self::Dolby)
+        } else {
+            None
+        }
+    }
+
+    /// 无损和杜比音轨需要用户主动选择，默认只选普通音轨
+    pub fn is_special(&self) -> bool {
+        matches!(self, Self::Flac | Self::Dolby)
+    }
+}
+
+/// 从多个清晰度、编码中挑选要下载的流的策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FormatPolicy {
+    /// 只选不超过这个高度的视频
+    pub max_height: Option<u32>,
+    /// 有这个编码时优先使用，即使清晰度更低
+    pub prefer_codec: Option<VideoCodec>,
+    /// 有这种音轨时优先使用，没有时退回普通音轨
+    pub prefer_audio: Option<AudioCodec>,
+}
+
+impl FormatPolicy {
+    pub const BEST: Self = Self {
+        max_height: None,
+        prefer_codec: None,
+        prefer_audio: None,
+    };
+
+    /// 界面上提供的几种策略
+    pub const PRESETS: &'static [(&'static str, Self)] = &[
+        ("最佳画质", Self::BEST),
+        (
+            "兼容优先 (AVC)",
+            Self {
+                max_height: None,
+                prefer_codec: Some(VideoCodec::Avc),
+                prefer_audio: None,
+            },
+        ),
+        (
+            "最高 1080P",
+            Self {
+                max_height: Some(1080),
+                prefer_codec: None,
+                prefer_audio: None,
+            },
+        ),
+        (
+            "无损音质",
+            Self {
+                max_height: None,
+                prefer_codec: None,
+                prefer_audio: Some(AudioCodec::Flac),
+            },
+        ),
+    ];
+
+    /// 解析 `best`、`avc`、`1080p`、`flac` 这样以逗号分隔的策略，例如 `avc,1080p`
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut policy = Self::BEST;
+        for token in s.split(',').map(|t| t.trim().to_ascii_lowercase()) {
+            match token.as_str() {
+                "" | "best" => {}
+                "avc" | "h264" => policy.prefer_codec = Some(VideoCodec::Avc),
+                "hevc" | "h265" => policy.prefer_codec = Some(VideoCodec::Hevc),
+                "av1" => policy.prefer_codec = Some(VideoCodec::Av1),
+                "aac" => policy.prefer_audio = Some(AudioCodec::Aac),
+                "flac" | "hires" | "lossless" => policy.prefer_audio = Some(AudioCodec::Flac),
+                "dolby" | "atmos" => policy.prefer_audio = Some(AudioCodec::Dolby),
+                "4k" => policy.max_height = Some(2160),
+                "8k" => policy.max_height = Some(4320),
+                _ => match token.strip_suffix('p').and_then(|h| h.parse().ok()) {
+                    Some(height) => policy.max_height = Some(height),
+                    None => bail!("无法识别的格式策略: {token}"),
+                },
+            }
+        }
+        Ok(policy)
+    }
+
+    /// 返回选中的流在 `formats` 中的下标：一个视频加一个音频，或者一个音视频合一的流
+    pub fn select(&self, formats: &[Stream]) -> Vec<usize> {
+        let video = self.select_video(formats);
+        let audio = self.select_audio(formats);
+        match (video, audio) {
+            (None, None) => Self::best_of(formats, StreamKind::Muxed)
+                .into_iter()
+                .collect(),
+            (video, audio) => video.into_iter().chain(audio).collect(),
+        }
+    }
+
+    fn select_video(&self, formats: &[Stream]) -> Option<usize> {
+        let mut candidates: Vec<_> = (0..formats.len())
+            .filter(|&i| formats[i].kind == StreamKind::Video)
+            .collect();
+        if let Some(codec) = self.prefer_codec {
+            let preferred: Vec<_> = candidates
+                .iter()
+                .copied()
+                .filter(|&i| formats[i].format.video_codec() == Some(codec))
+                .collect();
+            if !preferred.is_empty() {
+                candidates = preferred;
+            }
+        }
+        if let Some(max_height) = self.max_height {
+            let fit: Vec<_> = candidates
+                .iter()
+                .copied()
+                .filter(|&i| formats[i].format.height.unwrap_or(0) <= max_height)
+                .collect();
+            // 全都超出上限时退而求其次，选最低的
+            if fit.is_empty() {
+                return candidates
+                    .into_iter()
+                    .min_by_key(|&i| Self::video_rank(&formats[i]));
+            }
+            candidates = fit;
+        }
+        candidates
+            .into_iter()
+            .max_by_key(|&i| Self::video_rank(&formats[i]))
+    }
+
+    fn select_audio(&self, formats: &[Stream]) -> Option<usize> {
+        (0..formats.len())
+            .filter(|&i| formats[i].kind == StreamKind::Audio)
+            .max_by_key(|&i| {
+                let format = &formats[i].format;
+                let codec = format.audio_codec();
+                (
+                    self.prefer_audio.is_some_and(|c| codec == Some(c)),
+                    !codec.is_some_and(|c| c.is_special()),
+                    format.bandwidth.unwrap_or(0),
+                )
+            })
+    }
+
+    fn video_rank(stream: &Stream) -> (u32, u64, u64) {
+        let format = &stream.format;
+        (
+            format.height.unwrap_or(0),
+            (format.frame_rate.unwrap_or(0.) * 1000.) as u64,
+            format.bandwidth.unwrap_or(0),
+        )
+    }
+
+    fn best_of(formats: &[Stream], kind: StreamKind) -> Option<usize> {
+        (0..formats.len())
+            .filter(|&i| formats[i].kind == kind)
+            .max_by_key(|&i| formats[i].format.bandwidth.unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Format;
+
+    fn video(height: u32, codecs: &str, bandwidth: u64) -> Stream {
+        Stream::new(
+            StreamKind::Video,
+            "https://example.com/v.m4s".parse().unwrap(),
+            "mp4",
+        )
+        .with_format(Format {
+            codecs: Some(codecs.into()),
+            height: Some(height),
+            bandwidth: Some(bandwidth),
+            ..Default::default()
+        })
+    }
+
+    fn audio(bandwidth: u64) -> Stream {
+        Stream::new(
+            StreamKind::Audio,
+            "https://example.com/a.m4s".parse().unwrap(),
+            "m4a",
+        )
+        .with_format(Format {
+            bandwidth: Some(bandwidth),
+            ..Default::default()
+        })
+    }
+
+    fn special_audio(codecs: &str, bandwidth: u64) -> Stream {
+        let mut stream = audio(bandwidth);
+        stream.format.codecs = Some(codecs.into());
+        stream
+    }
+
+    #[test]
+    fn test_parse_policy() {
+        assert_eq!(FormatPolicy::parse("best").unwrap(), FormatPolicy::BEST);
+        assert_eq!(
+            FormatPolicy::parse("avc, 1080p").unwrap(),
+            FormatPolicy {
+                max_height: Some(1080),
+                prefer_codec: Some(VideoCodec::Avc),
+                prefer_audio: None,
+            }
+        );
+        assert_eq!(
+            FormatPolicy::parse("hires").unwrap().prefer_audio,
+            Some(AudioCodec::Flac)
+        );
+        assert_eq!(FormatPolicy::parse("4K").unwrap().max_height, Some(2160));
+        assert!(FormatPolicy::parse("fast").is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        let formats = vec![
+            video(2160, "hev1.1.6.L150.90", 8_000_000),
+            video(1080, "avc1.640032", 3_000_000),
+            video(1080, "hev1.1.6.L120.90", 1_500_000),
+            video(720, "avc1.64001F", 1_000_000),
+            audio(64_000),
+            audio(192_000),
+        ];
+        assert_eq!(FormatPolicy::BEST.select(&formats), vec![0, 5]);
+        assert_eq!(
+            FormatPolicy::parse("avc").unwrap().select(&formats),
+            vec![1, 5]
+        );
+        assert_eq!(
+            FormatPolicy::parse("1080p").unwrap().select(&formats),
+            vec![1, 5]
+        );
+        assert_eq!(
+            FormatPolicy::parse("hevc,1080p").unwrap().select(&formats),
+            vec![2, 5]
+        );
+        assert_eq!(
+            FormatPolicy::parse("360p").unwrap().select(&formats),
+            vec![3, 5]
+        );
+    }
+
+    #[test]
+    fn test_select_audio() {
+        let formats = vec![
+            video(1080, "avc1.640032", 3_000_000),
+            audio(192_000),
+            special_audio("fLaC", 1_500_000),
+            special_audio("ec-3", 768_000),
+        ];
+        assert_eq!(FormatPolicy::BEST.select(&formats), vec![0, 1]);
+        assert_eq!(
+            FormatPolicy::parse("flac").unwrap().select(&formats),
+            vec![0, 2]
+        );
+        assert_eq!(
+            FormatPolicy::parse("dolby").unwrap().select(&formats),
+            vec![0, 3]
+        );
+        assert_eq!(
+            FormatPolicy::parse("flac").unwrap().select(&formats[..2]),
+            vec![0, 1]
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    Extracted, Extractor, MediaInfo, TOKIO_RT,
+    abort::AbortOnDrop,
+    download::{DownloadOptions, DownloadState, download},
+};
+use anyhow::bail;
+use parking_lot::Mutex;
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+    },
+};
+use tracing::{error, info};
+
+#[derive(Debug, Clone)]
+pub enum TaskStatus {
+    Queued,
+    Running,
+    Finished(PathBuf),
+    Failed(String),
+    Cancelled,
+}
+
+impl TaskStatus {
+    /// 还没结束（排队中或下载中）
+    pub fn is_active(&self) -> bool {
+        matches!(self, TaskStatus::Queued | TaskStatus::Running)
+    }
+}
+
+pub struct DownloadTask {
+    pub id: u64,
+    pub info: MediaInfo,
+    pub options: DownloadOptions,
+    /// 重试时用它从 [`MediaInfo::input`] 重新解析
+    extractor: Arc<dyn Extractor>,
+    inner: Mutex<TaskInner>,
+}
+
+struct TaskInner {
+    status: TaskStatus,
+    state: Arc<DownloadState>,
+    handle: Option<AbortOnDrop<()>>,
+    /// 重试过，下载前要先刷新已经失效的地址
+    needs_refresh: bool,
+}
+
+impl DownloadTask {
+    pub fn status(&self) -> TaskStatus {
+        self.inner.lock().status.clone()
+    }
+
+    /// 当前这一次运行的进度，重试后会换成新的
+    pub fn state(&self) -> Arc<DownloadState> {
+        self.inner.lock().state.clone()
+    }
+}
+
+/// 下载队列，同时运行的任务数不超过 `limit`，其余排队等待
+pub struct DownloadQueue {
+    tasks: Mutex<Vec<Arc<DownloadTask>>>,
+    limit: AtomicUsize,
+    next_id: AtomicU64,
+}
+
+impl DownloadQueue {
+    pub const DEFAULT_LIMIT: usize = 3;
+
+    pub fn new(limit: usize) -> Arc<Self> {
+        Arc::new(Self {
+            tasks: Mutex::new(Vec::new()),
+            limit: AtomicUsize::new(limit.max(1)),
+            next_id: AtomicU64::new(0),
+        })
+    }
+
+    pub fn tasks(&self) -> Vec<Arc<DownloadTask>> {
+        self.tasks.lock().clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.lock().is_empty()
+    }
+
+    /// 是否还有排队中或下载中的任务
+    pub fn is_active(&self) -> bool {
+        self.tasks
+            .lock()
+            .iter()
+            .any(|task| task.inner.lock().status.is_active())
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit.load(Ordering::Relaxed)
+    }
+
+    pub fn set_limit(self: &Arc<Self>, limit: usize) {
+        self.limit.store(limit.max(1), Ordering::Relaxed);
+        self.schedule();
+    }
+
+    pub fn push(
+        self: &Arc<Self>,
+        info: MediaInfo,
+        options: DownloadOptions,
+        extractor: Arc<dyn Extractor>,
+    ) -> Arc<DownloadTask> {
+        let task = Arc::new(DownloadTask {
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            inner: Mutex::new(TaskInner {
+                status: TaskStatus::Queued,
+                state: DownloadState::new(&info),
+                handle: None,
+                needs_refresh: false,
+            }),
+            info,
+            options,
+            extractor,
+        });
+        info!(id = task.id, title = %task.info.title, "加入下载队列");
+        self.tasks.lock().push(task.clone());
+        self.schedule();
+        task
+    }
+
+    pub fn cancel(self: &Arc<Self>, id: u64) {
+        if let Some(task) = self.find(id) {
+            let mut inner = task.inner.lock();
+            if inner.status.is_active() {
+                inner.handle = None;
+                inner.status = TaskStatus::Cancelled;
+                inner.state.is_finished.store(true, Ordering::Relaxed);
+            }
+        }
+        self.schedule();
+    }
+
+    /// 停止直播录制，已录制的部分正常保存
+    pub fn stop(&self, id: u64) {
+        if let Some(task) = self.find(id) {
+            let inner = task.inner.lock();
+            if matches!(inner.status, TaskStatus::Running) {
+                info!(id, "停止录制");
+                inner.state.request_stop();
+            }
+        }
+    }
+
+    pub fn retry(self: &Arc<Self>, id: u64) {
+        if let Some(task) = self.find(id) {
+            let mut inner = task.inner.lock();
+            if !inner.status.is_active() {
+                inner.handle = None;
+                inner.status = TaskStatus::Queued;
+                inner.state = DownloadState::new(&task.info);
+                inner.needs_refresh = true;
+            }
+        }
+        self.schedule();
+    }
+
+    /// 从列表中移除任务，正在下载的会被打断
+    pub fn remove(self: &Arc<Self>, id: u64) {
+        self.tasks.lock().retain(|task| {
+            if task.id != id {
+                return true;
+            }
+            // 运行中的 future 自己持有 task，必须手动打断才能释放
+            task.inner.lock().handle = None;
+            false
+        });
+        self.schedule();
+    }
+
+    fn find(&self, id: u64) -> Option<Arc<DownloadTask>> {
+        self.tasks.lock().iter().find(|task| task.id == id).cloned()
+    }
+
+    /// 在不超过并发上限的前提下，按加入顺序启动排队中的任务
+    fn schedule(self: &Arc<Self>) {
+        let tasks = self.tasks.lock();
+        let mut running = tasks
+            .iter()
+            .filter(|task| matches!(task.inner.lock().status, TaskStatus::Running))
+            .count();
+        for task in tasks.iter() {
+            if running >= self.limit() {
+                break;
+            }
+            let mut inner = task.inner.lock();
+            if !matches!(inner.status, TaskStatus::Queued) {
+                continue;
+            }
+            running += 1;
+            inner.status = TaskStatus::Running;
+            let queue = self.clone();
+            let (task, state) = (task.clone(), inner.state.clone());
+            let refresh = inner.needs_refresh;
+            inner.handle = Some(AbortOnDrop(TOKIO_RT.spawn(async move {
+                let result = run(&task, refresh, state).await;
+                queue.finish(&task, result);
+            })));
+        }
+    }
+
+    fn finish(self: &Arc<Self>, task: &DownloadTask, result: anyhow::Result<PathBuf>) {
+        {
+            let mut inner = task.inner.lock();
+            if !matches!(inner.status, TaskStatus::Running) {
+                return;
+            }
+            inner.status = match result {
+                Ok(path) => {
+                    info!(id = task.id, path = ?path, "下载完成");
+                    TaskStatus::Finished(path)
+                }
+                Err(e) => {
+                    error!(id = task.id, err = ?e, "下载失败");
+                    TaskStatus::Failed(format!("{e:#}"))
+                }
+            };
+        }
+        self.schedule();
+    }
+}
+
+/// 执行一次下载，`refresh` 时先重新解析换上新的地址
+async fn run(
+    task: &DownloadTask,
+    refresh: bool,
+    state: Arc<DownloadState>,
+) -> anyhow::Result<PathBuf> {
+    let mut info = task.info.clone();
+    if refresh {
+        info!(id = task.id, input = %info.input, "重新解析以刷新下载地址");
+        match task.extractor.extract(&info.input).await? {
+            Some(Extracted::Media(fresh)) => info.refresh_urls(fresh)?,
+            _ => bail!("重新解析没有得到原来的内容"),
+        }
+    }
+    download(info, task.options.clone(), state).await
+}
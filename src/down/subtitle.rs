@@ -0,0 +1,407 @@
+use crate::{Subtitle, sanitize::sanitize};
+use anyhow::Context;
+use fast_down::utils::gen_unique_path;
+use lazy_static::lazy_static;
+use regex::Regex;
+use reqwest::Client;
+use std::{fmt::Write as _, path::Path, path::PathBuf};
+use tokio::fs;
+use tracing::warn;
+
+/// 转换好、可以封装进视频的字幕文件
+#[derive(Debug, Clone)]
+pub struct SubtitleFile {
+    pub path: PathBuf,
+    pub lang: String,
+    pub name: String,
+}
+
+/// 下载所有字幕，原始文件和转换后的文件都保存在视频旁边，单个字幕失败只记录日志
+pub async fn download_subtitles(
+    subtitles: &[Subtitle],
+    title: &str,
+    dir: &Path,
+    client: &Client,
+    resolution: (u32, u32),
+) -> Vec<SubtitleFile> {
+    let mut files = Vec::new();
+    for subtitle in subtitles {
+        match download_subtitle(subtitle, title, dir, client, resolution).await {
+            Ok(file) => files.extend(file),
+            Err(e) => warn!(err = ?e, lang = %subtitle.lang, "字幕下载失败"),
+        }
+    }
+    files
+}
+
+async fn download_subtitle(
+    subtitle: &Subtitle,
+    title: &str,
+    dir: &Path,
+    client: &Client,
+    (width, height): (u32, u32),
+) -> anyhow::Result<Option<SubtitleFile>> {
+    let text = client
+        .get(subtitle.url.clone())
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    save_text(dir, title, &subtitle.lang, &subtitle.ext, &text).await?;
+    // 第一个转换结果用于封装进视频
+    let converted = match subtitle.ext.as_str() {
+        "xml" => vec![("ass", danmaku_to_ass(&text, width, height))],
+        "json" => {
+            let cues = parse_bili_subtitle(&text)?;
+            vec![("srt", cues_to_srt(&cues)), ("vtt", cues_to_vtt(&cues))]
+        }
+        _ => return Ok(None),
+    };
+    let mut embed = None;
+    for (ext, content) in converted {
+        let path = save_text(dir, title, &subtitle.lang, ext, &content).await?;
+        embed.get_or_insert(SubtitleFile {
+            path,
+            lang: subtitle.lang.clone(),
+            name: subtitle.name.clone(),
+        });
+    }
+    Ok(embed)
+}
+
+async fn save_text(
+    dir: &Path,
+    title: &str,
+    lang: &str,
+    ext: &str,
+    content: &str,
+) -> anyhow::Result<PathBuf> {
+    let path = soft_canonicalize::soft_canonicalize(
+        dir.join(sanitize(format!("{}.{}.{}", title, lang, ext))),
+    )?;
+    let path = gen_unique_path(path).await?;
+    fs::write(&path, content)
+        .await
+        .with_context(|| format!("无法保存字幕: {}", path.display()))?;
+    Ok(path)
+}
+
+/// 一条字幕，时间单位为秒
+#[derive(Debug, Clone, PartialEq)]
+struct Cue {
+    from: f64,
+    to: f64,
+    content: String,
+}
+
+/// 解析 B 站 CC 字幕的 JSON，形如 `{"body": [{"from": 0.5, "to": 2, "content": "..."}]}`
+fn parse_bili_subtitle(json: &str) -> anyhow::Result<Vec<Cue>> {
+    let value: serde_json::Value = serde_json::from_str(json).context("字幕不是有效的 JSON")?;
+    value
+        .get("body")
+        .and_then(|b| b.as_array())
+        .context("字幕中没有 body")?
+        .iter()
+        .map(|cue| {
+            Some(Cue {
+                from: cue.get("from")?.as_f64()?,
+                to: cue.get("to")?.as_f64()?,
+                content: cue.get("content")?.as_str()?.to_string(),
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .context("无法解析字幕")
+}
+
+fn cues_to_srt(cues: &[Cue]) -> String {
+    let mut srt = String::new();
+    for (i, cue) in cues.iter().enumerate() {
+        let _ = writeln!(
+            srt,
+            "{}\n{} --> {}\n{}\n",
+            i + 1,
+            timestamp(cue.from, ','),
+            timestamp(cue.to, ','),
+            cue.content
+        );
+    }
+    srt
+}
+
+fn cues_to_vtt(cues: &[Cue]) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+    for cue in cues {
+        let _ = writeln!(
+            vtt,
+            "{} --> {}\n{}\n",
+            timestamp(cue.from, '.'),
+            timestamp(cue.to, '.'),
+            cue.content
+        );
+    }
+    vtt
+}
+
+/// SRT 和 WebVTT 的时间格式 `hh:mm:ss,mmm`，只有毫秒分隔符不同
+fn timestamp(secs: f64, sep: char) -> String {
+    let ms = (secs * 1000.).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        ms / 3_600_000,
+        ms / 60_000 % 60,
+        ms / 1000 % 60,
+        sep,
+        ms % 1000
+    )
+}
+
+/// 弹幕的显示方式，对应 XML 中 p 属性的第二项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DanmakuMode {
+    Scroll,
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Clone)]
+struct Danmaku {
+    time: f64,
+    mode: DanmakuMode,
+    size: u32,
+    color: u32,
+    text: String,
+}
+
+/// 解析 B 站弹幕 XML，高级弹幕和代码弹幕会被忽略
+fn parse_danmaku(xml: &str) -> Vec<Danmaku> {
+    lazy_static! {
+        static ref DANMAKU_REGEX: Regex = Regex::new(r#"<d p="([^"]*)">([^<]*)</d>"#).unwrap();
+    }
+    let mut list: Vec<_> = DANMAKU_REGEX
+        .captures_iter(xml)
+        .filter_map(|caps| {
+            let mut p = caps.get(1)?.as_str().split(',');
+            let time = p.next()?.parse().ok()?;
+            let mode = match p.next()?.parse::<u32>().ok()? {
+                1..=3 | 6 => DanmakuMode::Scroll,
+                4 => DanmakuMode::Bottom,
+                5 => DanmakuMode::Top,
+                _ => return None,
+            };
+            let size = p.next()?.parse().ok()?;
+            let color = p.next()?.parse().ok()?;
+            let text = unescape_xml(caps.get(2)?.as_str());
+            Some(Danmaku {
+                time,
+                mode,
+                size,
+                color,
+                text,
+            })
+        })
+        .collect();
+    list.sort_by(|a, b| a.time.total_cmp(&b.time));
+    list
+}
+
+/// 解码命名实体和 `&#10;`、`&#x1F600;` 这样的数字引用，一次替换完，`&amp;lt;` 不会被解码两次
+fn unescape_xml(s: &str) -> String {
+    lazy_static! {
+        static ref ENTITY_REGEX: Regex =
+            Regex::new(r"&(lt|gt|quot|apos|amp|#[xX][0-9a-fA-F]+|#[0-9]+);").unwrap();
+    }
+    ENTITY_REGEX
+        .replace_all(s, |caps: &regex::Captures| {
+            let c = match &caps[1] {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "amp" => Some('&'),
+                num => match num[1..].strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => num[1..].parse().ok(),
+                }
+                .and_then(char::from_u32),
+            };
+            // 无效的码点原样保留
+            c.map_or_else(|| caps[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+/// 滚动弹幕在屏幕上停留的秒数
+const SCROLL_DURATION: f64 = 8.;
+/// 顶部、底部弹幕停留的秒数
+const FIXED_DURATION: f64 = 4.;
+/// 弹幕默认字号，XML 中的字号以此为基准缩放
+const BASE_FONT_SIZE: u32 = 25;
+
+/// 把弹幕 XML 转换成 ASS 字幕，滚动弹幕按行分配避免重叠，屏幕满了就叠在最早空出来的行
+pub fn danmaku_to_ass(xml: &str, width: u32, height: u32) -> String {
+    let font_size = (height as f64 / 1080. * 50.).round().max(1.);
+    let (w, h) = (width as f64, height as f64);
+    let rows = ((h / font_size) as usize).max(1);
+    // 每一行下一条弹幕可以进入的时间
+    let mut scroll_rows = vec![0f64; rows];
+    let mut top_rows = vec![0f64; rows];
+    let mut bottom_rows = vec![0f64; rows];
+    let mut ass = format!(
+        "[Script Info]
+ScriptType: v4.00+
+PlayResX: {width}
+PlayResY: {height}
+WrapStyle: 2
+ScaledBorderAndShadow: yes
+
+[V4+ Styles]
+Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding
+Style: Danmaku,Microsoft YaHei,{font_size},&H33FFFFFF,&H33FFFFFF,&H33000000,&H33000000,1,0,0,0,100,100,0,0,1,1,0,7,0,0,0,1
+
+[Events]
+Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text
+"
+    );
+    for danmaku in parse_danmaku(xml) {
+        let size = font_size * danmaku.size as f64 / BASE_FONT_SIZE as f64;
+        let text_width = text_width(&danmaku.text, size);
+        let start = danmaku.time;
+        let (end, position) = match danmaku.mode {
+            DanmakuMode::Scroll => {
+                let row = pick_row(&scroll_rows, start);
+                // 弹幕尾部完全进入屏幕后，同一行才能放下一条
+                scroll_rows[row] = start + SCROLL_DURATION * text_width / (w + text_width);
+                let y = row as f64 * font_size;
+                (
+                    start + SCROLL_DURATION,
+                    format!("\\move({w:.0},{y:.0},{:.0},{y:.0})", -text_width),
+                )
+            }
+            DanmakuMode::Top => {
+                let row = pick_row(&top_rows, start);
+                top_rows[row] = start + FIXED_DURATION;
+                let y = row as f64 * font_size;
+                (
+                    start + FIXED_DURATION,
+                    format!("\\an8\\pos({:.0},{y:.0})", w / 2.),
+                )
+            }
+            DanmakuMode::Bottom => {
+                let row = pick_row(&bottom_rows, start);
+                bottom_rows[row] = start + FIXED_DURATION;
+                let y = h - row as f64 * font_size;
+                (
+                    start + FIXED_DURATION,
+                    format!("\\an2\\pos({:.0},{y:.0})", w / 2.),
+                )
+            }
+        };
+        let mut tags = position;
+        if danmaku.size != BASE_FONT_SIZE {
+            let _ = write!(tags, "\\fs{:.0}", size);
+        }
+        if danmaku.color != 0xFFFFFF {
+            let (r, g, b) = (
+                (danmaku.color >> 16) & 0xFF,
+                (danmaku.color >> 8) & 0xFF,
+                danmaku.color & 0xFF,
+            );
+            let _ = write!(tags, "\\c&H{:02X}{:02X}{:02X}&", b, g, r);
+        }
+        let _ = writeln!(
+            ass,
+            "Dialogue: 0,{},{},Danmaku,,0,0,0,,{{{}}}{}",
+            ass_time(start),
+            ass_time(end),
+            tags,
+            escape_ass(&danmaku.text)
+        );
+    }
+    ass
+}
+
+/// 第一个已经空出来的行，都被占着时选最早空出来的
+fn pick_row(rows: &[f64], time: f64) -> usize {
+    rows.iter()
+        .position(|&free| free <= time)
+        .unwrap_or_else(|| {
+            (0..rows.len())
+                .min_by(|&a, &b| rows[a].total_cmp(&rows[b]))
+                .unwrap_or(0)
+        })
+}
+
+/// 估算文字宽度，半角字符按半个字宽算
+fn text_width(text: &str, size: f64) -> f64 {
+    text.chars()
+        .map(|c| if c.is_ascii() { size / 2. } else { size })
+        .sum()
+}
+
+fn escape_ass(text: &str) -> String {
+    text.replace('\\', "＼")
+        .replace('{', "｛")
+        .replace('}', "｝")
+        .replace('\n', "\\N")
+}
+
+/// ASS 的时间格式 `h:mm:ss.cc`
+fn ass_time(secs: f64) -> String {
+    let cs = (secs * 100.).round() as u64;
+    format!(
+        "{}:{:02}:{:02}.{:02}",
+        cs / 360_000,
+        cs / 6000 % 60,
+        cs / 100 % 60,
+        cs % 100
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_danmaku_to_ass() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?><i>
+<d p="12.5,1,25,16777215,0,0,abc,1,10">第一条 &amp; {弹幕}</d>
+<d p="1.0,5,25,16711680,0,0,abc,2,10">顶部</d>
+<d p="3661.0,4,36,16777215,0,0,abc,3,10">底部</d>
+<d p="2.0,7,25,16777215,0,0,abc,4,10">[高级弹幕]</d>
+<d p="30.0,1,25,16777215,0,0,abc,5,10">笑&#x1F600;&#10;行&amp;#38;</d>
+</i>"#;
+        let ass = danmaku_to_ass(xml, 1920, 1080);
+        let events: Vec<_> = ass.lines().filter(|l| l.starts_with("Dialogue")).collect();
+        assert_eq!(
+            events,
+            vec![
+                "Dialogue: 0,0:00:01.00,0:00:05.00,Danmaku,,0,0,0,,{\\an8\\pos(960,0)\\c&H0000FF&}顶部",
+                "Dialogue: 0,0:00:12.50,0:00:20.50,Danmaku,,0,0,0,,{\\move(1920,0,-375,0)}第一条 & ｛弹幕｝",
+                "Dialogue: 0,0:00:30.00,0:00:38.00,Danmaku,,0,0,0,,{\\move(1920,0,-300,0)}笑😀\\N行&#38;",
+                "Dialogue: 0,1:01:01.00,1:01:05.00,Danmaku,,0,0,0,,{\\an2\\pos(960,1080)\\fs72}底部",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bili_subtitle() {
+        let json = r#"{"font_size":0.4,"body":[{"from":0.5,"to":2.25,"location":2,"content":"你好"},{"from":3661.0,"to":3662.5,"content":"再见"}]}"#;
+        let cues = parse_bili_subtitle(json).unwrap();
+        assert_eq!(
+            cues_to_srt(&cues),
+            "1\n00:00:00,500 --> 00:00:02,250\n你好\n\n2\n01:01:01,000 --> 01:01:02,500\n再见\n\n"
+        );
+        assert_eq!(
+            cues_to_vtt(&cues),
+            "WEBVTT\n\n00:00:00.500 --> 00:00:02.250\n你好\n\n01:01:01.000 --> 01:01:02.500\n再见\n\n"
+        );
+        assert!(parse_bili_subtitle("{}").is_err());
+    }
+
+    #[test]
+    fn test_pick_row() {
+        assert_eq!(pick_row(&[5., 1., 0.], 2.), 1);
+        assert_eq!(pick_row(&[5., 3., 4.], 2.), 1);
+    }
+}
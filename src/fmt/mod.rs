@@ -1,3 +1,7 @@
+mod date;
+mod duration;
 mod size;
 
+pub use date::*;
+pub use duration::*;
 pub use size::*;
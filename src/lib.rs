@@ -1,8 +1,10 @@
+mod cli;
 mod down;
 mod fmt;
 mod utils;
 mod view;
 
+pub use cli::*;
 pub use down::*;
 pub use fmt::*;
 pub use utils::*;
@@ -3,7 +3,7 @@ use anyhow::Context;
 use gpui::{AppContext, Application};
 use gpui_component::Root;
 use sevenz_rust2::decompress;
-use std::{io::Cursor, path::Path};
+use std::{env, io::Cursor, path::Path};
 use tracing::{info, level_filters::LevelFilter};
 use tracing_error::ErrorLayer;
 use tracing_subscriber::{
@@ -12,7 +12,10 @@ use tracing_subscriber::{
     layer::SubscriberExt,
     util::SubscriberInitExt,
 };
-use unidown::{FFMPEG_DIR, FFMPEG_PATH, home::HomeView, window_options::window_options};
+use unidown::{
+    CliArgs, CliCommand, FFMPEG_DIR, FFMPEG_PATH, TOKIO_RT, USAGE, home::HomeView, run_cli,
+    window_options::window_options,
+};
 
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
@@ -23,10 +26,26 @@ fn main() -> anyhow::Result<()> {
         use windows::Win32::System::Console::{ATTACH_PARENT_PROCESS, AttachConsole};
         AttachConsole(ATTACH_PARENT_PROCESS).is_ok()
     };
-    if has_console {
-        println!("检测到控制台启动");
-    } else {
-        println!("未检测到控制台");
+    #[cfg(not(windows))]
+    let has_console = {
+        use std::io::IsTerminal;
+        std::io::stdout().is_terminal()
+    };
+    // 带参数启动时进入命令行模式，不打开窗口
+    let cli_args = match CliArgs::parse(env::args().skip(1))? {
+        CliCommand::Gui => None,
+        CliCommand::Help => {
+            println!("{USAGE}");
+            return Ok(());
+        }
+        CliCommand::Download(args) => Some(args),
+    };
+    if cli_args.is_none() {
+        if has_console {
+            println!("检测到控制台启动");
+        } else {
+            println!("未检测到控制台");
+        }
     }
 
     Registry::default()
@@ -35,10 +54,19 @@ fn main() -> anyhow::Result<()> {
                 .with_default_directive(LevelFilter::INFO.into())
                 .from_env_lossy(),
         )
-        .with(fmt::layer().pretty().with_span_events(FmtSpan::CLOSE))
+        .with(
+            fmt::layer()
+                .pretty()
+                .with_span_events(FmtSpan::CLOSE)
+                .with_writer(std::io::stderr),
+        )
         .with(ErrorLayer::default())
         .init();
     install_ffmpeg()?;
+    if let Some(cli_args) = cli_args {
+        TOKIO_RT.block_on(run_cli(cli_args))?;
+        return Ok(());
+    }
     let app = Application::new().with_assets(gpui_component_assets::Assets);
     app.run(move |cx| {
         gpui_component::init(cx);
@@ -76,10 +104,10 @@ fn ensure_executable(path: &Path) -> std::io::Result<()> {
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
-        let mut perms = fs::metadata(path)?.permissions();
+        let mut perms = std::fs::metadata(path)?.permissions();
         if perms.mode() & 0o111 == 0 {
             perms.set_mode(0o755);
-            fs::set_permissions(path, perms)?;
+            std::fs::set_permissions(path, perms)?;
         }
     }
     Ok(())
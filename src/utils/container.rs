@@ -0,0 +1,145 @@
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+/// 根据文件头识别封装格式，返回扩展名，认不出时返回 `None`
+pub fn sniff_ext(head: &[u8]) -> Option<&'static str> {
+    if head.len() >= 12 && &head[4..8] == b"ftyp" {
+        // DASH 的音视频分片都是 iso5/dash，区分不了音频，交给调用方决定 m4a 还是 mp4
+        return Some(match &head[8..12] {
+            b"M4A " | b"M4B " => "m4a",
+            b"qt  " => "mov",
+            _ => "mp4",
+        });
+    }
+    // 部分 fmp4 分片直接以 moof/sidx/styp 开头
+    if head.len() >= 8 && matches!(&head[4..8], b"moof" | b"sidx" | b"styp") {
+        return Some("mp4");
+    }
+    if head.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        let is_webm = head.windows(4).any(|w| w == b"webm");
+        return Some(if is_webm { "webm" } else { "mkv" });
+    }
+    if head.starts_with(b"FLV") {
+        return Some("flv");
+    }
+    if head.starts_with(b"ID3") {
+        return Some("mp3");
+    }
+    if head.starts_with(b"fLaC") {
+        return Some("flac");
+    }
+    if head.starts_with(b"OggS") {
+        return Some("ogg");
+    }
+    if head.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Some("jpg");
+    }
+    if head.starts_with(b"\x89PNG") {
+        return Some("png");
+    }
+    if head.len() >= 12 && head.starts_with(b"RIFF") && &head[8..12] == b"WEBP" {
+        return Some("webp");
+    }
+    if head.len() > 188 && head[0] == 0x47 && head[188] == 0x47 {
+        return Some("ts");
+    }
+    // MPEG 音频帧同步字，layer 为 0 的是 AAC ADTS
+    if head.len() >= 2 && head[0] == 0xFF && head[1] & 0xE0 == 0xE0 {
+        return Some(if head[1] & 0x06 == 0 { "aac" } else { "mp3" });
+    }
+    None
+}
+
+/// 根据 `Content-Type` 推断扩展名，`application/octet-stream` 这类没有信息的返回 `None`
+pub fn ext_from_content_type(content_type: &str) -> Option<&'static str> {
+    let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+    Some(match mime.as_str() {
+        "video/mp4" => "mp4",
+        "audio/mp4" | "audio/x-m4a" | "audio/m4a" => "m4a",
+        "video/x-flv" => "flv",
+        "video/webm" | "audio/webm" => "webm",
+        "video/x-matroska" => "mkv",
+        "video/mp2t" => "ts",
+        "audio/mpeg" | "audio/mp3" => "mp3",
+        "audio/aac" => "aac",
+        "audio/flac" | "audio/x-flac" => "flac",
+        "audio/ogg" => "ogg",
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/webp" => "webp",
+        _ => return None,
+    })
+}
+
+/// 识别出来的格式和原来的扩展名同属一类时保留原来的，比如 m4a 不会被改成 mp4
+pub fn refine_ext<'a>(hint: &'a str, detected: Option<&'a str>) -> &'a str {
+    const FAMILIES: &[&[&str]] = &[
+        &["mp4", "m4a", "m4v", "m4s", "mov"],
+        &["mkv", "webm", "mka"],
+    ];
+    let Some(detected) = detected else {
+        return hint;
+    };
+    let hint_lower = hint.to_ascii_lowercase();
+    let same_family = hint_lower == detected
+        || FAMILIES
+            .iter()
+            .any(|f| f.contains(&hint_lower.as_str()) && f.contains(&detected));
+    if same_family { hint } else { detected }
+}
+
+/// 读取文件开头识别封装格式
+pub async fn sniff_file(path: &Path) -> Option<&'static str> {
+    let mut file = tokio::fs::File::open(path).await.ok()?;
+    let mut head = vec![0; 512];
+    let mut len = 0;
+    while len < head.len() {
+        match file.read(&mut head[len..]).await.ok()? {
+            0 => break,
+            n => len += n,
+        }
+    }
+    sniff_ext(&head[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_ext() {
+        assert_eq!(sniff_ext(b"\0\0\0\x18ftypiso5\0\0\0\0"), Some("mp4"));
+        assert_eq!(sniff_ext(b"\0\0\0\x20ftypM4A \0\0\0\0"), Some("m4a"));
+        assert_eq!(
+            sniff_ext(b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\x82\x84webm"),
+            Some("webm")
+        );
+        assert_eq!(
+            sniff_ext(b"\x1A\x45\xDF\xA3\x42\x82\x88matroska"),
+            Some("mkv")
+        );
+        assert_eq!(sniff_ext(b"FLV\x01\x05"), Some("flv"));
+        assert_eq!(sniff_ext(b"ID3\x04\0"), Some("mp3"));
+        assert_eq!(sniff_ext(&[0xFF, 0xFB, 0x90, 0x00]), Some("mp3"));
+        assert_eq!(sniff_ext(&[0xFF, 0xF1, 0x50, 0x80]), Some("aac"));
+        assert_eq!(sniff_ext(b"<html>"), None);
+    }
+
+    #[test]
+    fn test_content_type() {
+        assert_eq!(ext_from_content_type("video/mp4"), Some("mp4"));
+        assert_eq!(
+            ext_from_content_type("Audio/MP4; charset=binary"),
+            Some("m4a")
+        );
+        assert_eq!(ext_from_content_type("application/octet-stream"), None);
+    }
+
+    #[test]
+    fn test_refine_ext() {
+        assert_eq!(refine_ext("m4a", Some("mp4")), "m4a");
+        assert_eq!(refine_ext("mp3", Some("mp4")), "mp4");
+        assert_eq!(refine_ext("mp4", Some("flv")), "flv");
+        assert_eq!(refine_ext("mp4", None), "mp4");
+    }
+}
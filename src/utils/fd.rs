@@ -1,3 +1,4 @@
+use anyhow::{Context, bail};
 use fast_down::{
     Event, Merge, Total,
     file::MmapFilePusher,
@@ -6,8 +7,13 @@ use fast_down::{
     utils::{FastDownPuller, FastDownPullerOptions, gen_unique_path},
 };
 use parking_lot::Mutex;
-use reqwest::{Client, Url, header::HeaderMap};
+use reqwest::{
+    Client, Url,
+    header::{self, HeaderMap},
+};
 use std::{
+    ffi::OsString,
+    ops::Range,
     path::{Path, PathBuf},
     sync::{
         Arc,
@@ -15,10 +21,14 @@ use std::{
     },
     time::{Duration, Instant},
 };
-use tokio::fs;
+use tokio::{fs, io::AsyncWriteExt};
 use tracing::{error, info, warn};
 
-use crate::{format_size, sanitize::sanitize};
+use crate::{
+    container::{ext_from_content_type, refine_ext, sniff_file},
+    format_size,
+    sanitize::sanitize,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct ProgressInfo {
@@ -28,37 +38,91 @@ pub struct ProgressInfo {
     pub speed_bps: u64,
 }
 
+/// 获取元数据的最大尝试次数
+const MAX_PREFETCH_RETRY: u32 = 5;
+/// 不支持断点续传时，流式下载的最大尝试次数
+const MAX_STREAM_RETRY: u32 = 3;
+/// 每个镜像最多轮到几次，超过后放弃
+const MAX_MIRROR_ROUNDS: usize = 3;
+
+/// 下载 `urls` 指向的同一个文件，第一个是主地址，其余是镜像，出错过多时换下一个。
+/// 返回按响应 `Content-Type` 推断的扩展名
 pub async fn fd(
-    url: Url,
+    urls: &[Url],
     output: &Path,
     client: &Client,
     mut threads: usize,
     headers: Arc<HeaderMap>,
     on_progress: impl Fn(ProgressInfo) + Send + Sync,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<Option<&'static str>> {
+    if urls.is_empty() {
+        bail!("没有下载地址");
+    }
+    let mut saved = load_resume_state(output).await;
     let mut progress = Vec::new();
     let mut global_retry = 0;
+    let mut mirror = 0;
+    let mut switches = 0;
+    let mut known_size = None;
     'retry: loop {
-        info!("开始获取元数据");
+        let mut url = &urls[mirror];
+        info!(mirror, url = %url, "开始获取元数据");
         if global_retry >= 2 {
             error!("重试次数超过限制，完全重下");
             progress.clear();
+            // 旧的进度文件不再可信，避免下次启动又从中恢复
+            let _ = fs::remove_file(resume_state_path(output)).await;
             global_retry = 0;
         }
+        let mut prefetch_retry = 0;
         let (info, resp) = loop {
             match client.prefetch(url.clone()).await {
-                Ok(t) => {
-                    if t.0.fast_download {
-                        break t;
-                    }
-                }
+                Ok(t) => break t,
                 Err((e, t)) => {
-                    error!(err = ?e, "获取元数据失败");
-                    tokio::time::sleep(t.unwrap_or(Duration::from_millis(500))).await;
+                    prefetch_retry += 1;
+                    if prefetch_retry as usize >= (MAX_PREFETCH_RETRY as usize).max(urls.len()) {
+                        return Err(e)
+                            .context(format!("获取元数据失败，已重试 {prefetch_retry} 次"));
+                    }
+                    error!(err = ?e, retry = prefetch_retry, "获取元数据失败");
+                    // 有镜像时换一个节点再试
+                    mirror = (mirror + 1) % urls.len();
+                    url = &urls[mirror];
+                    let gap = Duration::from_millis(500) * prefetch_retry;
+                    tokio::time::sleep(t.unwrap_or(gap)).await;
                 }
             }
         };
         info!(info = ?info, "已获取元数据");
+        let content_ext = resp
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(ext_from_content_type);
+        if !info.fast_download {
+            warn!("服务器不支持断点续传，改用单线程流式下载");
+            drop(resp);
+            fd_stream(urls, output, client, &headers, info.size, &on_progress).await?;
+            return Ok(content_ext);
+        }
+        // 镜像之间文件不一致时，已下载的部分不能再用
+        if known_size.is_some_and(|size| size != info.size) {
+            warn!(size = info.size, "镜像的文件大小不同，从头下载");
+            progress.clear();
+        }
+        known_size = Some(info.size);
+        if let Some(saved) = saved.take() {
+            if saved.size == info.size {
+                info!(downloaded = saved.progress.total(), "从进度文件恢复下载");
+                progress = saved.progress;
+            } else {
+                warn!(
+                    saved = saved.size,
+                    size = info.size,
+                    "文件大小已变化，忽略进度文件"
+                );
+            }
+        }
         if info.size < 50 * 1024 * 1024 {
             info!("文件大小过小，不启用多线程下载");
             threads = 1;
@@ -89,12 +153,11 @@ pub async fn fd(
                 min_chunk_size: 1024 * 1024,
             },
         );
-        let mut smoothed_speed = 0.;
-        let alpha = 0.3;
-        let mut last_update = Instant::now();
-        let mut last_bytes = 0;
-        let start = last_update;
+        let mut meter = SpeedMeter::new(progress.total());
+        let start = Instant::now();
+        let mut last_save = start;
         let mut retry_count = 0;
+        let mut pull_errors = 0;
         while let Ok(e) = result.event_chain.recv().await {
             match e {
                 Event::FlushError(e) => error!("磁盘刷写失败: {:?}", e),
@@ -111,38 +174,44 @@ pub async fn fd(
                                 threads = threads,
                                 "下载数据出错过多，尝试断点续传"
                             );
+                            save_resume_state(output, total, &progress).await;
                             tokio::time::sleep(Duration::from_secs(2 * global_retry)).await;
                             continue 'retry;
                         }
                     }
+                    pull_errors += 1;
+                    // 节点变慢或中途 403 时换一个镜像，已下载的部分接着用
+                    if urls.len() > 1 && pull_errors > (threads * 2).max(8) {
+                        switches += 1;
+                        if switches > urls.len() * MAX_MIRROR_ROUNDS {
+                            bail!("所有镜像都下载失败: {:?}", e);
+                        }
+                        mirror = (mirror + 1) % urls.len();
+                        warn!(mirror, pull_errors, "当前镜像出错过多，切换镜像");
+                        save_resume_state(output, total, &progress).await;
+                        continue 'retry;
+                    }
                 }
                 Event::PushError(id, e) => error!("写入数据出错 {}: {:?}", id, e),
                 Event::Pulling(_) => {}
                 Event::PullProgress(_, _) => {}
                 Event::Finished(_) => {}
                 Event::PushProgress(_, p) => {
+                    // 有数据进来说明当前镜像还能用，只有连续出错才切换
+                    pull_errors = 0;
+                    switches = 0;
                     progress.merge_progress(p);
                     let now = Instant::now();
-                    let elapsed = now - last_update;
-                    let elapsed_secs = elapsed.as_secs_f64();
-                    if elapsed_secs > 0.2 {
-                        let downloaded = progress.total();
-                        let bytes_diff = downloaded - last_bytes;
-                        let instant_speed = bytes_diff as f64 / elapsed_secs;
-
-                        smoothed_speed = if smoothed_speed == 0. {
-                            instant_speed
-                        } else {
-                            alpha * instant_speed + (1.0 - alpha) * smoothed_speed
-                        };
-
-                        last_bytes = downloaded;
-                        last_update = now;
-
+                    if now - last_save > Duration::from_secs(1) {
+                        save_resume_state(output, total, &progress).await;
+                        last_save = now;
+                    }
+                    let downloaded = progress.total();
+                    if let Some(speed_bps) = meter.update(downloaded) {
                         let progress_info = ProgressInfo {
                             downloaded,
                             total,
-                            speed_bps: smoothed_speed as u64,
+                            speed_bps,
                         };
                         on_progress(progress_info);
                     }
@@ -150,18 +219,162 @@ pub async fn fd(
             }
         }
         result.join().await?;
+        let _ = fs::remove_file(resume_state_path(output)).await;
         let progress_info = ProgressInfo {
             downloaded: info.size,
             total,
             speed_bps: (info.size as f64 / start.elapsed().as_secs_f64()) as u64,
         };
         on_progress(progress_info);
-        break Ok(());
+        break Ok(content_ext);
     }
 }
 
-pub async fn download_segment(
+/// 服务器不支持 Range 时的单线程流式下载，出错只能从头重下
+async fn fd_stream(
+    urls: &[Url],
+    output: &Path,
+    client: &Client,
+    headers: &HeaderMap,
+    size: u64,
+    on_progress: &(impl Fn(ProgressInfo) + Send + Sync),
+) -> anyhow::Result<()> {
+    let _ = fs::remove_file(resume_state_path(output)).await;
+    let mut retry = 0;
+    loop {
+        // 每次重试换一个镜像
+        let url = urls[retry as usize % urls.len()].clone();
+        match fd_stream_once(url, output, client, headers, size, on_progress).await {
+            Ok(()) => break Ok(()),
+            Err(e) => {
+                retry += 1;
+                if retry >= MAX_STREAM_RETRY {
+                    break Err(e.context(format!("流式下载失败，已重试 {} 次", retry)));
+                }
+                warn!(err = ?e, retry = retry, "流式下载出错，从头重试");
+                tokio::time::sleep(Duration::from_secs(2 * retry as u64)).await;
+            }
+        }
+    }
+}
+
+async fn fd_stream_once(
     url: Url,
+    output: &Path,
+    client: &Client,
+    headers: &HeaderMap,
+    size: u64,
+    on_progress: &(impl Fn(ProgressInfo) + Send + Sync),
+) -> anyhow::Result<()> {
+    let mut resp = client
+        .get(url)
+        .headers(headers.clone())
+        .send()
+        .await?
+        .error_for_status()?;
+    let total = resp.content_length().unwrap_or(size);
+    let mut file = fs::File::create(output).await?;
+    let mut downloaded = 0;
+    let mut meter = SpeedMeter::new(0);
+    let start = Instant::now();
+    while let Some(chunk) = resp.chunk().await? {
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+        if let Some(speed_bps) = meter.update(downloaded) {
+            on_progress(ProgressInfo {
+                downloaded,
+                total,
+                speed_bps,
+            });
+        }
+    }
+    file.flush().await?;
+    on_progress(ProgressInfo {
+        downloaded,
+        total: downloaded,
+        speed_bps: (downloaded as f64 / start.elapsed().as_secs_f64()) as u64,
+    });
+    Ok(())
+}
+
+/// 指数平滑的下载速度
+struct SpeedMeter {
+    smoothed: f64,
+    last_update: Instant,
+    last_bytes: u64,
+}
+
+impl SpeedMeter {
+    const ALPHA: f64 = 0.3;
+
+    fn new(downloaded: u64) -> Self {
+        Self {
+            smoothed: 0.,
+            last_update: Instant::now(),
+            last_bytes: downloaded,
+        }
+    }
+
+    /// 距离上次更新超过 0.2 秒时返回新的速度（字节/秒）
+    fn update(&mut self, downloaded: u64) -> Option<u64> {
+        let now = Instant::now();
+        let elapsed_secs = (now - self.last_update).as_secs_f64();
+        if elapsed_secs <= 0.2 {
+            return None;
+        }
+        let bytes_diff = downloaded.saturating_sub(self.last_bytes);
+        let instant_speed = bytes_diff as f64 / elapsed_secs;
+        self.smoothed = if self.smoothed == 0. {
+            instant_speed
+        } else {
+            Self::ALPHA * instant_speed + (1.0 - Self::ALPHA) * self.smoothed
+        };
+        self.last_bytes = downloaded;
+        self.last_update = now;
+        Some(self.smoothed as u64)
+    }
+}
+
+/// 断点续传进度文件，和 .fdpart 放在一起，程序重启后也能接着下载
+struct ResumeState {
+    size: u64,
+    progress: Vec<Range<u64>>,
+}
+
+fn resume_state_path(output: &Path) -> PathBuf {
+    let mut path = OsString::from(output);
+    path.push(".json");
+    path.into()
+}
+
+async fn load_resume_state(output: &Path) -> Option<ResumeState> {
+    if !fs::try_exists(output).await.unwrap_or(false) {
+        return None;
+    }
+    let text = fs::read_to_string(resume_state_path(output)).await.ok()?;
+    let value: serde_json::Value = serde_json::from_str(&text).ok()?;
+    let size = value.get("size")?.as_u64()?;
+    let progress = value
+        .get("progress")?
+        .as_array()?
+        .iter()
+        .map(|r| Some(r.get(0)?.as_u64()?..r.get(1)?.as_u64()?))
+        .collect::<Option<Vec<_>>>()?;
+    Some(ResumeState { size, progress })
+}
+
+async fn save_resume_state(output: &Path, size: u64, progress: &[Range<u64>]) {
+    let value = serde_json::json!({
+        "size": size,
+        "progress": progress.iter().map(|r| [r.start, r.end]).collect::<Vec<_>>(),
+    });
+    if let Err(e) = fs::write(resume_state_path(output), value.to_string()).await {
+        warn!(err = ?e, "保存下载进度失败");
+    }
+}
+
+pub async fn download_segment(
+    urls: &[Url],
     title: &str,
     ext: &str,
     dir: &Path,
@@ -173,11 +386,18 @@ pub async fn download_segment(
     let path = soft_canonicalize::soft_canonicalize(
         dir.join(sanitize(format!("{}.{}.fdpart", title, ext))),
     )?;
-    fd(url, &path, client, threads, headers, move |info| {
+    let content_ext = fd(urls, &path, client, threads, headers, move |info| {
         state.update(info)
     })
     .await?;
-    let output_path = gen_unique_path(path.with_extension("")).await?;
+    // 站点给的扩展名不一定对，按文件头纠正，认不出文件头时参考 Content-Type，
+    // ffmpeg 和播放器都会参考扩展名
+    let detected = sniff_file(&path).await.or(content_ext);
+    let real_ext = refine_ext(ext, detected);
+    if real_ext != ext {
+        info!(ext, real_ext, "按文件头纠正扩展名");
+    }
+    let output_path = gen_unique_path(path.with_extension("").with_extension(real_ext)).await?;
     fs::rename(&path, &output_path).await?;
     Ok(output_path)
 }
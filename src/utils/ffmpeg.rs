@@ -1,14 +1,21 @@
 use crate::{FFMPEG_PATH, decode::decode_text};
-use std::{ffi::OsStr, fmt::Display, process::Stdio};
+use std::{collections::HashSet, ffi::OsStr, fmt::Display, process::Stdio, time::Duration};
 use tokio::{
     io::{AsyncBufReadExt, BufReader},
     process::Command,
+    sync::OnceCell,
 };
 
 #[derive(Debug, Clone, Default)]
 pub struct ProgressInfo {
     pub frame: u64,
     pub speed: f64,
+    /// 已写入的字节数
+    pub total_size: u64,
+    /// 已输出的时长
+    pub out_time: Duration,
+    /// kbit/s，ffmpeg 输出 N/A 时为 0
+    pub bitrate: f64,
 }
 
 pub async fn ffmpeg<I, S>(
@@ -50,6 +57,16 @@ where
                     let speed_str = value.trim_end_matches('x');
                     progress.speed = speed_str.parse().unwrap_or(0.0);
                 }
+                "total_size" => {
+                    progress.total_size = value.parse().unwrap_or(0);
+                }
+                "out_time_us" => {
+                    progress.out_time = Duration::from_micros(value.parse().unwrap_or(0));
+                }
+                "bitrate" => {
+                    let bitrate_str = value.trim_end_matches("kbits/s");
+                    progress.bitrate = bitrate_str.parse().unwrap_or(0.0);
+                }
                 "progress" => {
                     on_progress(progress.clone());
                     if value == "end" {
@@ -74,6 +91,44 @@ where
     }
 }
 
+/// ffmpeg 支持的编码器名，只在第一次调用时查询
+pub async fn encoders() -> Result<&'static HashSet<String>, FFmpegError> {
+    static ENCODERS: OnceCell<HashSet<String>> = OnceCell::const_new();
+    ENCODERS
+        .get_or_try_init(|| async {
+            let mut cmd = Command::new(FFMPEG_PATH.as_os_str());
+            #[cfg(windows)]
+            {
+                const CREATE_NO_WINDOW: u32 = 0x08000000;
+                cmd.creation_flags(CREATE_NO_WINDOW);
+            }
+            let output = cmd
+                .args(["-hide_banner", "-encoders"])
+                .stdin(Stdio::null())
+                .output()
+                .await?;
+            if !output.status.success() {
+                return Err(FFmpegError::Runtime(FFmpegRuntimeError {
+                    code: output.status.code(),
+                    stderr: decode_text(&output.stderr).trim().to_string(),
+                    stdout: decode_text(&output.stdout).trim().to_string(),
+                }));
+            }
+            Ok(parse_encoders(&decode_text(&output.stdout)))
+        })
+        .await
+}
+
+/// `ffmpeg -encoders` 的输出中，分隔线之后每行是「标志 名字 描述」
+fn parse_encoders(text: &str) -> HashSet<String> {
+    text.lines()
+        .skip_while(|line| !line.trim_start().starts_with("---"))
+        .skip(1)
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(String::from)
+        .collect()
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum FFmpegError {
     #[error(transparent)]
@@ -97,3 +152,24 @@ impl Display for FFmpegRuntimeError {
         writeln!(f, "stdout: {}", self.stdout)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_encoders() {
+        let text = "Encoders:
+ V..... = Video
+ A..... = Audio
+ ------
+ V....D libx264              libx264 H.264 / AVC / MPEG-4 AVC
+ A....D aac                  AAC (Advanced Audio Coding)
+ A....D libmp3lame           libmp3lame MP3 (MPEG audio layer 3)
+";
+        let encoders = parse_encoders(text);
+        assert_eq!(encoders.len(), 3);
+        assert!(encoders.contains("libmp3lame"));
+        assert!(!encoders.contains("Video"));
+    }
+}
@@ -62,7 +62,7 @@ impl JsRuntimeInner {
         })
     }
 
-    fn douyin_gen_output(&self, resp_text: &str) -> anyhow::Result<(String, Url)> {
+    fn douyin_gen_output(&self, resp_text: &str) -> anyhow::Result<DouyinOutput> {
         self.context.with(|ctx| {
             let douyin_obj: Object = ctx.globals().get("Douyin")?;
             let gen_output: Function = douyin_obj.get("genOuput")?;
@@ -83,11 +83,22 @@ impl JsRuntimeInner {
                 .get("url")
                 .map_err(|_| anyhow!("Missing or invalid 'url'"))?;
             let url = url.parse()?;
-            Ok((title, url))
+            let cover: Option<String> = result.get("cover").ok();
+            let cover = cover.and_then(|c| c.parse().ok());
+            Ok(DouyinOutput { title, url, cover })
         })
     }
 }
 
+/// 抖音解析结果
+#[derive(Debug, Clone)]
+pub struct DouyinOutput {
+    pub title: String,
+    pub url: Url,
+    /// 封面，接口没给时为 `None`
+    pub cover: Option<Url>,
+}
+
 #[derive(Clone)]
 pub struct JsRuntime {
     tx: MTx<mpsc::List<Action>>,
@@ -96,8 +107,7 @@ pub struct JsRuntime {
 enum Action {
     /// 返回 (body, auth_token)
     DouyinGenReq(String, TxOneshot<anyhow::Result<(String, String)>>),
-    /// 返回 (title, video_url)
-    DouyinGenOutput(String, TxOneshot<anyhow::Result<(String, Url)>>),
+    DouyinGenOutput(String, TxOneshot<anyhow::Result<DouyinOutput>>),
 }
 
 impl Default for JsRuntime {
@@ -129,13 +139,13 @@ impl JsRuntime {
         rx.await?
     }
 
-    async fn douyin_gen_output(&self, resp_text: String) -> anyhow::Result<(String, Url)> {
+    async fn douyin_gen_output(&self, resp_text: String) -> anyhow::Result<DouyinOutput> {
         let (tx, rx) = oneshot::oneshot();
         self.tx.send(Action::DouyinGenOutput(resp_text, tx))?;
         rx.await?
     }
 
-    pub async fn parse_douyin(&self, url: String, client: Client) -> anyhow::Result<(String, Url)> {
+    pub async fn parse_douyin(&self, url: String, client: Client) -> anyhow::Result<DouyinOutput> {
         let (body, auth) = self.douyin_gen_req(url).await?;
         let resp_text = client
             .post("https://www.hellotik.app/api/parse")
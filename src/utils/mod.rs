@@ -1,4 +1,5 @@
 pub mod abort;
+pub mod container;
 pub mod decode;
 pub mod fd;
 pub mod ffmpeg;
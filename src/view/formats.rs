@@ -0,0 +1,101 @@
+use crate::{FormatPolicy, MediaInfo, StreamKind};
+use gpui::{Context, Div, EventEmitter, IntoElement, ParentElement, Render, Styled, Window, div};
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    radio::Radio,
+    v_flex,
+};
+
+pub enum FormatEvent {
+    Confirm(MediaInfo),
+    Cancel,
+}
+
+/// 清晰度、编码选择界面，视频和音频各选一个
+pub struct FormatView {
+    info: MediaInfo,
+    video: Option<usize>,
+    audio: Option<usize>,
+}
+
+impl EventEmitter<FormatEvent> for FormatView {}
+
+impl FormatView {
+    pub fn new(info: MediaInfo, policy: &FormatPolicy) -> Self {
+        let selected = policy.select(&info.formats);
+        let find = |audio: bool| {
+            selected
+                .iter()
+                .copied()
+                .find(|&i| (info.formats[i].kind == StreamKind::Audio) == audio)
+        };
+        let (video, audio) = (find(false), find(true));
+        Self { info, video, audio }
+    }
+
+    fn render_group(&self, label: &'static str, audio: bool, cx: &mut Context<Self>) -> Div {
+        let selected = if audio { self.audio } else { self.video };
+        v_flex()
+            .gap_1()
+            .child(div().child(label).font_bold())
+            .children(
+                self.info
+                    .formats
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, stream)| (stream.kind == StreamKind::Audio) == audio)
+                    .map(|(i, stream)| {
+                        Radio::new(("format", i))
+                            .label(stream.format.describe())
+                            .checked(selected == Some(i))
+                            .on_click(cx.listener(move |view, _: &bool, _, cx| {
+                                if audio {
+                                    view.audio = Some(i);
+                                } else {
+                                    view.video = Some(i);
+                                }
+                                cx.notify();
+                            }))
+                    }),
+            )
+    }
+}
+
+impl Render for FormatView {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .p_4()
+            .gap_3()
+            .child(div().child(self.info.title.clone()).text_lg().font_bold())
+            .child(self.render_group("视频", false, cx))
+            .child(self.render_group("音频", true, cx))
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("confirm-format")
+                            .primary()
+                            .label("加入下载")
+                            .compact()
+                            .on_click(cx.listener(|view, _, _, cx| {
+                                let mut info = view.info.clone();
+                                let indices: Vec<_> =
+                                    view.video.into_iter().chain(view.audio).collect();
+                                info.select_formats(&indices);
+                                cx.emit(FormatEvent::Confirm(info));
+                            })),
+                    )
+                    .child(
+                        Button::new("cancel-format")
+                            .ghost()
+                            .label("取消")
+                            .compact()
+                            .on_click(cx.listener(|_, _, _, cx| {
+                                cx.emit(FormatEvent::Cancel);
+                            })),
+                    ),
+            )
+    }
+}
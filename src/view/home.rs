@@ -1,26 +1,69 @@
-use crate::{ALL_DOWN, Parser};
-use anyhow::bail;
+use crate::{
+    AllDown, ExtractError, Extracted, Extractor, FormatPolicy, MediaInfo, Playlist, PlaylistEntry,
+    TOKIO_RT,
+    download::{AudioFormat, DownloadOptions},
+    formats::{FormatEvent, FormatView},
+    login::{LoginEvent, LoginView},
+    playlist::{PlaylistEvent, PlaylistView},
+    queue::DownloadQueue,
+    tasks::TaskListView,
+};
+use anyhow::{Context as _, bail};
 use gpui::{
-    AnyView, AppContext, ClickEvent, Context, Div, Element, Entity, InteractiveElement,
-    IntoElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div,
+    AnyView, AppContext, ClickEvent, Context, Div, Entity, FluentBuilder as _, InteractiveElement,
+    IntoElement, ParentElement, Render, SharedString, Styled, Subscription, Window, div, px,
 };
 use gpui_component::{
-    IconName, StyledExt, TitleBar,
+    ActiveTheme, IconName, StyledExt, TitleBar,
     button::{Button, ButtonVariants},
+    checkbox::Checkbox,
     h_flex,
     input::{Input, InputEvent, InputState},
     scroll::ScrollableElement,
     v_flex,
 };
-use tracing::instrument;
+use std::sync::Arc;
+use tracing::{Instrument, Span, instrument};
 
 pub struct HomeView {
     input_state: Entity<InputState>,
     is_loading: bool,
-    view: Option<AnyView>,
+    queue: Arc<DownloadQueue>,
+    tasks: Entity<TaskListView>,
+    /// 正在显示的分 P 列表或清晰度选择
+    picker: Option<AnyView>,
+    policy: FormatPolicy,
+    embed_subtitles: bool,
+    /// 仅音频模式的输出格式
+    audio_format: Option<AudioFormat>,
+    slideshow: bool,
+    /// 抖音原生解析失败时是否把链接发给第三方接口
+    douyin_third_party: bool,
+    error: Option<ErrorMessage>,
+    show_error_detail: bool,
     _subscription: Subscription,
 }
 
+struct ErrorMessage {
+    message: SharedString,
+    detail: Option<SharedString>,
+}
+
+impl ErrorMessage {
+    fn new(err: &anyhow::Error) -> Self {
+        match err.downcast_ref::<ExtractError>() {
+            Some(e) => Self {
+                message: e.to_string().into(),
+                detail: e.detail().map(|d| d.to_string().into()),
+            },
+            None => Self {
+                message: format!("{err:#}").into(),
+                detail: Some(format!("{err:?}").into()),
+            },
+        }
+    }
+}
+
 impl HomeView {
     pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let input_state = cx.new(|cx| {
@@ -35,10 +78,21 @@ impl HomeView {
                 }
             }
         });
+        let queue = DownloadQueue::new(DownloadQueue::DEFAULT_LIMIT);
+        let tasks = cx.new(|cx| TaskListView::new(queue.clone(), cx));
         Self {
             input_state,
             is_loading: false,
-            view: None,
+            queue,
+            tasks,
+            picker: None,
+            policy: FormatPolicy::BEST,
+            embed_subtitles: false,
+            audio_format: None,
+            slideshow: false,
+            douyin_third_party: false,
+            error: None,
+            show_error_detail: false,
             _subscription,
         }
     }
@@ -57,14 +111,23 @@ impl HomeView {
             bail!("正在解析中")
         }
         self.is_loading = true;
+        self.error = None;
+        self.show_error_detail = false;
         cx.notify();
-        let parsed = ALL_DOWN.parse(&value, window, cx);
-        cx.spawn(async move |view, cx| {
-            let parsed = parsed.await;
-            view.update(cx, |view, cx| {
+        let all_down = AllDown::all_sites(self.douyin_third_party);
+        let parsed = TOKIO_RT
+            .spawn(async move { all_down.extract(&value).await }.instrument(Span::current()));
+        cx.spawn_in(window, async move |view, cx| {
+            let parsed = async { parsed.await??.context(ExtractError::Empty) }.await;
+            view.update_in(cx, |view, window, cx| {
                 view.is_loading = false;
-                if let Ok(parsed) = parsed {
-                    view.view = parsed;
+                match parsed {
+                    Ok(Extracted::Media(info)) if !info.formats.is_empty() => {
+                        view.show_formats(info, cx)
+                    }
+                    Ok(Extracted::Media(info)) => view.push_media(info, cx),
+                    Ok(Extracted::Playlist(playlist)) => view.show_playlist(playlist, window, cx),
+                    Err(e) => view.error = Some(ErrorMessage::new(&e)),
                 }
                 cx.notify();
             })
@@ -72,11 +135,100 @@ impl HomeView {
         .detach();
         Ok(())
     }
+
+    fn push_media(&mut self, mut info: MediaInfo, cx: &mut Context<Self>) {
+        match DownloadOptions::new() {
+            Ok(mut options) => {
+                options.embed_subtitles = self.embed_subtitles;
+                options.audio_format = self.audio_format;
+                options.slideshow = self.slideshow;
+                if self.audio_format.is_some() {
+                    info.keep_audio_only();
+                }
+                let extractor = Arc::new(AllDown::all_sites(self.douyin_third_party));
+                self.queue.push(info, options, extractor);
+                self.tasks.update(cx, |_, cx| cx.notify());
+            }
+            Err(e) => self.error = Some(ErrorMessage::new(&e)),
+        }
+    }
+
+    fn show_playlist(&mut self, playlist: Playlist, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.new(|cx| PlaylistView::new(playlist, window, cx));
+        cx.subscribe(&view, |this, _, ev: &PlaylistEvent, cx| {
+            if let PlaylistEvent::Confirm(entries) = ev {
+                this.enqueue_entries(entries.clone(), cx);
+            }
+            this.picker = None;
+            cx.notify();
+        })
+        .detach();
+        self.picker = Some(view.into());
+    }
+
+    fn show_login(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let view = cx.new(|cx| LoginView::new(window, cx));
+        cx.subscribe(&view, |this, _, ev: &LoginEvent, cx| match ev {
+            LoginEvent::Close => {
+                this.picker = None;
+                cx.notify();
+            }
+        })
+        .detach();
+        self.picker = Some(view.into());
+        cx.notify();
+    }
+
+    fn show_formats(&mut self, info: MediaInfo, cx: &mut Context<Self>) {
+        let policy = self.policy;
+        let view = cx.new(|_| FormatView::new(info, &policy));
+        cx.subscribe(&view, |this, _, ev: &FormatEvent, cx| {
+            if let FormatEvent::Confirm(info) = ev {
+                this.push_media(info.clone(), cx);
+            }
+            this.picker = None;
+            cx.notify();
+        })
+        .detach();
+        self.picker = Some(view.into());
+    }
+
+    /// 逐个解析列表中选中的项并加入下载队列
+    fn enqueue_entries(&mut self, entries: Vec<PlaylistEntry>, cx: &mut Context<Self>) {
+        self.is_loading = true;
+        let policy = self.policy;
+        let all_down = Arc::new(AllDown::all_sites(self.douyin_third_party));
+        cx.spawn(async move |view, cx| {
+            for entry in entries {
+                let all_down = all_down.clone();
+                let infos = TOKIO_RT
+                    .spawn(async move { all_down.extract_media(&entry.input).await })
+                    .await;
+                view.update(cx, |view, cx| {
+                    match infos.map_err(anyhow::Error::from).and_then(|r| r) {
+                        Ok(infos) => {
+                            for mut info in infos {
+                                info.apply_policy(&policy);
+                                view.push_media(info, cx)
+                            }
+                        }
+                        Err(e) => view.error = Some(ErrorMessage::new(&e)),
+                    }
+                    cx.notify();
+                })?;
+            }
+            view.update(cx, |view, cx| {
+                view.is_loading = false;
+                cx.notify();
+            })
+        })
+        .detach();
+    }
 }
 
 impl Render for HomeView {
     fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        if self.view.is_none() {
+        if self.queue.is_empty() && self.picker.is_none() {
             self.render_home(cx)
         } else {
             self.render_parsed(cx)
@@ -85,20 +237,29 @@ impl Render for HomeView {
 }
 
 impl HomeView {
-    fn title() -> Div {
+    fn title(&mut self, cx: &mut Context<Self>) -> Div {
         v_flex().size_full().child(
             TitleBar::new().child(
                 h_flex()
                     .w_full()
                     .pr_2()
                     .justify_between()
-                    .child("Unidown 下载器"),
+                    .child("Unidown 下载器")
+                    .child(
+                        Button::new("show-login")
+                            .ghost()
+                            .label("B 站登录")
+                            .compact()
+                            .on_click(
+                                cx.listener(|view, _, window, cx| view.show_login(window, cx)),
+                            ),
+                    ),
             ),
         )
     }
 
     fn render_home(&mut self, cx: &mut Context<Self>) -> Div {
-        Self::title().child(
+        self.title(cx).child(
             v_flex()
                 .id("window-body")
                 .p_4()
@@ -128,12 +289,14 @@ impl HomeView {
                                 .compact()
                                 .on_click(cx.listener(Self::handle_click)),
                         ),
-                ),
+                )
+                .child(self.render_options(cx))
+                .children(self.render_error(cx).map(|e| e.max_w_128())),
         )
     }
 
     fn render_parsed(&mut self, cx: &mut Context<Self>) -> Div {
-        Self::title().child(
+        self.title(cx).child(
             v_flex()
                 .id("window-body")
                 .flex_1()
@@ -154,13 +317,150 @@ impl HomeView {
                                 .on_click(cx.listener(Self::handle_click)),
                         ),
                 )
+                .child(self.render_options(cx).pt_2().px_4())
+                .children(self.render_error(cx).map(|e| e.mt_4().mx_4()))
                 .child(
                     v_flex()
                         .flex_1()
                         .min_h_0()
                         .overflow_y_scrollbar()
-                        .child(self.view.clone().unwrap().into_any()),
+                        .children(self.picker.clone())
+                        .child(self.tasks.clone()),
                 ),
         )
     }
+
+    fn render_options(&mut self, cx: &mut Context<Self>) -> Div {
+        h_flex()
+            .gap_2()
+            .items_center()
+            .child("画质")
+            .children(
+                FormatPolicy::PRESETS
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (name, policy))| {
+                        let policy = *policy;
+                        Button::new(("policy", i))
+                            .label(*name)
+                            .compact()
+                            .map(|b| {
+                                if self.policy == policy {
+                                    b.primary()
+                                } else {
+                                    b.ghost()
+                                }
+                            })
+                            .on_click(cx.listener(move |view, _, _, cx| {
+                                view.policy = policy;
+                                cx.notify();
+                            }))
+                    }),
+            )
+            .child(
+                Checkbox::new("embed-subtitles")
+                    .label("封装字幕和弹幕")
+                    .checked(self.embed_subtitles)
+                    .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                        view.embed_subtitles = *checked;
+                        cx.notify();
+                    })),
+            )
+            .child(
+                Checkbox::new("slideshow")
+                    .label("图文合成视频")
+                    .checked(self.slideshow)
+                    .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                        view.slideshow = *checked;
+                        cx.notify();
+                    })),
+            )
+            .child(
+                Checkbox::new("douyin-third-party")
+                    .label("抖音解析失败时使用第三方接口")
+                    .checked(self.douyin_third_party)
+                    .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                        view.douyin_third_party = *checked;
+                        cx.notify();
+                    })),
+            )
+            .child(
+                Checkbox::new("audio-only")
+                    .label("仅音频")
+                    .checked(self.audio_format.is_some())
+                    .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                        view.audio_format = checked.then_some(AudioFormat::M4a);
+                        cx.notify();
+                    })),
+            )
+            .when_some(self.audio_format, |this, current| {
+                this.children(AudioFormat::ALL.into_iter().enumerate().map(|(i, format)| {
+                    Button::new(("audio-format", i))
+                        .label(format.ext())
+                        .compact()
+                        .map(|b| {
+                            if format == current {
+                                b.primary()
+                            } else {
+                                b.ghost()
+                            }
+                        })
+                        .on_click(cx.listener(move |view, _, _, cx| {
+                            view.audio_format = Some(format);
+                            cx.notify();
+                        }))
+                }))
+            })
+    }
+
+    fn render_error(&mut self, cx: &mut Context<Self>) -> Option<Div> {
+        let error = self.error.as_ref()?;
+        let danger = cx.theme().danger;
+        let detail = error.detail.clone().filter(|_| self.show_error_detail);
+        Some(
+            v_flex()
+                .w_full()
+                .gap_2()
+                .p_3()
+                .rounded_md()
+                .border_1()
+                .border_color(danger)
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .justify_between()
+                        .child(
+                            div()
+                                .flex_1()
+                                .text_color(danger)
+                                .child(error.message.clone()),
+                        )
+                        .when(error.detail.is_some(), |this| {
+                            this.child(
+                                Button::new("toggle-error-detail")
+                                    .ghost()
+                                    .compact()
+                                    .label(if self.show_error_detail {
+                                        "收起详情"
+                                    } else {
+                                        "展开详情"
+                                    })
+                                    .on_click(cx.listener(|view, _, _, cx| {
+                                        view.show_error_detail = !view.show_error_detail;
+                                        cx.notify();
+                                    })),
+                            )
+                        }),
+                )
+                .when_some(detail, |this, detail| {
+                    this.child(
+                        v_flex()
+                            .max_h(px(240.))
+                            .text_xs()
+                            .overflow_y_scrollbar()
+                            .child(detail),
+                    )
+                }),
+        )
+    }
 }
@@ -0,0 +1,235 @@
+use crate::{
+    TOKIO_RT,
+    bili_login::{BiliSession, QrLogin, QrStatus, bili_session, bili_user_name, set_bili_session},
+};
+use anyhow::Context as _;
+use gpui::{
+    AppContext, Context, Div, Entity, EventEmitter, FluentBuilder as _, IntoElement, ParentElement,
+    Render, SharedString, Styled, Task, Timer, Window, black, div, px, white,
+};
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputState},
+    v_flex,
+};
+use qrcode::{Color, QrCode};
+use reqwest::Client;
+use std::time::Duration;
+
+pub enum LoginEvent {
+    Close,
+}
+
+/// 二维码的模块，按行展开
+struct QrImage {
+    width: usize,
+    dark: Vec<bool>,
+}
+
+/// B 站登录界面：扫码或者粘贴 cookie
+pub struct LoginView {
+    cookie_input: Entity<InputState>,
+    user: Option<SharedString>,
+    qr: Option<QrImage>,
+    status: SharedString,
+    _poll: Option<Task<()>>,
+}
+
+impl EventEmitter<LoginEvent> for LoginView {}
+
+impl LoginView {
+    pub fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let cookie_input = cx
+            .new(|cx| InputState::new(window, cx).placeholder("粘贴浏览器中的 cookie 或 SESSDATA"));
+        let mut view = Self {
+            cookie_input,
+            user: None,
+            qr: None,
+            status: SharedString::default(),
+            _poll: None,
+        };
+        match bili_session() {
+            Some(session) => view.check_session(session, false, cx),
+            None => view.start_qr(cx),
+        }
+        view
+    }
+
+    /// 检查登录信息，`save` 为真时检查通过后保存
+    fn check_session(&mut self, session: BiliSession, save: bool, cx: &mut Context<Self>) {
+        self.status = "正在检查登录状态".into();
+        cx.spawn(async move |view, cx| {
+            let result = TOKIO_RT
+                .spawn(async move {
+                    let name = bili_user_name(&session).await?;
+                    if save {
+                        set_bili_session(Some(session)).await?;
+                    }
+                    anyhow::Ok(name)
+                })
+                .await;
+            view.update(cx, |view, cx| {
+                match result.map_err(anyhow::Error::from).and_then(|r| r) {
+                    Ok(name) => {
+                        view.user = Some(name.into());
+                        view.qr = None;
+                        view._poll = None;
+                        view.status = "已登录".into();
+                    }
+                    Err(e) => view.status = format!("{e:#}").into(),
+                }
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    fn start_qr(&mut self, cx: &mut Context<Self>) {
+        self.status = "正在获取二维码".into();
+        self._poll = Some(cx.spawn(async move |view, cx| {
+            let result = async {
+                let client = Client::new();
+                let login = {
+                    let client = client.clone();
+                    TOKIO_RT
+                        .spawn(async move { QrLogin::generate(&client).await })
+                        .await??
+                };
+                let code = QrCode::new(login.url.as_bytes()).context("无法生成二维码")?;
+                view.update(cx, |view, cx| {
+                    view.qr = Some(QrImage {
+                        width: code.width(),
+                        dark: code.to_colors().iter().map(|c| *c == Color::Dark).collect(),
+                    });
+                    view.status = "请使用哔哩哔哩手机客户端扫码".into();
+                    cx.notify();
+                })?;
+                loop {
+                    Timer::after(Duration::from_secs(2)).await;
+                    let status = {
+                        let (client, login) = (client.clone(), login.clone());
+                        TOKIO_RT
+                            .spawn(async move { login.poll(&client).await })
+                            .await??
+                    };
+                    let session = match status {
+                        QrStatus::Waiting => continue,
+                        QrStatus::Scanned => {
+                            view.update(cx, |view, cx| {
+                                view.status = "已扫码，请在手机上确认".into();
+                                cx.notify();
+                            })?;
+                            continue;
+                        }
+                        QrStatus::Expired => anyhow::bail!("二维码已过期"),
+                        QrStatus::Confirmed(session) => session,
+                    };
+                    view.update(cx, |view, cx| view.check_session(session, true, cx))?;
+                    return anyhow::Ok(());
+                }
+            }
+            .await;
+            if let Err(e) = result {
+                let _ = view.update(cx, |view, cx| {
+                    view.qr = None;
+                    view.status = format!("{e:#}").into();
+                    cx.notify();
+                });
+            }
+        }));
+        cx.notify();
+    }
+
+    fn apply_cookie(&mut self, cx: &mut Context<Self>) {
+        let value = self.cookie_input.read(cx).value();
+        match BiliSession::parse(&value) {
+            Ok(session) => self.check_session(session, true, cx),
+            Err(e) => self.status = format!("{e:#}").into(),
+        }
+        cx.notify();
+    }
+
+    fn logout(&mut self, cx: &mut Context<Self>) {
+        self.user = None;
+        cx.spawn(async move |view, cx| {
+            let result = TOKIO_RT.spawn(set_bili_session(None)).await;
+            view.update(cx, |view, cx| {
+                match result.map_err(anyhow::Error::from).and_then(|r| r) {
+                    Ok(()) => view.start_qr(cx),
+                    Err(e) => view.status = format!("{e:#}").into(),
+                }
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    fn render_qr(qr: &QrImage) -> Div {
+        v_flex()
+            .p_2()
+            .bg(white())
+            .children(qr.dark.chunks(qr.width).map(|row| {
+                h_flex().children(
+                    row.iter()
+                        .map(|dark| div().size(px(4.)).when(*dark, |this| this.bg(black()))),
+                )
+            }))
+    }
+}
+
+impl Render for LoginView {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .p_4()
+            .gap_3()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .child(div().child("B 站登录").text_lg().font_bold())
+                    .child(
+                        Button::new("close-login")
+                            .ghost()
+                            .label("关闭")
+                            .compact()
+                            .on_click(cx.listener(|_, _, _, cx| cx.emit(LoginEvent::Close))),
+                    ),
+            )
+            .child(self.status.clone())
+            .when_some(self.user.clone(), |this, user| {
+                this.child(
+                    h_flex().gap_2().child(format!("当前账号: {}", user)).child(
+                        Button::new("logout")
+                            .ghost()
+                            .label("退出登录")
+                            .compact()
+                            .on_click(cx.listener(|view, _, _, cx| view.logout(cx))),
+                    ),
+                )
+            })
+            .when(self.user.is_none(), |this| {
+                this.children(self.qr.as_ref().map(Self::render_qr))
+                    .when(self.qr.is_none(), |this| {
+                        this.child(
+                            Button::new("refresh-qr")
+                                .label("刷新二维码")
+                                .compact()
+                                .on_click(cx.listener(|view, _, _, cx| view.start_qr(cx))),
+                        )
+                    })
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Input::new(&self.cookie_input).cleanable(true))
+                            .child(
+                                Button::new("apply-cookie")
+                                    .primary()
+                                    .label("使用 cookie 登录")
+                                    .compact()
+                                    .on_click(cx.listener(|view, _, _, cx| view.apply_cookie(cx))),
+                            ),
+                    )
+            })
+    }
+}
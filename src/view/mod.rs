@@ -1 +1,5 @@
+pub mod formats;
 pub mod home;
+pub mod login;
+pub mod playlist;
+pub mod tasks;
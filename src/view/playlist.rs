@@ -0,0 +1,239 @@
+use crate::{
+    EntryKind, Playlist, PlaylistEntry, PlaylistFilter, format_date, format_duration, parse_date,
+};
+use gpui::{
+    AppContext, Context, Entity, EventEmitter, FluentBuilder as _, IntoElement, ParentElement,
+    Render, Styled, Window, div, px,
+};
+use gpui_component::{
+    StyledExt,
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
+    input::{Input, InputState},
+    v_flex,
+};
+
+pub enum PlaylistEvent {
+    Confirm(Vec<PlaylistEntry>),
+    Cancel,
+}
+
+/// 分 P、合集等列表的选择界面
+pub struct PlaylistView {
+    playlist: Playlist,
+    selected: Vec<bool>,
+    since: Entity<InputState>,
+    until: Entity<InputState>,
+    max_count: Entity<InputState>,
+    skip_downloaded: bool,
+    /// 只选视频或只选图文，`None` 表示都选
+    kind: Option<EntryKind>,
+    filter_error: Option<String>,
+}
+
+impl EventEmitter<PlaylistEvent> for PlaylistView {}
+
+impl PlaylistView {
+    pub fn new(playlist: Playlist, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let selected = vec![true; playlist.entries.len()];
+        Self {
+            playlist,
+            selected,
+            since: cx.new(|cx| InputState::new(window, cx).placeholder("起始日期 2024-01-01")),
+            until: cx.new(|cx| InputState::new(window, cx).placeholder("截止日期")),
+            max_count: cx.new(|cx| InputState::new(window, cx).placeholder("最多几项")),
+            skip_downloaded: false,
+            kind: None,
+            filter_error: None,
+        }
+    }
+
+    /// 读取筛选条件，空输入表示不限
+    fn filter(&self, cx: &Context<Self>) -> Result<PlaylistFilter, String> {
+        let since = self.since.read(cx).value();
+        let until = self.until.read(cx).value();
+        let max_count = self.max_count.read(cx).value();
+        let date = |value: &str| match value.trim() {
+            "" => Ok(None),
+            value => parse_date(value)
+                .map(Some)
+                .ok_or_else(|| format!("无效的日期: {value}")),
+        };
+        let max_count = match max_count.trim() {
+            "" => None,
+            value => Some(
+                value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| format!("无效的数量: {value}"))?,
+            ),
+        };
+        Ok(PlaylistFilter {
+            since: date(&since)?,
+            // 包含截止当天
+            until: date(&until)?.map(|until| until + 86400),
+            max_count,
+            skip_downloaded: self.skip_downloaded,
+            kind: self.kind,
+        })
+    }
+
+    fn apply_filter(&mut self, cx: &mut Context<Self>) {
+        match self.filter(cx) {
+            Ok(filter) => {
+                self.selected = filter.select(&self.playlist.entries);
+                self.filter_error = None;
+            }
+            Err(e) => self.filter_error = Some(e),
+        }
+        cx.notify();
+    }
+
+    fn selected_entries(&self) -> Vec<PlaylistEntry> {
+        self.playlist
+            .entries
+            .iter()
+            .zip(&self.selected)
+            .filter(|(_, selected)| **selected)
+            .map(|(entry, _)| entry.clone())
+            .collect()
+    }
+}
+
+impl Render for PlaylistView {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let count = self.selected.iter().filter(|s| **s).count();
+        let has_gallery = self
+            .playlist
+            .entries
+            .iter()
+            .any(|e| e.kind == EntryKind::Gallery);
+        let current_kind = self.kind;
+
+        v_flex()
+            .p_4()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .justify_between()
+                    .child(
+                        div()
+                            .flex_1()
+                            .child(self.playlist.title.clone())
+                            .text_lg()
+                            .font_bold(),
+                    )
+                    .child(format!("共 {} 项", self.playlist.entries.len())),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("select-all")
+                            .label("全选")
+                            .compact()
+                            .on_click(cx.listener(|view, _, _, cx| {
+                                view.selected.fill(true);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("select-none")
+                            .label("全不选")
+                            .compact()
+                            .on_click(cx.listener(|view, _, _, cx| {
+                                view.selected.fill(false);
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("download-selected")
+                            .primary()
+                            .label(format!("下载所选（{}）", count))
+                            .compact()
+                            .disabled(count == 0)
+                            .on_click(cx.listener(|view, _, _, cx| {
+                                cx.emit(PlaylistEvent::Confirm(view.selected_entries()));
+                            })),
+                    )
+                    .child(
+                        Button::new("cancel-playlist")
+                            .ghost()
+                            .label("取消")
+                            .compact()
+                            .on_click(cx.listener(|_, _, _, cx| {
+                                cx.emit(PlaylistEvent::Cancel);
+                            })),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(div().w(px(140.)).child(Input::new(&self.since)))
+                    .child(div().w(px(140.)).child(Input::new(&self.until)))
+                    .child(div().w(px(100.)).child(Input::new(&self.max_count)))
+                    .when(has_gallery, |this| {
+                        let kinds = [
+                            ("全部", None),
+                            ("视频", Some(EntryKind::Video)),
+                            ("图文", Some(EntryKind::Gallery)),
+                        ];
+                        this.children(kinds.into_iter().enumerate().map(|(i, (label, kind))| {
+                            Button::new(("entry-kind", i))
+                                .label(label)
+                                .compact()
+                                .map(|b| {
+                                    if kind == current_kind {
+                                        b.primary()
+                                    } else {
+                                        b.ghost()
+                                    }
+                                })
+                                .on_click(cx.listener(move |view, _, _, cx| {
+                                    view.kind = kind;
+                                    cx.notify();
+                                }))
+                        }))
+                    })
+                    .child(
+                        Checkbox::new("skip-downloaded")
+                            .label("跳过已下载")
+                            .checked(self.skip_downloaded)
+                            .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                                view.skip_downloaded = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("apply-filter")
+                            .label("按条件选择")
+                            .compact()
+                            .on_click(cx.listener(|view, _, _, cx| view.apply_filter(cx))),
+                    ),
+            )
+            .children(self.filter_error.clone())
+            .children(self.playlist.entries.iter().enumerate().map(|(i, entry)| {
+                h_flex()
+                    .gap_2()
+                    .justify_between()
+                    .child(
+                        Checkbox::new(("playlist-entry", i))
+                            .label(entry.title.clone())
+                            .checked(self.selected[i])
+                            .on_click(cx.listener(move |view, checked: &bool, _, cx| {
+                                view.selected[i] = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .children(entry.upload_time.map(format_date))
+                            .children(entry.duration.map(format_duration)),
+                    )
+            }))
+    }
+}
@@ -0,0 +1,210 @@
+use crate::queue::{DownloadQueue, DownloadTask, TaskStatus};
+use gpui::{
+    Context, Div, FluentBuilder as _, IntoElement, ParentElement, Render, Styled, Timer, Window,
+    div,
+};
+use gpui_component::{
+    ActiveTheme, StyledExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    progress::Progress,
+    v_flex,
+};
+use std::{sync::Arc, time::Duration};
+
+pub struct TaskListView {
+    queue: Arc<DownloadQueue>,
+}
+
+impl TaskListView {
+    pub fn new(queue: Arc<DownloadQueue>, cx: &mut Context<Self>) -> Self {
+        cx.spawn(async move |view, cx| {
+            // 多刷新一帧，保证任务结束后的最终状态能显示出来
+            let mut was_active = false;
+            loop {
+                Timer::after(Duration::from_millis(100)).await;
+                let result = view.update(cx, |view, cx| {
+                    let is_active = view.queue.is_active();
+                    if is_active || was_active {
+                        cx.notify();
+                    }
+                    was_active = is_active;
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        })
+        .detach();
+        Self { queue }
+    }
+}
+
+impl Render for TaskListView {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let limit = self.queue.limit();
+
+        v_flex()
+            .child(
+                h_flex()
+                    .px_4()
+                    .pt_4()
+                    .gap_2()
+                    .justify_between()
+                    .child(div().child("下载任务").text_lg().font_bold())
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child("同时下载")
+                            .child(
+                                Button::new("limit-dec")
+                                    .label("-")
+                                    .compact()
+                                    .disabled(limit <= 1)
+                                    .on_click(cx.listener(move |view, _, _, cx| {
+                                        view.queue.set_limit(limit - 1);
+                                        cx.notify();
+                                    })),
+                            )
+                            .child(limit.to_string())
+                            .child(Button::new("limit-inc").label("+").compact().on_click(
+                                cx.listener(move |view, _, _, cx| {
+                                    view.queue.set_limit(limit + 1);
+                                    cx.notify();
+                                }),
+                            )),
+                    ),
+            )
+            .children(
+                self.queue
+                    .tasks()
+                    .into_iter()
+                    .map(|task| self.render_task(&task, cx)),
+            )
+    }
+}
+
+impl TaskListView {
+    fn render_task(&self, task: &DownloadTask, cx: &mut Context<Self>) -> Div {
+        let id = task.id;
+        let status = task.status();
+        let state = task.state();
+        let is_live = task.info.is_live();
+        let (status_text, is_error) = match &status {
+            TaskStatus::Queued => ("排队中".to_string(), false),
+            TaskStatus::Running if state.is_stop_requested() => ("正在停止".to_string(), false),
+            TaskStatus::Running if is_live => ("录制中".to_string(), false),
+            TaskStatus::Running => ("下载中".to_string(), false),
+            TaskStatus::Finished(path) => (format!("已完成: {}", path.display()), false),
+            TaskStatus::Failed(msg) => (format!("下载失败: {msg}"), true),
+            TaskStatus::Cancelled => ("已取消".to_string(), false),
+        };
+        let danger = cx.theme().danger;
+
+        v_flex()
+            .p_4()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                h_flex()
+                    .gap_2()
+                    .justify_between()
+                    .child(
+                        div()
+                            .flex_1()
+                            .child(task.info.title.clone())
+                            .text_lg()
+                            .font_bold(),
+                    )
+                    .when(is_live && matches!(status, TaskStatus::Running), |this| {
+                        this.child(
+                            Button::new(("task-stop", id as usize))
+                                .primary()
+                                .label("停止录制")
+                                .compact()
+                                .disabled(state.is_stop_requested())
+                                .on_click(cx.listener(move |view, _, _, cx| {
+                                    view.queue.stop(id);
+                                    cx.notify();
+                                })),
+                        )
+                    })
+                    .when(status.is_active(), |this| {
+                        this.child(
+                            Button::new(("task-cancel", id as usize))
+                                .label("取消")
+                                .compact()
+                                .on_click(cx.listener(move |view, _, _, cx| {
+                                    view.queue.cancel(id);
+                                    cx.notify();
+                                })),
+                        )
+                    })
+                    .when(!status.is_active(), |this| {
+                        this.child(
+                            Button::new(("task-retry", id as usize))
+                                .primary()
+                                .label("重试")
+                                .compact()
+                                .on_click(cx.listener(move |view, _, _, cx| {
+                                    view.queue.retry(id);
+                                    cx.notify();
+                                })),
+                        )
+                        .child(
+                            Button::new(("task-remove", id as usize))
+                                .ghost()
+                                .label("移除")
+                                .compact()
+                                .on_click(cx.listener(move |view, _, _, cx| {
+                                    view.queue.remove(id);
+                                    cx.notify();
+                                })),
+                        )
+                    }),
+            )
+            // 直播没有总长度，显示已录制的时长、大小和码率
+            .when(is_live, |this| {
+                this.child(
+                    h_flex()
+                        .justify_between()
+                        .child(div().child("直播录制").font_bold())
+                        .child(state.live_display()),
+                )
+            })
+            .when(!is_live, |this| {
+                this.children(task.info.streams.iter().zip(&state.streams).map(
+                    |(stream, progress)| {
+                        let (text, pct) = progress.display();
+                        Self::render_row(stream.kind.label(), text, pct)
+                    },
+                ))
+            })
+            .when(state.streams.len() > 1, |this| {
+                this.child(
+                    h_flex()
+                        .justify_between()
+                        .child(div().child("合并处理").font_bold())
+                        .child(state.merge_display()),
+                )
+            })
+            .child(
+                div()
+                    .child(status_text)
+                    .when(is_error, |this| this.text_color(danger)),
+            )
+    }
+
+    fn render_row(label: &str, text: String, pct: f32) -> impl IntoElement {
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .child(div().child(label.to_string()).font_bold())
+                    .child(text),
+            )
+            .child(Progress::new().value(pct))
+    }
+}